@@ -1,4 +1,6 @@
-use crate::adapters::common::{AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult};
+use crate::adapters::common::{
+	returns_rows, AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult,
+};
 use async_trait::async_trait;
 use gcloud_auth::project::Config as AuthConfig;
 use gcloud_auth::token::DefaultTokenSourceProvider;
@@ -16,7 +18,7 @@ use gcloud_googleapis::cloud::bigquery::storage::v1::{
 use polars::frame::DataFrame;
 use polars::io::SerReader;
 use polars::io::ipc::IpcStreamReader;
-use sqlparser::{ast::Statement, dialect::BigQueryDialect, parser::Parser};
+use sqlparser::{dialect::BigQueryDialect, parser::Parser};
 use std::io::Cursor;
 
 pub const FIELDS: &[AdapterField] = &[AdapterField {
@@ -49,7 +51,7 @@ impl DatabaseAdapter for BigQueryAdapter {
 			Err(err) => return ExecutionResult::Err(format!("BigQuery parse error: {}", err)),
 		};
 		match ast.as_slice() {
-			[Statement::Query(_)] => bq_query_to_df(&self.client, &self.project_id, code)
+			[statement] if returns_rows(statement) => bq_query_to_df(&self.client, &self.project_id, code)
 				.await
 				.map(ExecutionResult::Rows)
 				.unwrap_or_else(|err| ExecutionResult::Err(err.to_string())),
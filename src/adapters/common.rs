@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use polars::frame::DataFrame;
+use sqlparser::ast::Statement;
 
 #[derive(Clone, Debug, Default)]
 pub enum AdapterStage {
@@ -45,6 +46,26 @@ pub enum ExecutionResult {
 	Rows(DataFrame),
 }
 
+/// Queries plus the statements that answer with rows instead of a count:
+/// EXPLAIN, DESCRIBE, PRAGMA and SHOW.
+pub fn returns_rows(statement: &Statement) -> bool {
+	matches!(
+		statement,
+		Statement::Query(_)
+			| Statement::Explain { .. }
+			| Statement::ExplainTable { .. }
+			| Statement::Pragma { .. }
+			| Statement::ShowColumns { .. }
+			| Statement::ShowCreate { .. }
+			| Statement::ShowDatabases { .. }
+			| Statement::ShowSchemas { .. }
+			| Statement::ShowTables { .. }
+			| Statement::ShowVariable { .. }
+			| Statement::ShowVariables { .. }
+			| Statement::ShowViews { .. }
+	)
+}
+
 #[async_trait]
 pub trait DatabaseAdapter: Send + Sync + 'static {
 	async fn dispatch(&mut self, code: &str) -> ExecutionResult;
@@ -1,7 +1,7 @@
 use crate::adapters::{
 	bigquery,
 	common::{AdapterField, AdapterStage, DatabaseAdapter},
-	mysql, parquet, postgres, sqlite,
+	duckdb, mysql, parquet, postgres, sqlite,
 };
 use gcloud_bigquery::client::{Client, ClientConfig};
 use polars::prelude::{HiveOptions, LazyFrame, PlRefPath, ScanArgsParquet};
@@ -14,6 +14,7 @@ pub enum AdapterSelection {
 	#[default]
 	None,
 	BigQuery,
+	DuckDB,
 	MySQL,
 	Parquet,
 	Postgres,
@@ -25,6 +26,7 @@ impl AdapterSelection {
 		match self {
 			AdapterSelection::None => "None",
 			AdapterSelection::BigQuery => "BigQuery",
+			AdapterSelection::DuckDB => "DuckDB",
 			AdapterSelection::MySQL => "MySQL",
 			AdapterSelection::Parquet => "Parquet",
 			AdapterSelection::Postgres => "Postgres",
@@ -40,6 +42,9 @@ pub enum AdapterConfiguration {
 	BigQuery {
 		project_id: String,
 	},
+	DuckDB {
+		connection_string: String,
+	},
 	MySQL {
 		connection_string: String,
 	},
@@ -70,6 +75,9 @@ impl AdapterConfiguration {
 			"BigQuery" => AdapterConfiguration::BigQuery {
 				project_id: config_value.to_string(),
 			},
+			"DuckDB" => AdapterConfiguration::DuckDB {
+				connection_string: config_value.to_string(),
+			},
 			"MySQL" => AdapterConfiguration::MySQL {
 				connection_string: config_value.to_string(),
 			},
@@ -103,6 +111,15 @@ impl AdapterState {
 				self.configuration = AdapterConfiguration::MySQL { connection_string };
 				self.stage = AdapterStage::Configured;
 			}
+			AdapterSelection::DuckDB => {
+				let connection_string = self
+					.fields
+					.get("connection_string")
+					.unwrap_or(&"memory".to_string())
+					.clone();
+				self.configuration = AdapterConfiguration::DuckDB { connection_string };
+				self.stage = AdapterStage::Configured;
+			}
 			AdapterSelection::BigQuery => {
 				let project_id = self.fields.get("project_id").cloned().unwrap_or_default();
 				self.configuration = AdapterConfiguration::BigQuery { project_id };
@@ -171,6 +188,19 @@ impl AdapterState {
 					Err(_) => None,
 				}
 			}
+			#[cfg(feature = "duckdb")]
+			AdapterConfiguration::DuckDB { connection_string } => match duckdb::open(&connection_string) {
+				Ok(adapter) => Some(Arc::new(RwLock::new(adapter))),
+				Err(err) => {
+					eprintln!("DuckDB connection error: {err}");
+					None
+				}
+			},
+			#[cfg(not(feature = "duckdb"))]
+			AdapterConfiguration::DuckDB { connection_string } => {
+				eprintln!("Cannot open {connection_string}: DuckDB support is not compiled in, rebuild with --features duckdb");
+				None
+			}
 			AdapterConfiguration::Parquet { input_path } => {
 				let input_path = Path::new(&input_path);
 				let input_ref_path = PlRefPath::try_from_path(input_path).unwrap();
@@ -237,6 +267,7 @@ pub fn fields_for(selection: &AdapterSelection) -> &'static [AdapterField] {
 	match selection {
 		AdapterSelection::None => &[],
 		AdapterSelection::BigQuery => bigquery::FIELDS,
+		AdapterSelection::DuckDB => duckdb::FIELDS,
 		AdapterSelection::MySQL => mysql::FIELDS,
 		AdapterSelection::Parquet => parquet::FIELDS,
 		AdapterSelection::Postgres => postgres::FIELDS,
@@ -0,0 +1,126 @@
+use crate::adapters::common::{AdapterField, AdapterFieldType};
+
+pub const FIELDS: &[AdapterField] = &[AdapterField {
+	key: "connection_string",
+	value: "memory",
+	field_type: &AdapterFieldType::Text,
+	is_secure: false,
+}];
+
+#[cfg(feature = "duckdb")]
+pub use backend::{open, DuckDBAdapter};
+
+/// Only built with the `duckdb` feature; without it the adapter can be
+/// selected but fails to connect.
+#[cfg(feature = "duckdb")]
+mod backend {
+	use crate::adapters::common::{DatabaseAdapter, ExecutionResult};
+	use async_trait::async_trait;
+	use ::duckdb::{types::Value, Connection};
+	use polars::frame::{column::Column, DataFrame};
+	use sqlparser::{ast::Statement, dialect::DuckDbDialect, parser::Parser};
+	use std::sync::{Arc, Mutex};
+
+	/// The connection outlives each query, so tables created by one run are
+	/// visible to the next until the adapter is reconnected.
+	pub struct DuckDBAdapter {
+		pub conn: Arc<Mutex<Connection>>,
+	}
+
+	type BoxError = Box<dyn std::error::Error + Send + Sync>;
+	const DIALECT: DuckDbDialect = DuckDbDialect {};
+
+	pub fn open(connection_string: &str) -> Result<DuckDBAdapter, BoxError> {
+		let conn = if connection_string == "memory" {
+			Connection::open_in_memory()?
+		} else {
+			Connection::open(connection_string)?
+		};
+		Ok(DuckDBAdapter {
+			conn: Arc::new(Mutex::new(conn)),
+		})
+	}
+
+	#[async_trait]
+	impl DatabaseAdapter for DuckDBAdapter {
+		async fn dispatch(&mut self, code: &str) -> ExecutionResult {
+			let ast = match Parser::parse_sql(&DIALECT, code) {
+				Ok(nodes) => nodes,
+				Err(err) => {
+					return ExecutionResult::Err(format!("DuckDB parse error: {}", err));
+				}
+			};
+			let code = code.to_string();
+			let conn = Arc::clone(&self.conn);
+			let run = move || -> Result<ExecutionResult, BoxError> {
+				let conn = conn.lock().map_err(|err| err.to_string())?;
+				Ok(match ast.as_slice() {
+					[_, _, ..] => {
+						conn.execute_batch(&code)?;
+						ExecutionResult::Batch(vec![ExecutionResult::CommandCompleted(
+							"Batch complete.".to_string(),
+						)])
+					}
+					[Statement::Insert { .. }] | [Statement::Update { .. }] | [Statement::Delete { .. }] => {
+						ExecutionResult::Affected(conn.execute(&code, [])? as u64)
+					}
+					[_] => match duckdb_to_df(&conn, &code)? {
+						Some(df) => ExecutionResult::Rows(df),
+						None => ExecutionResult::CommandCompleted("Statement complete.".to_string()),
+					},
+					[] => ExecutionResult::None,
+				})
+			};
+			match tokio::task::spawn_blocking(run).await {
+				Ok(Ok(result)) => result,
+				Ok(Err(err)) => ExecutionResult::Err(err.to_string()),
+				Err(err) => ExecutionResult::Err(err.to_string()),
+			}
+		}
+	}
+
+	/// Collects the result as text columns; the table formats them as it would
+	/// any other string data. Statements that produce no columns, such as DDL,
+	/// return `None`, while EXPLAIN, PRAGMA, SHOW and DESCRIBE keep their rows.
+	pub fn duckdb_to_df(conn: &Connection, code: &str) -> Result<Option<DataFrame>, BoxError> {
+		let mut stmt = conn.prepare(code)?;
+		let mut rows = stmt.query([])?;
+		if rows.as_ref().map_or(0, |stmt| stmt.column_count()) == 0 {
+			return Ok(None);
+		}
+		let col_names: Vec<String> = rows.as_ref().map(|stmt| stmt.column_names()).unwrap_or_default();
+		let mut column_data: Vec<Vec<Option<String>>> = vec![Vec::new(); col_names.len()];
+		while let Some(row) = rows.next()? {
+			for (indx, bucket) in column_data.iter_mut().enumerate() {
+				bucket.push(value_to_string(row.get(indx)?));
+			}
+		}
+		let height = column_data.first().map_or(0, Vec::len);
+		let polars_columns: Vec<Column> = col_names
+			.into_iter()
+			.zip(column_data)
+			.map(|(name, values)| Column::new(name.into(), values))
+			.collect();
+		Ok(Some(DataFrame::new(height, polars_columns)?))
+	}
+
+	fn value_to_string(value: Value) -> Option<String> {
+		match value {
+			Value::Null => None,
+			Value::Boolean(b) => Some(b.to_string()),
+			Value::TinyInt(n) => Some(n.to_string()),
+			Value::SmallInt(n) => Some(n.to_string()),
+			Value::Int(n) => Some(n.to_string()),
+			Value::BigInt(n) => Some(n.to_string()),
+			Value::HugeInt(n) => Some(n.to_string()),
+			Value::UTinyInt(n) => Some(n.to_string()),
+			Value::USmallInt(n) => Some(n.to_string()),
+			Value::UInt(n) => Some(n.to_string()),
+			Value::UBigInt(n) => Some(n.to_string()),
+			Value::Float(f) => Some(f.to_string()),
+			Value::Double(f) => Some(f.to_string()),
+			Value::Text(s) => Some(s),
+			other => Some(format!("{other:?}")),
+		}
+	}
+}
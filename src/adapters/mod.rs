@@ -1,6 +1,7 @@
 pub mod bigquery;
 pub mod common;
 pub mod driver;
+pub mod duckdb;
 pub mod mysql;
 pub mod parquet;
 pub mod postgres;
@@ -1,4 +1,6 @@
-use crate::adapters::common::{AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult};
+use crate::adapters::common::{
+	returns_rows, AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult,
+};
 use async_trait::async_trait;
 use mysql_async::{prelude::Queryable, Pool, Value as MySqlValue};
 use polars::{
@@ -48,7 +50,7 @@ impl DatabaseAdapter for MySQLAdapter {
 					)])
 				})
 				.unwrap_or_else(|err| ExecutionResult::Err(err.to_string())),
-			[Statement::Query(_)] => mysql_to_df(conn, &code)
+			[statement] if returns_rows(statement) => mysql_to_df(conn, &code)
 				.await
 				.map(ExecutionResult::Rows)
 				.unwrap_or_else(|err| ExecutionResult::Err(err.to_string())),
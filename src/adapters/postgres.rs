@@ -1,4 +1,6 @@
-use crate::adapters::common::{AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult};
+use crate::adapters::common::{
+	returns_rows, AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult,
+};
 use async_trait::async_trait;
 use bytes::{Buf, BytesMut};
 use polars::{
@@ -8,7 +10,7 @@ use polars::{
 };
 use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, parser::Parser};
 use std::pin::pin;
-use tokio_postgres::{types::Type, Client};
+use tokio_postgres::{types::Type, Client, SimpleQueryMessage};
 use tokio_stream::StreamExt;
 
 pub const FIELDS: &[AdapterField] = &[AdapterField {
@@ -40,6 +42,10 @@ impl DatabaseAdapter for PostgresAdapter {
 				.await
 				.map(ExecutionResult::Rows)
 				.unwrap_or_else(|err| ExecutionResult::Err(err.to_string())),
+			[statement] if returns_rows(statement) => pg_text_to_df(&self.client, &code)
+				.await
+				.map(ExecutionResult::Rows)
+				.unwrap_or_else(|err| ExecutionResult::Err(err.to_string())),
 			[] => ExecutionResult::None,
 			_ => ExecutionResult::None,
 		}
@@ -152,3 +158,28 @@ pub async fn pg_to_df(client: &Client, code: &str) -> Result<DataFrame, BoxError
 	let height = polars_columns.first().map(|c| c.len()).unwrap_or(0);
 	Ok(DataFrame::new(height, polars_columns)?)
 }
+
+/// COPY only wraps queries, so EXPLAIN, SHOW and the like go through the
+/// simple query protocol and come back as text columns.
+async fn pg_text_to_df(client: &Client, code: &str) -> Result<DataFrame, BoxError> {
+	let mut names: Vec<String> = Vec::new();
+	let mut column_data: Vec<Vec<Option<String>>> = Vec::new();
+	for message in client.simple_query(code).await? {
+		if let SimpleQueryMessage::Row(row) = message {
+			if names.is_empty() {
+				names = row.columns().iter().map(|c| c.name().to_string()).collect();
+				column_data = vec![Vec::new(); names.len()];
+			}
+			for (i, bucket) in column_data.iter_mut().enumerate() {
+				bucket.push(row.get(i).map(String::from));
+			}
+		}
+	}
+	let height = column_data.first().map_or(0, Vec::len);
+	let polars_columns: Vec<Column> = names
+		.into_iter()
+		.zip(column_data)
+		.map(|(name, values)| Column::new(name.into(), values))
+		.collect();
+	Ok(DataFrame::new(height, polars_columns)?)
+}
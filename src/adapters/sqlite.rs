@@ -1,4 +1,6 @@
-use crate::adapters::common::{AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult};
+use crate::adapters::common::{
+	returns_rows, AdapterField, AdapterFieldType, DatabaseAdapter, ExecutionResult,
+};
 use async_trait::async_trait;
 use polars::{
 	datatypes::AnyValue,
@@ -46,7 +48,7 @@ impl DatabaseAdapter for SQLiteAdapter {
 					)])
 				})
 				.unwrap_or_else(|err| ExecutionResult::Err(err.to_string())),
-			[Statement::Query(_)] => self
+			[statement] if returns_rows(statement) => self
 				.aconn
 				.call(move |conn| sqlite_to_df(conn, &code))
 				.await
@@ -67,11 +69,7 @@ pub fn sqlite_to_df(conn: &SyncConnection, code: &str) -> Result<DataFrame, BoxE
 	let mut stmt = conn.prepare(code)?;
 	let col_names: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
 	let width = col_names.len();
-	let total_rows: usize =
-		conn.query_row(&format!("select count(1) from ({})", code), [], |row| {
-			row.get(0)
-		})?;
-	let mut column_data: Vec<Vec<AnyValue>> = vec![Vec::with_capacity(total_rows); width];
+	let mut column_data: Vec<Vec<AnyValue>> = vec![Vec::new(); width];
 	let mut rows = stmt.query([])?;
 	while let Some(row) = rows.next()? {
 		for (indx, bucket) in column_data.iter_mut().enumerate() {
@@ -93,6 +91,7 @@ pub fn sqlite_to_df(conn: &SyncConnection, code: &str) -> Result<DataFrame, BoxE
 			Column::from(ser)
 		})
 		.collect();
+	let total_rows = column_data.first().map_or(0, Vec::len);
 	let df = DataFrame::new(total_rows, polars_columns)?;
 	Ok(df)
 }
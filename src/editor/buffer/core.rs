@@ -832,8 +832,9 @@ impl Buffer {
 
 	// ── Indent / Dedent ───────────────────────────────────────────────────
 
-	/// Indent selected lines (or current line) by one tab character.
-	pub fn indent_lines(&mut self) {
+	/// Indent selected lines (or current line) by `width` spaces.
+	pub fn indent_lines(&mut self, width: usize) {
+		let indent = " ".repeat(width);
 		if self.has_multiple_carets() {
 			self.save_undo(EditKind::Insert);
 			let mut lines: Vec<LineIdx> = self
@@ -845,9 +846,9 @@ impl Buffer {
 			lines.dedup();
 			for &line in lines.iter().rev() {
 				let ci = self.document.rope.line_to_char(*line);
-				self.insert_char_at(CharIdx(ci), "\t");
+				self.insert_char_at(CharIdx(ci), &indent);
 			}
-			self.map_secondary_cursor_heads(|_, p| CursorPos::new(p.line, p.col + 1));
+			self.map_secondary_cursor_heads(|_, p| CursorPos::new(p.line, p.col + width));
 			self.post_edit();
 			return;
 		}
@@ -861,17 +862,17 @@ impl Buffer {
 		self.save_undo(EditKind::Insert);
 		for line_raw in (*first..=*last).rev() {
 			let ci = self.document.rope.line_to_char(line_raw);
-			self.insert_char_at(CharIdx(ci), "\t");
+			self.insert_char_at(CharIdx(ci), &indent);
 		}
-		let shift = |p: CursorPos| CursorPos::new(p.line, p.col + 1);
+		let shift = |p: CursorPos| CursorPos::new(p.line, p.col + width);
 		self.session.selection.anchor = shift(self.session.selection.anchor);
 		self.session.selection.head = shift(self.session.selection.head);
 		self.post_edit();
 	}
 
 	/// Dedent selected lines (or current line) by one tab stop.
-	/// Removes a leading tab first; if none, removes up to 4 leading spaces.
-	pub fn dedent_lines(&mut self) {
+	/// Removes a leading tab first; if none, removes up to `width` leading spaces.
+	pub fn dedent_lines(&mut self, width: usize) {
 		if self.has_multiple_carets() {
 			self.save_undo(EditKind::Delete);
 			let mut lines: Vec<LineIdx> = self
@@ -893,7 +894,7 @@ impl Buffer {
 						.chars()
 						.take_while(|c| *c == ' ')
 						.count()
-						.min(TAB_WIDTH);
+						.min(width);
 					if spaces > 0 {
 						self.remove_range(CharIdx(ci), CharIdx(ci + spaces));
 					}
@@ -934,7 +935,7 @@ impl Buffer {
 					.chars()
 					.take_while(|c| *c == ' ')
 					.count()
-					.min(TAB_WIDTH);
+					.min(width);
 				if spaces > 0 {
 					self.remove_range(CharIdx(ci), CharIdx(ci + spaces));
 				}
@@ -955,6 +956,64 @@ impl Buffer {
 		self.post_edit();
 	}
 
+	/// Toggle line comments on selected lines (or every caret's line).
+	/// Uncomments when every non-blank line already starts with the marker,
+	/// otherwise prefixes each non-blank line with the marker and a space.
+	pub fn toggle_line_comments(&mut self) {
+		let Some(marker) = self.language().line_comment() else {
+			return;
+		};
+		let mut lines: Vec<LineIdx> = if self.has_multiple_carets() {
+			self.all_cursor_heads().into_iter().map(|p| p.line).collect()
+		} else if self.session.selection.is_caret() {
+			vec![self.session.selection.head.line]
+		} else {
+			let (s, e) = self.session.selection.ordered();
+			(*s.line..=*e.line).map(LineIdx).collect()
+		};
+		lines.sort_unstable();
+		lines.dedup();
+		let texts: Vec<String> = lines.iter().map(|&line| self.line_text(line)).collect();
+		let uncomment = texts
+			.iter()
+			.filter(|text| !text.trim().is_empty())
+			.all(|text| text.starts_with(marker));
+		if texts.iter().all(|text| text.trim().is_empty()) {
+			return;
+		}
+		self.save_undo(EditKind::Other);
+		let prefix = format!("{marker} ");
+		let mut deltas: Vec<(LineIdx, isize)> = Vec::with_capacity(lines.len());
+		for (&line, text) in lines.iter().zip(&texts).rev() {
+			if text.trim().is_empty() {
+				continue;
+			}
+			let ci = self.document.rope.line_to_char(*line);
+			if uncomment {
+				let len = if text.starts_with(&prefix) { prefix.len() } else { marker.len() };
+				self.remove_range(CharIdx(ci), CharIdx(ci + len));
+				deltas.push((line, -(len as isize)));
+			} else {
+				self.insert_char_at(CharIdx(ci), &prefix);
+				deltas.push((line, prefix.len() as isize));
+			}
+		}
+		let shift = |p: CursorPos| {
+			let delta = deltas
+				.iter()
+				.find(|(line, _)| *line == p.line)
+				.map_or(0, |(_, delta)| *delta);
+			CursorPos::new(p.line, CharIdx((*p.col).saturating_add_signed(delta)))
+		};
+		if self.has_multiple_carets() {
+			self.map_secondary_cursor_heads(|_, p| shift(p));
+		} else {
+			self.session.selection.anchor = shift(self.session.selection.anchor);
+			self.session.selection.head = shift(self.session.selection.head);
+		}
+		self.post_edit();
+	}
+
 	// ── Editing ───────────────────────────────────────────────────────────
 
 	pub fn insert_char(&mut self, ch: char) {
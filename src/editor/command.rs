@@ -12,6 +12,7 @@ pub enum EditorCommand {
 	InsertNewline,
 	Indent,
 	Outdent,
+	ToggleComment,
 	ReplaceChar(char),
 
 	// ─── Cursor & Selection ─────────────────────────────────────────────────
@@ -51,6 +52,8 @@ pub enum EditorCommand {
 	// ─── Search & Replace ───────────────────────────────────────────────────
 	SearchOpen,
 	SearchClose,
+	SearchSetQuery(String),
+	SearchSetReplacement(String),
 	SearchNext,
 	SearchPrev,
 	SearchReplaceCurrent,
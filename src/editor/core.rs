@@ -1,13 +1,14 @@
 use iced::keyboard::{self, Key};
 use iced::widget::{column, container, row, text};
 use iced::{Element, Length, Subscription, Task, Theme, event};
+use ropey::Rope;
 
 use crate::gui::statusbar::{Segment, StatusBar, StatusBarStyle, Tone};
 
 use super::analysis::{self, AnalysisSnapshot};
 use super::buffer::Buffer;
 use super::command::EditorCommand;
-use super::coords::{CharIdx, CursorPos, LineIdx, line};
+use super::coords::{CharIdx, CursorPos, LineIdx, TAB_WIDTH, line};
 use super::highlight::SyntaxLanguage;
 use super::theme::EditorTheme;
 use super::undo::UndoConfig;
@@ -46,7 +47,13 @@ pub struct CodeEditor {
 	pub theme: EditorTheme,
 	pub view: EditorViewState,
 	pub vim: VimHandler,
+	/// Typing an opener also inserts its closer, and typing a closer steps over one.
+	pub auto_pairs: bool,
+	/// Spaces inserted by Tab and added or removed per indent level.
+	pub tab_width: usize,
 	pointer: PointerState,
+	/// Document version and word count as of the last `update_status`.
+	word_count: Option<(u64, usize)>,
 }
 
 pub struct EditorViewState {
@@ -70,6 +77,17 @@ fn default_undo_config() -> UndoConfig {
 	}
 }
 
+/// Runs of non-whitespace characters.
+fn count_words(rope: &Rope) -> usize {
+	rope.chunks()
+		.flat_map(str::chars)
+		.fold((0usize, false), |(count, in_word), ch| {
+			let is_word = !ch.is_whitespace();
+			(count + usize::from(is_word && !in_word), is_word)
+		})
+		.0
+}
+
 #[allow(dead_code)] // public API — used by the consuming application, not the demo
 impl CodeEditor {
 	/// Create a new editor with the given initial content and syntax language.
@@ -88,10 +106,13 @@ impl CodeEditor {
 				viewport_h: 0.0,
 			},
 			vim: VimHandler::new(),
+			auto_pairs: true,
+			tab_width: TAB_WIDTH,
 			pointer: PointerState {
 				is_dragging: false,
 				click_count: 0,
 			},
+			word_count: None,
 		};
 		ed.update_status();
 		ed
@@ -108,6 +129,7 @@ impl CodeEditor {
 		self.buffer = Buffer::with_undo_config(content, lang, default_undo_config());
 		self.view.scroll_y = 0.0;
 		self.view.scroll_x = 0.0;
+		self.word_count = None;
 		self.update_status();
 	}
 
@@ -116,6 +138,7 @@ impl CodeEditor {
 		self.buffer = Buffer::with_undo_config(content, language, default_undo_config());
 		self.view.scroll_y = 0.0;
 		self.view.scroll_x = 0.0;
+		self.word_count = None;
 		self.update_status();
 	}
 
@@ -136,6 +159,21 @@ impl CodeEditor {
 		self.update_status();
 	}
 
+	/// The query of the search panel, pre-filled from the selection on open.
+	pub fn search_query(&self) -> &str {
+		&self.buffer.session.search.query
+	}
+
+	/// The 1-based current match (0 when there are none) and the match count.
+	pub fn search_position(&self) -> (usize, usize) {
+		let search = &self.buffer.session.search;
+		if search.matches.is_empty() {
+			(0, 0)
+		} else {
+			(search.current_match + 1, search.match_count())
+		}
+	}
+
 	/// Returns `true` when vim modal editing is active.
 	pub fn vim_enabled(&self) -> bool {
 		self.vim.mode != VimMode::Off
@@ -375,6 +413,9 @@ impl CodeEditor {
 						let l = self.buffer.session.selection.head.line;
 						return self.execute_command(EditorCommand::ToggleFold(l));
 					}
+					Key::Character(ref ch) if ctrl && ch.as_str() == "/" => {
+						return self.execute_command(EditorCommand::ToggleComment);
+					}
 					Key::Character(ref ch) if ctrl && ch.as_str() == "w" => {
 						let enabled = !self.buffer.document.wrap_config.enabled;
 						return self.execute_command(EditorCommand::SetWrap(enabled));
@@ -435,6 +476,11 @@ impl CodeEditor {
 					Key::Named(keyboard::key::Named::Tab) if shift => {
 						return self.execute_command(EditorCommand::Outdent);
 					}
+					Key::Named(keyboard::key::Named::Tab)
+						if self.buffer.session.selection.is_caret() && !self.buffer.has_multiple_carets() =>
+					{
+						self.buffer.insert_text(&" ".repeat(self.tab_width));
+					}
 					Key::Named(keyboard::key::Named::Tab) => {
 						return self.execute_command(EditorCommand::Indent);
 					}
@@ -575,8 +621,12 @@ impl CodeEditor {
 	pub fn execute_command(&mut self, cmd: EditorCommand) -> Task<EditorMsg> {
 		match cmd {
 			EditorCommand::Insert(text) => {
-				for ch in text.chars() {
-					self.buffer.insert_char_auto_pair(ch);
+				if self.auto_pairs {
+					for ch in text.chars() {
+						self.buffer.insert_char_auto_pair(ch);
+					}
+				} else {
+					self.buffer.insert_text(&text);
 				}
 				self.update_status();
 				self.ensure_cursor_visible();
@@ -597,12 +647,17 @@ impl CodeEditor {
 				self.ensure_cursor_visible();
 			}
 			EditorCommand::Indent => {
-				self.buffer.indent_lines();
+				self.buffer.indent_lines(self.tab_width);
+				self.update_status();
+				self.ensure_cursor_visible();
+			}
+			EditorCommand::ToggleComment => {
+				self.buffer.toggle_line_comments();
 				self.update_status();
 				self.ensure_cursor_visible();
 			}
 			EditorCommand::Outdent => {
-				self.buffer.dedent_lines();
+				self.buffer.dedent_lines(self.tab_width);
 				self.update_status();
 				self.ensure_cursor_visible();
 			}
@@ -765,6 +820,13 @@ impl CodeEditor {
 				self.buffer.search_close();
 				self.update_status();
 			}
+			EditorCommand::SearchSetQuery(query) => {
+				self.buffer.search_update_query(&query);
+				self.update_status();
+			}
+			EditorCommand::SearchSetReplacement(replacement) => {
+				self.buffer.search_update_replacement(&replacement);
+			}
 			EditorCommand::SearchNext => {
 				self.buffer.search_next();
 				self.update_status();
@@ -818,8 +880,12 @@ impl CodeEditor {
 	}
 
 	pub(in crate::editor) fn update_status(&mut self) {
-		// Status is derived directly in `status_bar()`. Keep this hook in place so
-		// existing editor actions do not need to change in the same cleanup pass.
+		// Status is derived directly in `status_bar()`, apart from the word
+		// count, which walks the whole rope and so is only redone after edits.
+		let version = self.buffer.document_version();
+		if self.word_count.is_none_or(|(counted, _)| counted != version) {
+			self.word_count = Some((version, count_words(&self.buffer.document.rope)));
+		}
 	}
 
 	fn status_bar(&self) -> StatusBar {
@@ -851,6 +917,12 @@ impl CodeEditor {
 		} else {
 			None
 		};
+		let rope = &self.buffer.document.rope;
+		let words = match self.word_count {
+			Some((counted, words)) if counted == self.buffer.document_version() => words,
+			_ => count_words(rope),
+		};
+		let counts = format!("Lines: {} | Words: {words} | Chars: {}", rope.len_lines(), rope.len_chars());
 		let wrap_status = if self.buffer.document.wrap_config.enabled {
 			"wrap on"
 		} else {
@@ -874,6 +946,7 @@ impl CodeEditor {
 			.left(Segment::text(wrap_status))
 			.left(Segment::text("UTF-8"))
 			.left(Segment::text(self.buffer.language().display_name()))
+			.left(Segment::text(counts))
 			.right(
 				Segment::text("C-l=ws  C-m=map  C-w=wrap  C-A-j/k=carets  MMB=caret  C-\\=vim")
 					.max_chars(40)
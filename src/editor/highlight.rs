@@ -44,6 +44,14 @@ impl SyntaxLanguage {
 			Self::Txt => "Plain Text",
 		}
 	}
+
+	pub fn line_comment(&self) -> Option<&'static str> {
+		match self {
+			Self::Sql => Some("--"),
+			Self::Rust => Some("//"),
+			Self::Txt => None,
+		}
+	}
 }
 
 // ─── Highlighter ──────────────────────────────────────────────────────────────
@@ -218,6 +226,19 @@ fn tokenize_sql(text: &str) -> Vec<SyntaxToken> {
 			while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
 				i += 1;
 			}
+			// Exponent: 1e10, 2.5E-3
+			if i < bytes.len() && matches!(bytes[i], b'e' | b'E') {
+				let mut j = i + 1;
+				if j < bytes.len() && matches!(bytes[j], b'+' | b'-') {
+					j += 1;
+				}
+				if j < bytes.len() && bytes[j].is_ascii_digit() {
+					i = j;
+					while i < bytes.len() && bytes[i].is_ascii_digit() {
+						i += 1;
+					}
+				}
+			}
 			tokens.push(SyntaxToken {
 				byte_range: start..i,
 				kind: TokenKind::Number,
@@ -161,9 +161,9 @@ pub(in crate::editor) fn handle_normal_key(
 							head: CursorPos::new(last, ed.buffer.line_len(last)),
 						};
 						if op == '>' {
-							ed.buffer.indent_lines();
+							ed.buffer.indent_lines(ed.tab_width);
 						} else {
-							ed.buffer.dedent_lines();
+							ed.buffer.dedent_lines(ed.tab_width);
 						}
 						ed.buffer.session.selection =
 							Selection::caret(CursorPos::new(line, CharIdx(0)));
@@ -184,14 +184,14 @@ pub(in crate::editor) fn handle_visual_key(
 						return Task::none();
 					}
 					"<" => {
-						ed.buffer.dedent_lines();
+						ed.buffer.dedent_lines(ed.tab_width);
 						vim.mode = VimMode::Normal;
 						ed.update_status();
 						ed.ensure_cursor_visible();
 						return Task::none();
 					}
 					">" => {
-						ed.buffer.indent_lines();
+						ed.buffer.indent_lines(ed.tab_width);
 						vim.mode = VimMode::Normal;
 						ed.update_status();
 						ed.ensure_cursor_visible();
@@ -334,11 +334,11 @@ pub(in crate::editor) fn handle_visual_block_key(
 						vim.mode = VimMode::Normal;
 					}
 					"<" => {
-						ed.buffer.dedent_lines();
+						ed.buffer.dedent_lines(ed.tab_width);
 						vim.mode = VimMode::Normal;
 					}
 					">" => {
-						ed.buffer.indent_lines();
+						ed.buffer.indent_lines(ed.tab_width);
 						vim.mode = VimMode::Normal;
 					}
 					_ => {}
@@ -1,53 +1,170 @@
 use iced::Color;
 
-pub const BG_PRIMARY: Color = rgb(1, 1, 1);
-pub const BG_SECONDARY: Color = rgb(4, 4, 4);
-pub const BG_INPUT: Color = rgb(4, 4, 8);
-pub const BG_INPUT_HOVER: Color = rgb(10, 8, 16);
-pub const BG_INPUT_FOCUS: Color = rgb(8, 4, 10);
-pub const BG_BUTTON: Color = rgb(8, 8, 16);
-pub const BG_BUTTON_HOVER: Color = rgb(16, 16, 32);
-pub const BG_MODAL: Color = rgba(2, 2, 2, 0.96);
-
-pub const BORDER_PRIMARY: Color = rgb(60, 8, 100);
-pub const BORDER_DIM: Color = rgb(24, 2, 32);
-pub const BORDER_ACCENT: Color = rgb(110, 10, 240);
-pub const BORDER_HOVER: Color = rgb(80, 8, 140);
-
-pub const TEXT_PRIMARY: Color = rgb(230, 230, 230);
-pub const TEXT_SECONDARY: Color = rgb(200, 180, 200);
-pub const TEXT_PLACEHOLDER: Color = rgb(80, 70, 80);
-pub const TEXT_PLACEHOLDER_HOVER: Color = rgb(100, 90, 100);
-pub const TEXT_TITLE_BUTTON: Color = rgb(120, 120, 120);
-pub const TEXT_TITLE_BUTTON_HOVER: Color = Color::WHITE;
-pub const BRAND_PURPLE: Color = rgb(150, 4, 250);
-
-pub const DANGER: Color = rgb(200, 40, 40);
-pub const PRIMARY: Color = rgb(110, 10, 240);
-pub const SELECTION: Color = rgb(110, 10, 240);
-pub const SUCCESS: Color = rgb(40, 200, 40);
-pub const WARNING: Color = rgb(200, 80, 80);
-
-pub const TABLE_ROW_EVEN: Color = rgb(20, 8, 38);
-pub const TABLE_ROW_ODD: Color = rgb(40, 20, 70);
-pub const TABLE_BORDER: Color = rgba(250, 250, 250, 0.1);
-pub const TABLE_TEXT_HEADER: Color = rgb(220, 220, 220);
-pub const TABLE_TYPE_LABEL: Color = rgb(180, 150, 220);
-pub const TABLE_SELECTION: Color = rgba(140, 0, 250, 0.25);
-pub const SCROLLBAR_THUMB: Color = rgba(140, 0, 250, 0.5);
-pub const STATUS_BAR_RAIL_BACKGROUND: Color = rgb(10, 10, 12);
-pub const STATUS_BAR_RAIL_SEPARATOR: Color = BORDER_DIM;
-pub const STATUS_BAR_SEGMENT_BACKGROUND: Color = BG_BUTTON;
-pub const STATUS_BAR_SEGMENT_BORDER: Color = BORDER_DIM;
-pub const STATUS_BAR_TEXT: Color = rgb(200, 190, 210);
-pub const STATUS_BAR_TEXT_ACCENT: Color = rgb(200, 190, 210);
-pub const STATUS_BAR_TEXT_SUCCESS: Color = SUCCESS;
-pub const STATUS_BAR_TEXT_WARNING: Color = WARNING;
-pub const STATUS_BAR_TEXT_DANGER: Color = DANGER;
-pub const PROGRESS_BAR_TRACK_BACKGROUND: Color = BG_PRIMARY;
-pub const PROGRESS_BAR_FILL: Color = BRAND_PURPLE;
-
-pub const WHITE: Color = Color::WHITE;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+	#[default]
+	Dark,
+	Light,
+}
+
+impl ThemeMode {
+	pub fn colors(self) -> &'static Colors {
+		match self {
+			ThemeMode::Dark => &DARK,
+			ThemeMode::Light => &LIGHT,
+		}
+	}
+
+	pub fn toggled(self) -> Self {
+		match self {
+			ThemeMode::Dark => ThemeMode::Light,
+			ThemeMode::Light => ThemeMode::Dark,
+		}
+	}
+}
+
+/// The UI palette; `DARK` and `LIGHT` are the two built-in instances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Colors {
+	pub bg_primary: Color,
+	pub bg_primary_translucent: Color,
+	pub bg_secondary: Color,
+	pub bg_input: Color,
+	pub bg_input_hover: Color,
+	pub bg_input_focus: Color,
+	pub bg_button: Color,
+	pub bg_button_hover: Color,
+	pub bg_modal: Color,
+
+	pub border_primary: Color,
+	pub border_dim: Color,
+	pub border_accent: Color,
+	pub border_hover: Color,
+
+	pub text_primary: Color,
+	pub text_secondary: Color,
+	pub text_placeholder: Color,
+	pub text_placeholder_hover: Color,
+	pub text_title: Color,
+	pub text_title_button: Color,
+	pub text_title_button_hover: Color,
+	pub brand_purple: Color,
+
+	pub danger: Color,
+	pub primary: Color,
+	pub selection: Color,
+	pub success: Color,
+	pub warning: Color,
+
+	pub table_row_even: Color,
+	pub table_row_odd: Color,
+	pub table_row_selected: Color,
+	pub table_border: Color,
+	pub table_text_header: Color,
+	pub table_group_header: Color,
+	pub table_type_label: Color,
+	pub table_selection: Color,
+	pub table_reorder_ghost: Color,
+	pub scrollbar_thumb: Color,
+
+	pub status_bar_rail_background: Color,
+	pub status_bar_text: Color,
+	pub status_bar_text_accent: Color,
+}
+
+pub const DARK: Colors = Colors {
+	bg_primary: rgb(1, 1, 1),
+	bg_primary_translucent: rgba(1, 1, 1, 0.85),
+	bg_secondary: rgb(4, 4, 4),
+	bg_input: rgb(4, 4, 8),
+	bg_input_hover: rgb(10, 8, 16),
+	bg_input_focus: rgb(8, 4, 10),
+	bg_button: rgb(8, 8, 16),
+	bg_button_hover: rgb(16, 16, 32),
+	bg_modal: rgba(2, 2, 2, 0.96),
+
+	border_primary: rgb(60, 8, 100),
+	border_dim: rgb(24, 2, 32),
+	border_accent: rgb(110, 10, 240),
+	border_hover: rgb(80, 8, 140),
+
+	text_primary: rgb(230, 230, 230),
+	text_secondary: rgb(200, 180, 200),
+	text_placeholder: rgb(80, 70, 80),
+	text_placeholder_hover: rgb(100, 90, 100),
+	text_title: Color::WHITE,
+	text_title_button: rgb(120, 120, 120),
+	text_title_button_hover: Color::WHITE,
+	brand_purple: rgb(150, 4, 250),
+
+	danger: rgb(200, 40, 40),
+	primary: rgb(110, 10, 240),
+	selection: rgb(110, 10, 240),
+	success: rgb(40, 200, 40),
+	warning: rgb(200, 80, 80),
+
+	table_row_even: rgb(20, 8, 38),
+	table_row_odd: rgb(40, 20, 70),
+	table_row_selected: rgb(70, 30, 130),
+	table_border: rgba(250, 250, 250, 0.1),
+	table_text_header: rgb(220, 220, 220),
+	table_group_header: rgb(14, 6, 26),
+	table_type_label: rgb(180, 150, 220),
+	table_selection: rgba(140, 0, 250, 0.25),
+	table_reorder_ghost: rgba(110, 10, 240, 0.45),
+	scrollbar_thumb: rgba(140, 0, 250, 0.5),
+
+	status_bar_rail_background: rgb(10, 10, 12),
+	status_bar_text: rgb(200, 190, 210),
+	status_bar_text_accent: rgb(200, 190, 210),
+};
+
+pub const LIGHT: Colors = Colors {
+	bg_primary: rgb(250, 250, 252),
+	bg_primary_translucent: rgba(250, 250, 252, 0.9),
+	bg_secondary: rgb(238, 236, 242),
+	bg_input: rgb(255, 255, 255),
+	bg_input_hover: rgb(246, 242, 252),
+	bg_input_focus: rgb(255, 255, 255),
+	bg_button: rgb(234, 230, 242),
+	bg_button_hover: rgb(220, 210, 238),
+	bg_modal: rgba(250, 250, 252, 0.98),
+
+	border_primary: rgb(150, 110, 200),
+	border_dim: rgb(212, 202, 226),
+	border_accent: rgb(110, 10, 240),
+	border_hover: rgb(130, 60, 220),
+
+	text_primary: rgb(18, 14, 24),
+	text_secondary: rgb(56, 36, 76),
+	text_placeholder: rgb(150, 140, 160),
+	text_placeholder_hover: rgb(120, 110, 130),
+	text_title: rgb(10, 8, 14),
+	text_title_button: rgb(100, 92, 110),
+	text_title_button_hover: Color::WHITE,
+	brand_purple: rgb(130, 4, 220),
+
+	danger: rgb(180, 20, 20),
+	primary: rgb(110, 10, 240),
+	selection: rgb(196, 170, 250),
+	success: rgb(20, 130, 40),
+	warning: rgb(180, 90, 0),
+
+	table_row_even: rgb(255, 255, 255),
+	table_row_odd: rgb(243, 238, 250),
+	table_row_selected: rgb(214, 196, 246),
+	table_border: rgba(20, 0, 40, 0.14),
+	table_text_header: rgb(28, 20, 38),
+	table_group_header: rgb(230, 222, 242),
+	table_type_label: rgb(96, 56, 156),
+	table_selection: rgba(140, 0, 250, 0.18),
+	table_reorder_ghost: rgba(110, 10, 240, 0.35),
+	scrollbar_thumb: rgba(110, 10, 240, 0.45),
+
+	status_bar_rail_background: rgb(236, 234, 240),
+	status_bar_text: rgb(48, 38, 60),
+	status_bar_text_accent: rgb(90, 20, 170),
+};
 
 const fn rgb(r: u8, g: u8, b: u8) -> Color {
 	Color::from_rgb8(r, g, b)
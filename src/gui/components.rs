@@ -3,54 +3,382 @@ use crate::adapters::{
 	driver::{fields_for, AdapterSelection, AdapterState},
 };
 use crate::editor::CodeEditor;
-use crate::gui::messages::{Message, PlotMessage};
+use crate::gui::messages::{JoinSide, Message, PlotMessage};
 use crate::gui::plot_state::PlotState;
 use crate::gui::{
-	colors,
+	colors::{Colors, ThemeMode},
 	menu::{MenuBar, MenuFontPolicy, MenuItem, MenuRoot, MenuState},
-	table::Table,
+	table::{CellImage, NumericFormat, ResizeIndicatorStyle, SortOrder, SummaryMode, Table, TableTheme},
+	table_import::Delimiter,
 };
-use crate::persistence::{SavedConnection, SavedStatement};
+use crate::persistence::{Prefs, SavedConnection, SavedStatement, TableLayout};
 use crate::plot::colors::ColorTheme;
 use crate::plot::common::{GridLineStyle, PlotRenderLayer, PlotWidget, ScatterRenderMode};
 use crate::plot::core::PlotType;
-use crate::gui::statusbar::{Segment, StatusBar, Tone};
+use crate::gui::statusbar::{Segment, StatusBar, StatusBarStyle, Tone};
 use iced::widget::{
 	button, canvas, center, checkbox, column, container, mouse_area, opaque, pane_grid, pick_list,
-	row, scrollable, space, stack, text, text_input, TextInput,
+	row, scrollable, sensor, space, stack, text, text_editor, text_input, TextInput,
 };
 use iced::{
 	border, font, mouse,
 	theme::{Palette, Theme},
 	window::Direction,
-	Alignment, Background, Center, Color, Element, Fill, FillPortion, Font, Length,
+	Alignment, Background, Center, Color, Element, Fill, FillPortion, Font, Length, Padding, Point,
 };
-use polars::frame::DataFrame;
+use polars::frame::{column::Column, DataFrame};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::sync::Arc;
 
 pub const BUTTON_SIZE_DEFAULT: (u32, u32) = (120, 40);
+const TABLE_FONT_SIZES: [f32; 6] = [11.0, 12.0, 13.0, 14.0, 16.0, 18.0];
+const TAB_WIDTHS: [usize; 3] = [2, 4, 8];
+const SPLITTER_WIDTH: f32 = 6.0;
+const SPLIT_RATIO_MIN: f32 = 0.1;
+const SPLIT_RATIO_MAX: f32 = 0.9;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StatusInfo {
+	pub table_size: Option<(usize, usize)>,
+	pub selected_rows: usize,
+	pub active_filters: usize,
+	pub hidden_cols: usize,
+	pub elapsed_ms: Option<u64>,
+	pub differing_cells: Option<usize>,
+}
+
+/// Host side of the code editor's find-and-replace panel; matching and
+/// replacing run in the editor's own search state.
+#[derive(Debug, Clone, Default)]
+pub struct FindReplaceState {
+	pub open: bool,
+	pub find: String,
+	pub replace: String,
+}
+
+/// Left-pane ratio of a `split_view`, with the width it was last laid out
+/// at so drags can be turned back into a ratio.
+#[derive(Debug, Clone, Copy)]
+pub struct SplitState {
+	pub ratio: f32,
+	pub width: f32,
+	pub dragging: bool,
+}
+
+impl Default for SplitState {
+	fn default() -> Self {
+		Self {
+			ratio: 0.5,
+			width: 0.0,
+			dragging: false,
+		}
+	}
+}
+
+/// Join keys picked in the join preview pane and the last key counts as
+/// (left only, both, right only).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JoinPreviewState {
+	pub left_key: Option<usize>,
+	pub right_key: Option<usize>,
+	pub counts: Option<(usize, usize, usize)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryHistoryEntry {
+	pub sql: String,
+	pub executed_at: chrono::DateTime<chrono::Local>,
+	pub row_count: usize,
+	pub elapsed_ms: u64,
+}
+
+/// The editor text as it was when Run was pressed, whether or not the
+/// query succeeded.
+#[derive(Debug, Clone)]
+pub struct QuerySnapshot {
+	pub sql: String,
+	pub taken_at: chrono::DateTime<chrono::Local>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct QuerySnapshotOption {
+	index: usize,
+	label: String,
+}
+
+impl std::fmt::Display for QuerySnapshotOption {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.label)
+	}
+}
+
+/// A column's number format in the column menu; `None` shows values as stored.
+#[derive(Debug, Clone, PartialEq)]
+struct NumericFormatOption(Option<NumericFormat>);
+
+impl NumericFormatOption {
+	fn all() -> [Self; 5] {
+		[
+			Self(None),
+			Self(Some(NumericFormat::Integer)),
+			Self(Some(NumericFormat::Float(2))),
+			Self(Some(NumericFormat::Currency("$".to_string()))),
+			Self(Some(NumericFormat::Percentage(1))),
+		]
+	}
+}
+
+impl std::fmt::Display for NumericFormatOption {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match &self.0 {
+			None => write!(f, "As stored"),
+			Some(NumericFormat::Integer) => write!(f, "1,235"),
+			Some(NumericFormat::Float(places)) => write!(f, "1,234.{}", "5".repeat(*places as usize)),
+			Some(NumericFormat::Currency(symbol)) => write!(f, "{symbol}1,234.56"),
+			Some(NumericFormat::Percentage(places)) => write!(f, "12.{}%", "3".repeat(*places as usize)),
+		}
+	}
+}
+
+/// The results footer aggregate; `None` hides the footer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SummaryModeOption(Option<SummaryMode>);
+
+impl SummaryModeOption {
+	fn all() -> Vec<Self> {
+		std::iter::once(Self(None))
+			.chain(SummaryMode::ALL.into_iter().map(|mode| Self(Some(mode))))
+			.collect()
+	}
+}
+
+impl std::fmt::Display for SummaryModeOption {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self.0 {
+			None => write!(f, "No Totals"),
+			Some(SummaryMode::Sum) => write!(f, "Sum"),
+			Some(SummaryMode::Count) => write!(f, "Count"),
+			Some(SummaryMode::Average) => write!(f, "Average"),
+			Some(SummaryMode::Min) => write!(f, "Min"),
+			Some(SummaryMode::Max) => write!(f, "Max"),
+		}
+	}
+}
+
+/// An entry in the Ctrl+P command palette.
+#[derive(Clone)]
+pub struct Command {
+	pub label: String,
+	pub shortcut: Option<String>,
+	pub msg: Message,
+}
+
+impl Command {
+	fn new(label: &str, shortcut: Option<&str>, msg: Message) -> Self {
+		Self {
+			label: label.to_string(),
+			shortcut: shortcut.map(str::to_string),
+			msg,
+		}
+	}
+}
+
+pub fn palette_commands() -> Vec<Command> {
+	vec![
+		Command::new("Run", Some("Ctrl+Enter"), Message::Run),
+		Command::new("Explain", None, Message::Explain),
+		Command::new("New Connection", None, Message::Connect),
+		Command::new("Refresh Schema", None, Message::RefreshSchema),
+		Command::new("Open File", None, Message::OpenFile),
+		Command::new("Save Statement", None, Message::OpenSaveStatementDialog),
+		Command::new("Undo", Some("Ctrl+Z"), Message::Undo),
+		Command::new("Redo", Some("Ctrl+Y"), Message::Redo),
+		Command::new("Toggle Comment", None, Message::ToggleComment),
+		Command::new("Find and Replace", Some("Ctrl+H"), Message::FindReplace),
+		Command::new("Query History", Some("Ctrl+Alt+H"), Message::ToggleQueryHistory),
+		Command::new("Search Table", None, Message::OpenTableSearch),
+		Command::new("Toggle Filter Row", None, Message::ToggleFilterRow),
+		Command::new("Compare With Current Results", None, Message::ToggleCompare),
+		Command::new("Join Preview", None, Message::ToggleJoinPreview),
+		Command::new("Export CSV", Some("Ctrl+E"), Message::ExportCSV),
+		Command::new("Export Parquet", None, Message::ExportParquet),
+		Command::new("Copy as Markdown", Some("Ctrl+Shift+C"), Message::ExportMarkdown),
+		Command::new("Toggle Theme", None, Message::ToggleTheme),
+		Command::new("Toggle Transparency", Some("Ctrl+Shift+T"), Message::ToggleTransparency),
+		Command::new("Full Screen", Some("F11"), Message::FullScreen),
+		Command::new("Save Screenshot", Some("Ctrl+Shift+S"), Message::Screenshot),
+		Command::new("Focus Next Pane", Some("Ctrl+Tab"), Message::FocusNextPane),
+		Command::new("Focus Previous Pane", Some("Ctrl+Shift+Tab"), Message::FocusPrevPane),
+		Command::new("Maximize Window", None, Message::MaximizeWindow),
+		Command::new("Minimize Window", None, Message::MinimizeWindow),
+		Command::new("Preferences", None, Message::OpenSettings),
+	]
+}
+
+/// Case-insensitive subsequence match. A forward scan finds the first
+/// window that contains the query, a backward scan from its end shrinks it,
+/// and runs of consecutive matches inside it score progressively higher.
+pub fn fuzzy_score(query: &str, label: &str) -> Option<i32> {
+	let query: Vec<char> = query.to_lowercase().chars().collect();
+	let label: Vec<char> = label.to_lowercase().chars().collect();
+	if query.is_empty() {
+		return Some(0);
+	}
+	let mut matched = 0;
+	let mut end = None;
+	for (i, &c) in label.iter().enumerate() {
+		if c == query[matched] {
+			matched += 1;
+			if matched == query.len() {
+				end = Some(i);
+				break;
+			}
+		}
+	}
+	let end = end?;
+	let mut start = end;
+	let mut remaining = query.len();
+	for i in (0..=end).rev() {
+		if label[i] == query[remaining - 1] {
+			remaining -= 1;
+			if remaining == 0 {
+				start = i;
+				break;
+			}
+		}
+	}
+	let mut score = 0;
+	let mut run = 0;
+	let mut next = 0;
+	for i in start..=end {
+		if next < query.len() && label[i] == query[next] {
+			run += 1;
+			score += run;
+			if i == 0 || label[i - 1] == ' ' {
+				score += 2;
+			}
+			next += 1;
+		} else {
+			run = 0;
+			score -= 1;
+		}
+	}
+	Some(score)
+}
+
+/// Commands matching `query`, best first; ties keep the palette order.
+pub fn filter_commands<'c>(commands: &'c [Command], query: &str) -> Vec<&'c Command> {
+	let query = query.trim();
+	let mut scored: Vec<(i32, &Command)> = commands
+		.iter()
+		.filter_map(|command| fuzzy_score(query, &command.label).map(|score| (score, command)))
+		.collect();
+	scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+	scored.into_iter().map(|(_, command)| command).collect()
+}
+
+/// The palette's command and shortcut columns for the commands matching `query`.
+pub fn command_palette_frame(commands: &[Command], query: &str) -> DataFrame {
+	let matches = filter_commands(commands, query);
+	let columns = vec![
+		Column::new(
+			"Command".into(),
+			matches.iter().map(|command| command.label.as_str()).collect::<Vec<_>>(),
+		),
+		Column::new(
+			"Shortcut".into(),
+			matches
+				.iter()
+				.map(|command| command.shortcut.as_deref().unwrap_or(""))
+				.collect::<Vec<_>>(),
+		),
+	];
+	DataFrame::new(matches.len(), columns).unwrap_or_default()
+}
+
+pub fn command_palette_id() -> iced::widget::Id {
+	iced::widget::Id::new("command-palette")
+}
+
+const QUERY_SNAPSHOT_MENU_LEN: usize = 10;
+const QUERY_SNAPSHOT_LABEL_CHARS: usize = 48;
+
+/// A table in the schema browser; `schema` is empty on databases without
+/// schemas, like SQLite.
+#[derive(Debug, Clone)]
+pub struct TableSchema {
+	pub schema: String,
+	pub name: String,
+	pub columns: Vec<(String, String)>,
+}
+
+impl TableSchema {
+	fn label(&self) -> String {
+		if self.schema.is_empty() {
+			self.name.clone()
+		} else {
+			format!("{}.{}", self.schema, self.name)
+		}
+	}
+}
+
+/// Schema tree rows in display order as (table, column) indexes; a table is
+/// followed by its columns only while expanded.
+pub fn schema_nodes(schema: &[TableSchema], expanded: &BTreeSet<usize>) -> Vec<(usize, Option<usize>)> {
+	schema
+		.iter()
+		.enumerate()
+		.flat_map(|(table_idx, table)| {
+			let cols = if expanded.contains(&table_idx) {
+				0..table.columns.len()
+			} else {
+				0..0
+			};
+			std::iter::once((table_idx, None)).chain(cols.map(move |col| (table_idx, Some(col))))
+		})
+		.collect()
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PaneType {
 	CodeEditor,
 	DataTable,
 	Dashboard,
+	JoinPreview,
+	QueryHistory,
+	QueryPlan,
+	Schema,
 }
 
-pub fn theme() -> Theme {
+pub fn theme(transparent: bool, mode: ThemeMode) -> Theme {
+	let colors = mode.colors();
+	let name = match mode {
+		ThemeMode::Dark => "BlackHole",
+		ThemeMode::Light => "WhiteHole",
+	};
 	Theme::custom(
-		"BlackHole".to_string(),
+		name.to_string(),
 		Palette {
-			background: colors::BG_PRIMARY,
-			danger: colors::DANGER,
-			primary: colors::PRIMARY,
-			success: colors::SUCCESS,
-			text: colors::TEXT_PRIMARY,
-			warning: colors::WARNING,
+			background: background_color(transparent, colors),
+			danger: colors.danger,
+			primary: colors.primary,
+			success: colors.success,
+			text: colors.text_primary,
+			warning: colors.warning,
 		},
 	)
 }
 
-pub fn title_bar<'a>() -> Element<'a, Message> {
+fn background_color(transparent: bool, colors: &Colors) -> Color {
+	if transparent {
+		colors.bg_primary_translucent
+	} else {
+		colors.bg_primary
+	}
+}
+
+pub fn title_bar<'a>(theme: ThemeMode) -> Element<'a, Message> {
+	let colors = theme.colors();
 	let width = 34;
 	let height = 30;
 	let font_size = 16;
@@ -65,10 +393,80 @@ pub fn title_bar<'a>() -> Element<'a, Message> {
 						weight: font::Weight::Bold,
 						..Default::default()
 					})
-					.color(colors::WHITE),
+					.color(colors.text_title),
 				space::horizontal()
 			]))
 			.on_press(Message::DragWindow),
+			button(
+				text("Open")
+					.font(Font {
+						weight: font::Weight::Bold,
+						..Default::default()
+					})
+					.size(font_size - 2)
+					.align_y(Center)
+					.align_x(Center)
+			)
+			.width(width * 2)
+			.height(height)
+			.style(|_theme: &Theme, status: button::Status| match status {
+				button::Status::Hovered => button::Style {
+					background: Some(Background::Color(colors.brand_purple)),
+					text_color: colors.text_title_button_hover,
+					..button::Style::default()
+				},
+				_ => button::Style {
+					background: Some(Background::Color(Color::TRANSPARENT)),
+					text_color: colors.text_title_button,
+					..button::Style::default()
+				},
+			})
+			.on_press(Message::OpenFile),
+			button(
+				text(match theme {
+					ThemeMode::Dark => "☀",
+					ThemeMode::Light => "☾",
+				})
+					.size(font_size)
+					.align_y(Center)
+					.align_x(Center)
+			)
+			.width(width)
+			.height(height)
+			.style(move |_theme: &Theme, status: button::Status| match status {
+				button::Status::Hovered => button::Style {
+					background: Some(Background::Color(colors.brand_purple)),
+					text_color: colors.text_title_button_hover,
+					..button::Style::default()
+				},
+				_ => button::Style {
+					background: Some(Background::Color(Color::TRANSPARENT)),
+					text_color: colors.text_title_button,
+					..button::Style::default()
+				},
+			})
+			.on_press(Message::ToggleTheme),
+			button(
+				text("⚙")
+					.size(font_size)
+					.align_y(Center)
+					.align_x(Center)
+			)
+			.width(width)
+			.height(height)
+			.style(|_theme: &Theme, status: button::Status| match status {
+				button::Status::Hovered => button::Style {
+					background: Some(Background::Color(colors.brand_purple)),
+					text_color: colors.text_title_button_hover,
+					..button::Style::default()
+				},
+				_ => button::Style {
+					background: Some(Background::Color(Color::TRANSPARENT)),
+					text_color: colors.text_title_button,
+					..button::Style::default()
+				},
+			})
+			.on_press(Message::OpenSettings),
 			button(
 				text("—")
 					.font(Font {
@@ -83,13 +481,13 @@ pub fn title_bar<'a>() -> Element<'a, Message> {
 			.height(height)
 			.style(|_theme: &Theme, status: button::Status| match status {
 				button::Status::Hovered => button::Style {
-					background: Some(Background::Color(colors::BRAND_PURPLE)),
-					text_color: colors::TEXT_TITLE_BUTTON_HOVER,
+					background: Some(Background::Color(colors.brand_purple)),
+					text_color: colors.text_title_button_hover,
 					..button::Style::default()
 				},
 				_ => button::Style {
 					background: Some(Background::Color(Color::TRANSPARENT)),
-					text_color: colors::TEXT_TITLE_BUTTON,
+					text_color: colors.text_title_button,
 					..button::Style::default()
 				},
 			})
@@ -108,13 +506,13 @@ pub fn title_bar<'a>() -> Element<'a, Message> {
 			.height(height)
 			.style(|_theme: &Theme, status: button::Status| match status {
 				button::Status::Hovered => button::Style {
-					background: Some(Background::Color(colors::BRAND_PURPLE)),
-					text_color: colors::TEXT_TITLE_BUTTON_HOVER,
+					background: Some(Background::Color(colors.brand_purple)),
+					text_color: colors.text_title_button_hover,
 					..button::Style::default()
 				},
 				_ => button::Style {
 					background: Some(Background::Color(Color::TRANSPARENT)),
-					text_color: colors::TEXT_TITLE_BUTTON,
+					text_color: colors.text_title_button,
 					..button::Style::default()
 				},
 			})
@@ -133,13 +531,13 @@ pub fn title_bar<'a>() -> Element<'a, Message> {
 			.height(height)
 			.style(|_theme: &Theme, status: button::Status| match status {
 				button::Status::Hovered => button::Style {
-					background: Some(Background::Color(colors::BRAND_PURPLE)),
-					text_color: colors::TEXT_TITLE_BUTTON_HOVER,
+					background: Some(Background::Color(colors.brand_purple)),
+					text_color: colors.text_title_button_hover,
 					..button::Style::default()
 				},
 				_ => button::Style {
 					background: Some(Background::Color(Color::TRANSPARENT)),
-					text_color: colors::TEXT_TITLE_BUTTON,
+					text_color: colors.text_title_button,
 					..button::Style::default()
 				},
 			})
@@ -151,9 +549,9 @@ pub fn title_bar<'a>() -> Element<'a, Message> {
 	.width(Fill)
 	.height(height)
 	.style(|_theme| container::Style {
-		background: Some(colors::BG_SECONDARY.into()),
+		background: Some(colors.bg_secondary.into()),
 		border: border::Border {
-			color: colors::WHITE,
+			color: colors.text_title,
 			width: 0.2,
 			radius: 0.0.into(),
 		},
@@ -162,13 +560,29 @@ pub fn title_bar<'a>() -> Element<'a, Message> {
 	.into()
 }
 
-fn pane_title_bar<'a>(_pane_type: PaneType) -> pane_grid::TitleBar<'a, Message> {
+fn pane_style(is_focused: bool, transparent: bool, colors: &Colors) -> container::Style {
+	container::Style {
+		background: (!transparent).then(|| colors.bg_primary.into()),
+		border: border::Border {
+			color: if is_focused {
+				colors.border_accent
+			} else {
+				Color::TRANSPARENT
+			},
+			width: 1.0,
+			radius: 5.0.into(),
+		},
+		..Default::default()
+	}
+}
+
+fn pane_title_bar<'a>(_pane_type: PaneType, colors: &'static Colors) -> pane_grid::TitleBar<'a, Message> {
 	pane_grid::TitleBar::new(
 		container(space::horizontal().width(Fill))
 			.width(Fill)
 			.padding(5)
 			.style(|_| container::Style {
-				background: Some(Background::Color(colors::BG_SECONDARY)),
+				background: Some(Background::Color(colors.bg_secondary)),
 				..Default::default()
 			}),
 	)
@@ -177,14 +591,16 @@ fn pane_title_bar<'a>(_pane_type: PaneType) -> pane_grid::TitleBar<'a, Message>
 
 pub fn main_screen<'a>(
 	panes: &'a pane_grid::State<PaneType>,
+	focused_pane: Option<pane_grid::Pane>,
 	dashboard: &'a Option<pane_grid::State<PlotState>>,
 	menu_state: &'a MenuState,
 	code_editor: &'a CodeEditor,
+	find_replace: &'a FindReplaceState,
 	data_frame: &'a DataFrame,
+	data_generation: u64,
 	status_msg: &'a str,
 	status_error: &'a str,
-	status_df_size: Option<(usize, usize)>,
-	status_time_elapsed: Option<f64>,
+	status_info: StatusInfo,
 	adapter_state: &'a AdapterState,
 	saved_connections: &'a [SavedConnection],
 	saved_statements: &'a [SavedStatement],
@@ -197,58 +613,274 @@ pub fn main_screen<'a>(
 	settings_error: &'a str,
 	is_password_protected: bool,
 	show_column_types: bool,
+	hidden_cols: &'a BTreeSet<usize>,
+	column_menu: Option<(usize, Point)>,
+	cell_menu: Option<(usize, usize, Point)>,
+	table_search_query: &'a str,
+	table_search_hits: &'a [(usize, usize)],
+	table_search_index: Option<usize>,
+	jump_to_col: Option<(usize, u64)>,
+	sort: Option<(usize, SortOrder)>,
+	table_layout: Option<&'a TableLayout>,
+	editing_cell: Option<(usize, usize, &'a str)>,
+	col_filters: Option<&'a [String]>,
+	table_loading: bool,
+	cell_images: &'a HashMap<String, CellImage>,
+	image_cols: &'a BTreeSet<usize>,
+	numeric_formats: &'a HashMap<usize, NumericFormat>,
+	summary_mode: Option<SummaryMode>,
+	compare_baseline: Option<&'a Arc<DataFrame>>,
+	previous_result: Option<&'a DataFrame>,
+	join_preview: JoinPreviewState,
+	delimiter: char,
+	prefs: Prefs,
+	query_history: &'a VecDeque<QueryHistoryEntry>,
+	query_snapshots: &'a VecDeque<QuerySnapshot>,
+	query_history_frame: &'a DataFrame,
+	query_history_offset: usize,
+	editor_split: SplitState,
+	query_plan: &'a text_editor::Content,
+	schema: &'a [TableSchema],
+	schema_expanded: &'a BTreeSet<usize>,
+	schema_cursor: Option<usize>,
 	showing_save_statement_dialog: bool,
 	save_statement_name: &'a str,
 	editing_statement_id: Option<i64>,
+	command_palette_frame: &'a Arc<DataFrame>,
+	command_palette: Option<(&'a str, usize)>,
+	transparent: bool,
+	theme: ThemeMode,
+	is_fullscreen: bool,
 ) -> Element<'a, Message> {
-	let main_pane = pane_grid(panes, |_id, pane_type, _is_maximized| match pane_type {
-		PaneType::CodeEditor => pane_grid::Content::new(center(
-			container(code_editor.view().map(Message::CodeEditEvent))
-				.padding(1)
-				.style(|_| container::Style {
+	let colors = theme.colors();
+	let table_theme = TableTheme {
+		background: background_color(transparent, colors),
+		..TableTheme::from(colors)
+	};
+	let pane_body = |pane_type: PaneType, is_focused: bool| -> Element<'a, Message> {
+		match pane_type {
+			PaneType::CodeEditor => center(
+				container(code_panel(code_editor, find_replace, colors))
+					.padding(1)
+					.style(|_| container::Style {
+						border: border::Border {
+							color: colors.border_primary,
+							width: 1.0,
+							radius: 5.0.into(),
+						},
+						..Default::default()
+					}),
+			)
+			.into(),
+			PaneType::DataTable => {
+				// Until a pane is clicked the table takes keys only while hovered,
+				// so typing in the editor doesn't scroll it.
+				let table_focus = match command_palette {
+					Some(_) => Some(false),
+					None => focused_pane.map(|_| is_focused),
+				};
+				let mut table = Table::new(data_frame, 0)
+					.data_generation(data_generation)
+					.theme(table_theme)
+					.font_size(prefs.table_font_size)
+					.zebra_striping(prefs.zebra_striping)
+					.json_preview(true)
+					.url_cells(true)
+					.on_open_url(Message::OpenUrl)
+					.loading(table_loading)
+					.show_column_types(show_column_types)
+					.hidden_columns(hidden_cols)
+					.initial_layout(table_layout)
+					.on_row_click(Message::TableRowClicked)
+					.sorted_by(sort)
+					.on_sort(Message::SortRequested)
+					.on_reorder(Message::ColumnsReordered)
+					.on_column_menu(Message::ShowColumnMenu)
+					.column_menu_button(true)
+					.column_resize_indicator(ResizeIndicatorStyle {
+						color: colors.border_accent,
+						width: 2.0,
+						show_ghost: true,
+					})
+					.selected_cell_border(colors.border_accent, 2.0)
+					.row_click_select_range(true)
+					.row_drag(Message::RowsReordered)
+					.on_cell_menu(|row, col, screen_pos| Message::CellContextMenu { row, col, screen_pos })
+					.on_find(|| Message::OpenTableSearch)
+					.on_layout_change(Message::TableLayoutChanged)
+					.on_cell_double_click(Message::CellDoubleClicked)
+					.on_selection_change(Message::TableSelectionChanged)
+					.image_cache(cell_images)
+					.on_image_request(Message::CellImagesRequested);
+				for &col_idx in image_cols {
+					table = table.image_cells(col_idx);
+				}
+				for (&col_idx, format) in numeric_formats {
+					table = table.numeric_format(col_idx, format.clone());
+				}
+				for (col_idx, column) in data_frame.columns().iter().enumerate() {
+					table = table.header_tooltip(col_idx, format!("{}\n{}", column.name(), column.dtype()));
+				}
+				if let Some(focused) = table_focus {
+					table = table.focused(focused);
+				}
+				if let Some(mode) = summary_mode {
+					table = table.show_summary_row(mode);
+				}
+				if transparent {
+					let alpha = colors.bg_primary_translucent.a;
+					table = table.stripe_color(
+						colors.table_row_even.scale_alpha(alpha),
+						colors.table_row_odd.scale_alpha(alpha),
+					);
+				}
+				if let Some(baseline) = compare_baseline {
+					table = table.compare_mode(Arc::clone(baseline));
+				}
+				if let Some((col_idx, request)) = jump_to_col {
+					table = table.focus_col(col_idx, request);
+				}
+				if let Some(col_filters) = col_filters {
+					table = table
+						.show_filter_row(true)
+						.col_filters(col_filters)
+						.on_filter_change(Message::FilterChanged);
+				}
+				table = table.empty_message(
+					if col_filters.is_some_and(|filters| filters.iter().any(|f| !f.is_empty())) {
+						"No rows match the current filter"
+					} else {
+						"Run a query to see results"
+					},
+				);
+				let mut table_view = stack![data_panel(
+					table,
+					table_search_query,
+					table_search_hits,
+					table_search_index,
+					delimiter,
+					summary_mode,
+					query_snapshots,
+					colors,
+				)];
+				if let Some((row, col, value)) = editing_cell {
+					table_view = table_view.push(cell_edit_bar(data_frame, row, col, value, colors));
+				}
+				center(table_view).into()
+			}
+			PaneType::Dashboard => if let Some(dashboard) = dashboard {
+				dashboard_view(dashboard, colors)
+			} else {
+				center(text("").color(colors.text_secondary)).into()
+			},
+			PaneType::JoinPreview => join_preview_view(
+				previous_result,
+				data_frame,
+				join_preview,
+				table_theme,
+				colors,
+			),
+			PaneType::QueryHistory => center(
+				Table::new(query_history_frame, query_history_offset)
+					.theme(table_theme)
+					.virtual_rows(query_history.len(), Message::FetchHistoryRows)
+					.focused(command_palette.is_none() && is_focused)
+					.on_row_click(move |row| {
+						Message::LoadHistoryEntry(
+							query_history
+								.get(row)
+								.map(|entry| entry.sql.clone())
+								.unwrap_or_default(),
+						)
+					}),
+			)
+			.into(),
+			PaneType::QueryPlan => text_editor(query_plan)
+				.font(Font::with_name("DejaVu Sans Mono"))
+				.size(14)
+				.height(Fill)
+				.on_action(Message::QueryPlanAction)
+				.into(),
+			PaneType::Schema => schema_tree(schema, schema_expanded, schema_cursor, colors),
+		}
+	};
+	let find_pane = |target: PaneType| panes.iter().find(|(_, pane_type)| **pane_type == target).map(|(pane, _)| *pane);
+	let main_pane: Element<'a, Message> = match (find_pane(PaneType::CodeEditor), find_pane(PaneType::DataTable)) {
+		// The default editor/table layout skips pane_grid; any other pane
+		// switches back to it.
+		(Some(editor_pane), Some(data_pane)) if panes.len() == 2 => {
+			let framed = |pane: pane_grid::Pane, pane_type: PaneType| {
+				let is_focused = focused_pane == Some(pane);
+				container(pane_body(pane_type, is_focused))
+					.padding(2)
+					.style(move |_| pane_style(is_focused, transparent, colors))
+			};
+			split_view(
+				framed(editor_pane, PaneType::CodeEditor),
+				framed(data_pane, PaneType::DataTable),
+				editor_split,
+				Message::SplitResized,
+				colors,
+			)
+		}
+		_ => pane_grid(panes, |id, pane_type, _is_maximized| {
+			let is_focused = focused_pane == Some(id);
+			pane_grid::Content::new(pane_body(*pane_type, is_focused))
+				.title_bar(pane_title_bar(*pane_type, colors))
+				.style(move |_| pane_style(is_focused, transparent, colors))
+		})
+		.width(Fill)
+		.height(Fill)
+		.spacing(SPLITTER_WIDTH)
+		.on_click(Message::PaneClicked)
+		.on_drag(Message::PaneDragged)
+		.on_resize(10, Message::PaneResized)
+		.style(move |_| {
+			let split = pane_grid::Line {
+				color: colors.border_accent,
+				width: SPLITTER_WIDTH,
+			};
+			pane_grid::Style {
+				hovered_region: pane_grid::Highlight {
+					background: Background::Color(Color { a: 0.3, ..colors.border_accent }),
 					border: border::Border {
-						color: colors::BORDER_PRIMARY,
-						width: 1.0,
+						color: colors.border_accent,
+						width: 2.0,
 						radius: 5.0.into(),
 					},
-					..Default::default()
-				}),
-		))
-		.title_bar(pane_title_bar(PaneType::CodeEditor)),
-		PaneType::DataTable => pane_grid::Content::new(center(
-			Table::new(data_frame, 0).show_column_types(show_column_types),
-		))
-		.title_bar(pane_title_bar(PaneType::DataTable)),
-		PaneType::Dashboard => pane_grid::Content::new(if let Some(dashboard) = dashboard {
-			dashboard_view(dashboard)
-		} else {
-			center(text("").color(colors::TEXT_SECONDARY)).into()
+				},
+				picked_split: split,
+				hovered_split: split,
+			}
 		})
-		.title_bar(pane_title_bar(PaneType::Dashboard)),
-	})
-	.width(Fill)
-	.height(Fill)
-	.spacing(2)
-	.on_drag(Message::PaneDragged)
-	.on_resize(10, Message::PaneResized);
-	let main_content = container(main_pane).padding(4).width(Fill);
+		.into(),
+	};
+	// The pane grid has no background of its own; this shows through the
+	// gaps between panes as the idle splitter bars.
+	let splitter_bars = container(space()).width(Fill).height(Fill).style(move |_| container::Style {
+		background: (!transparent).then(|| colors.border_dim.into()),
+		..Default::default()
+	});
+	let main_content = container(stack![splitter_bars, main_pane]).padding(4).width(Fill);
 	let status_bar = app_status_bar(
 		status_msg,
 		status_error,
-		status_df_size,
-		status_time_elapsed,
+		status_info,
 		adapter_state,
 	)
+	.style(StatusBarStyle::from(colors))
 	.view();
 	let main_window = window_decorations(
 		column![main_content, status_bar],
 		menu_state,
 		saved_connections,
 		saved_statements,
+		theme,
+		is_fullscreen,
 	);
-	let adapter_modal = adapter_view(adapter_state);
+	let adapter_modal = adapter_view(adapter_state, colors);
 	let password_modal: Element<Message> = if showing_password_prompt {
-		password_prompt_view(password_entry, password_entry_error)
+		password_prompt_view(password_entry, password_entry_error, colors)
 	} else {
 		container(text("")).into()
 	};
@@ -259,23 +891,494 @@ pub fn main_screen<'a>(
 			settings_error,
 			is_password_protected,
 			show_column_types,
+			prefs,
+			colors,
 		)
 	} else {
 		container(text("")).into()
 	};
 	let save_statement_modal: Element<Message> = if showing_save_statement_dialog {
-		save_statement_dialog_view(save_statement_name, editing_statement_id.is_some())
+		save_statement_dialog_view(save_statement_name, editing_statement_id.is_some(), colors)
+	} else {
+		container(text("")).into()
+	};
+	let column_menu_overlay: Element<Message> = if let Some((col_idx, position)) = column_menu {
+		column_menu_view(data_frame, hidden_cols, image_cols, numeric_formats, col_idx, position, colors)
 	} else {
 		container(text("")).into()
 	};
-	stack![main_window, adapter_modal, password_modal, settings_modal, save_statement_modal].into()
+	let command_palette_overlay: Element<Message> = match command_palette {
+		Some((query, selected)) => command_palette_view(command_palette_frame, query, selected, table_theme, colors),
+		None => container(text("")).into(),
+	};
+	let cell_menu_overlay: Element<Message> = if let Some((row, col, position)) = cell_menu {
+		cell_menu_view(row, col, position, colors)
+	} else {
+		container(text("")).into()
+	};
+	stack![
+		main_window,
+		column_menu_overlay,
+		cell_menu_overlay,
+		adapter_modal,
+		password_modal,
+		settings_modal,
+		save_statement_modal,
+		command_palette_overlay
+	]
+	.into()
+}
+
+pub fn table_search_id() -> iced::widget::Id {
+	iced::widget::Id::new("table-search")
+}
+
+fn schema_tree<'a>(
+	schema: &'a [TableSchema],
+	expanded: &'a BTreeSet<usize>,
+	cursor: Option<usize>,
+	colors: &'static Colors,
+) -> Element<'a, Message> {
+	if schema.is_empty() {
+		return center(text("No tables found.").color(colors.text_placeholder)).into();
+	}
+	let rows = schema_nodes(schema, expanded)
+		.into_iter()
+		.enumerate()
+		.map(|(node_idx, (table_idx, col))| {
+			let table = &schema[table_idx];
+			let content: Element<'a, Message> = match col {
+				None => row![
+					button(text(if expanded.contains(&table_idx) { "▾" } else { "▸" }).size(14))
+						.padding([0, 4])
+						.style(button::text)
+						.on_press(Message::ToggleSchemaTable(table_idx)),
+					button(text(table.label()).size(14).color(colors.text_primary))
+						.padding([0, 4])
+						.style(button::text)
+						.on_press(Message::SchemaTableClicked(table_idx)),
+				]
+				.align_y(Center)
+				.into(),
+				Some(col_idx) => {
+					let (name, dtype) = &table.columns[col_idx];
+					button(
+						row![
+							space::horizontal().width(28),
+							text(name.as_str()).size(13).color(colors.text_secondary),
+							text(dtype.as_str()).size(12).color(colors.table_type_label),
+						]
+						.spacing(8)
+						.align_y(Center),
+					)
+					.padding(0)
+					.style(button::text)
+					.on_press(Message::SchemaColumnClicked(table_idx, col_idx))
+					.into()
+				}
+			};
+			container(content)
+				.width(Fill)
+				.padding([1, 4])
+				.style(move |_| container::Style {
+					background: (cursor == Some(node_idx)).then_some(Background::Color(colors.table_row_selected)),
+					..Default::default()
+				})
+				.into()
+		});
+	scrollable(column(rows).width(Fill)).height(Fill).into()
+}
+
+/// The previous and latest results side by side, each with a join key
+/// picker, above the key overlap counts.
+fn join_preview_view<'a>(
+	left: Option<&'a DataFrame>,
+	right: &'a DataFrame,
+	state: JoinPreviewState,
+	table_theme: TableTheme,
+	colors: &'static Colors,
+) -> Element<'a, Message> {
+	let side = |data_frame: &'a DataFrame, join_side: JoinSide, key: Option<usize>| -> Element<'a, Message> {
+		let names: Vec<String> = data_frame
+			.get_column_names()
+			.iter()
+			.map(|name| name.to_string())
+			.collect();
+		let selected = key.and_then(|col_idx| names.get(col_idx).cloned());
+		let lookup = names.clone();
+		column![
+			pick_list(names, selected, move |name| {
+				let col_idx = lookup.iter().position(|n| *n == name).unwrap_or_default();
+				Message::SetJoinKey(join_side, col_idx)
+			})
+			.placeholder("Join key")
+			.text_size(14),
+			Table::new(data_frame, 0).theme(table_theme),
+		]
+		.spacing(4)
+		.width(FillPortion(1))
+		.into()
+	};
+	let Some(left) = left else {
+		return center(text("Run two queries to preview a join.").color(colors.text_secondary)).into();
+	};
+	let summary = match state.counts {
+		Some((left_only, both, right_only)) => {
+			format!("Left only: {left_only} | Both: {both} | Right only: {right_only}")
+		}
+		None if state.left_key.is_some() && state.right_key.is_some() => "Counting...".to_string(),
+		None => "Pick a join key on each side.".to_string(),
+	};
+	column![
+		row![
+			side(left, JoinSide::Left, state.left_key),
+			side(right, JoinSide::Right, state.right_key),
+		]
+		.spacing(8)
+		.height(Fill),
+		text(summary).size(14).color(colors.text_primary),
+	]
+	.spacing(6)
+	.padding(4)
+	.into()
+}
+
+/// A table under a toolbar with export buttons and a search field. Search
+/// hits are highlighted in the table, which scrolls to the current one.
+#[allow(clippy::too_many_arguments)]
+pub fn data_panel<'a>(
+	table: Table<'a, Message>,
+	search_query: &'a str,
+	search_hits: &'a [(usize, usize)],
+	search_index: Option<usize>,
+	delimiter: char,
+	summary_mode: Option<SummaryMode>,
+	query_snapshots: &'a VecDeque<QuerySnapshot>,
+	colors: &'static Colors,
+) -> Element<'a, Message> {
+	let mut table = table.search_hits(search_hits);
+	if let Some(&(row, _col)) = search_index.and_then(|i| search_hits.get(i)) {
+		table = table.scroll_to_row(row);
+	}
+	let counter = match search_index {
+		Some(i) => format!("{}/{}", i + 1, search_hits.len()),
+		None if search_query.is_empty() => String::new(),
+		None => "0/0".to_string(),
+	};
+	let mut search = row![
+		styled_text_input("Find in table", search_query, colors)
+			.id(table_search_id())
+			.padding(6)
+			.size(14)
+			.width(Length::Fixed(220.0))
+			.on_input(Message::TableSearchChanged)
+			.on_submit(Message::TableSearchNext),
+		text(counter).size(14).color(colors.text_secondary),
+		styled_button("▲", Message::TableSearchPrev, (32, 32), colors),
+		styled_button("▼", Message::TableSearchNext, (32, 32), colors),
+	]
+	.spacing(6)
+	.align_y(Alignment::Center);
+	if !search_query.is_empty() {
+		search = search.push(styled_button("✕", Message::CloseTableSearch, (32, 32), colors));
+	}
+	let snapshots = query_snapshots
+		.iter()
+		.take(QUERY_SNAPSHOT_MENU_LEN)
+		.enumerate()
+		.map(|(index, snapshot)| {
+			let mut sql = snapshot.sql.split_whitespace().collect::<Vec<_>>().join(" ");
+			if sql.chars().count() > QUERY_SNAPSHOT_LABEL_CHARS {
+				sql = sql.chars().take(QUERY_SNAPSHOT_LABEL_CHARS).collect::<String>() + "…";
+			}
+			let label = format!("{}  {sql}", snapshot.taken_at.format("%H:%M:%S"));
+			QuerySnapshotOption { index, label }
+		})
+		.collect::<Vec<_>>();
+	let toolbar = container(
+		row![
+			styled_button("Export CSV", Message::ExportCSV, (130, 32), colors),
+			styled_button("Export Parquet", Message::ExportParquet, (160, 32), colors),
+			text("Delimiter").size(14).color(colors.text_secondary),
+			pick_list(&Delimiter::ALL[..], Delimiter::from_char(delimiter), |d| {
+				Message::SetDelimiter(d.as_char())
+			})
+			.text_size(14),
+			pick_list(snapshots, None::<QuerySnapshotOption>, |option| {
+				Message::RestoreQuerySnapshot(option.index)
+			})
+			.placeholder("Query History")
+			.text_size(14),
+			pick_list(SummaryModeOption::all(), Some(SummaryModeOption(summary_mode)), |option| {
+				Message::SetSummaryMode(option.0)
+			})
+			.text_size(14),
+			space::horizontal().width(Fill),
+			search,
+		]
+		.spacing(6)
+		.align_y(Alignment::Center),
+	)
+	.padding(4)
+	.width(Fill)
+	.style(|_| container::Style {
+		background: Some(colors.bg_secondary.into()),
+		..Default::default()
+	});
+	column![toolbar, table].spacing(2).into()
+}
+
+fn code_panel<'a>(code_editor: &'a CodeEditor, find_replace: &'a FindReplaceState, colors: &'static Colors) -> Element<'a, Message> {
+	let editor = code_editor.view().map(Message::CodeEditEvent);
+	if !find_replace.open {
+		return column![button_bar(colors), editor].spacing(2).into();
+	}
+	let (current, total) = code_editor.search_position();
+	let bar = container(
+		row![
+			styled_text_input("Find", &find_replace.find, colors)
+				.id(find_replace_id())
+				.padding(6)
+				.size(14)
+				.width(Length::Fixed(200.0))
+				.on_input(Message::FindReplaceQueryChanged)
+				.on_submit(Message::FindReplaceNext),
+			styled_text_input("Replace", &find_replace.replace, colors)
+				.padding(6)
+				.size(14)
+				.width(Length::Fixed(200.0))
+				.on_input(Message::FindReplaceWithChanged)
+				.on_submit(Message::FindReplaceCurrent),
+			text(format!("{current} of {total} matches")).size(14).color(colors.text_secondary),
+			space::horizontal().width(Fill),
+			styled_button("Previous", Message::FindReplacePrev, (90, 32), colors),
+			styled_button("Next", Message::FindReplaceNext, (70, 32), colors),
+			styled_button("Replace", Message::FindReplaceCurrent, (90, 32), colors),
+			styled_button("Replace All", Message::FindReplaceAll, (110, 32), colors),
+			styled_button("✕", Message::FindReplaceClose, (32, 32), colors),
+		]
+		.spacing(6)
+		.align_y(Alignment::Center),
+	)
+	.padding(4)
+	.width(Fill)
+	.style(|_| container::Style {
+		background: Some(colors.bg_secondary.into()),
+		..Default::default()
+	});
+	column![button_bar(colors), bar, editor].spacing(2).into()
+}
+
+/// Actions on the editor's query, above the editor.
+fn button_bar<'a>(colors: &'static Colors) -> Element<'a, Message> {
+	container(
+		row![
+			styled_button("Run", Message::Run, (80, 32), colors),
+			styled_button("Explain", Message::Explain, (90, 28), colors),
+			styled_button("Export", Message::ExportCSV, (90, 28), colors),
+		]
+		.spacing(6)
+		.align_y(Alignment::Center),
+	)
+	.padding(4)
+	.width(Fill)
+	.style(|_| container::Style {
+		background: Some(colors.bg_secondary.into()),
+		..Default::default()
+	})
+	.into()
+}
+
+pub fn find_replace_id() -> iced::widget::Id {
+	iced::widget::Id::new("code-find-replace")
+}
+
+pub fn cell_edit_id() -> iced::widget::Id {
+	iced::widget::Id::new("table-cell-edit")
+}
+
+fn cell_edit_bar<'a>(
+	data_frame: &'a DataFrame,
+	row: usize,
+	col: usize,
+	value: &'a str,
+	colors: &'static Colors,
+) -> Element<'a, Message> {
+	let col_name = data_frame
+		.get_column_names()
+		.get(col)
+		.map(|name| name.to_string())
+		.unwrap_or_default();
+	let submit = Message::CellEdited {
+		row,
+		col,
+		value: value.to_string(),
+	};
+	let bar = container(
+		row![
+			text(format!("{col_name} [{}]", row + 1))
+				.size(14)
+				.color(colors.text_secondary),
+			styled_text_input("Value", value, colors)
+				.id(cell_edit_id())
+				.padding(6)
+				.size(14)
+				.width(Length::Fixed(280.0))
+				.on_input(Message::CellEditChanged)
+				.on_submit(submit.clone()),
+			styled_button("✓", submit, (32, 32), colors),
+			styled_button("✕", Message::CancelCellEdit, (32, 32), colors),
+		]
+		.spacing(6)
+		.align_y(Alignment::Center),
+	)
+	.padding(6)
+	.style(|_| container::Style {
+		background: Some(colors.bg_modal.into()),
+		border: border::Border {
+			color: colors.border_primary,
+			width: 1.0,
+			radius: 5.0.into(),
+		},
+		..Default::default()
+	});
+	container(opaque(bar))
+		.width(Fill)
+		.height(Fill)
+		.align_x(Alignment::Start)
+		.align_y(Alignment::End)
+		.padding([20, 20])
+		.into()
+}
+
+fn column_menu_view<'a>(
+	data_frame: &'a DataFrame,
+	hidden_cols: &'a BTreeSet<usize>,
+	image_cols: &'a BTreeSet<usize>,
+	numeric_formats: &'a HashMap<usize, NumericFormat>,
+	col_idx: usize,
+	position: Point,
+	colors: &'static Colors,
+) -> Element<'a, Message> {
+	// Image cells load local files and URLs named by the data, so each column
+	// has to be switched on explicitly.
+	let show_images = checkbox(image_cols.contains(&col_idx))
+		.label("Show as images")
+		.size(14)
+		.text_size(14)
+		.on_toggle(move |enabled| Message::ToggleImageColumn(col_idx, enabled));
+	let number_format = row![
+		text("Numbers").size(14),
+		pick_list(
+			NumericFormatOption::all(),
+			Some(NumericFormatOption(numeric_formats.get(&col_idx).cloned())),
+			move |option| Message::SetNumericFormat(col_idx, option.0),
+		)
+		.text_size(14),
+	]
+	.spacing(8)
+	.align_y(Alignment::Center);
+	let items = data_frame
+		.get_column_names()
+		.into_iter()
+		.enumerate()
+		.map(|(i, name)| {
+			let weight = if i == col_idx {
+				font::Weight::Bold
+			} else {
+				font::Weight::Normal
+			};
+			checkbox(!hidden_cols.contains(&i))
+				.label(name.as_str())
+				.size(14)
+				.text_size(14)
+				.font(Font {
+					weight,
+					..Font::DEFAULT
+				})
+				.on_toggle(move |visible| Message::ToggleColumnVisibility(i, visible))
+				.into()
+		});
+	let menu = container(column![
+		column![show_images, number_format].spacing(6).padding([8, 10]),
+		scrollable(column(items).spacing(6).padding(10)),
+	])
+		.width(Length::Fixed(220.0))
+		.max_height(360)
+		.style(|_| container::Style {
+			background: Some(colors.bg_modal.into()),
+			border: border::Border {
+				color: colors.border_primary,
+				width: 1.0,
+				radius: 5.0.into(),
+			},
+			..Default::default()
+		});
+	mouse_area(
+		container(opaque(menu))
+			.padding(Padding {
+				top: position.y,
+				left: position.x,
+				..Padding::ZERO
+			})
+			.width(Fill)
+			.height(Fill),
+	)
+	.on_press(Message::CloseColumnMenu)
+	.on_right_press(Message::CloseColumnMenu)
+	.into()
+}
+
+fn cell_menu_view<'a>(row: usize, col: usize, position: Point, colors: &'static Colors) -> Element<'a, Message> {
+	let item = |label: &'a str, message: Message| {
+		button(text(label).size(14))
+			.width(Fill)
+			.padding([4, 10])
+			.style(|_theme: &Theme, status: button::Status| button::Style {
+				background: match status {
+					button::Status::Hovered => Some(Background::Color(colors.bg_button_hover)),
+					_ => None,
+				},
+				text_color: colors.text_primary,
+				..button::Style::default()
+			})
+			.on_press(message)
+	};
+	let menu = container(column![
+		item("Copy Cell", Message::CopyCell(row, col)),
+		item("Copy Row", Message::CopyRow(row)),
+		item("Copy Column", Message::CopyColumn(col)),
+	])
+	.width(Length::Fixed(160.0))
+	.padding(4)
+	.style(|_| container::Style {
+		background: Some(colors.bg_modal.into()),
+		border: border::Border {
+			color: colors.border_primary,
+			width: 1.0,
+			radius: 5.0.into(),
+		},
+		..Default::default()
+	});
+	mouse_area(
+		container(opaque(menu))
+			.padding(Padding {
+				top: position.y,
+				left: position.x,
+				..Padding::ZERO
+			})
+			.width(Fill)
+			.height(Fill),
+	)
+	.on_press(Message::CloseCellMenu)
+	.on_right_press(Message::CloseCellMenu)
+	.into()
 }
 
 fn app_status_bar(
 	status_msg: &str,
 	status_error: &str,
-	status_df_size: Option<(usize, usize)>,
-	status_time_elapsed: Option<f64>,
+	status_info: StatusInfo,
 	adapter_state: &AdapterState,
 ) -> StatusBar {
 	let (connection_label, connection_tone) = match adapter_state.stage {
@@ -299,31 +1402,78 @@ fn app_status_bar(
 				.max_chars(56)
 				.fill_portion(2),
 		);
-	if let Some((rows, cols)) = status_df_size {
+	if let Some((rows, cols)) = status_info.table_size {
 		bar = bar.right(
-			Segment::label_value("table", format!("{rows} x {cols}"), Tone::Normal)
-				.reserve_chars(18),
+			Segment::label_value(
+				"table",
+				format!("{} rows × {} cols", format_count(rows), format_count(cols)),
+				Tone::Normal,
+			)
+			.reserve_chars(28),
 		);
 	}
-	if let Some(time_elapsed) = status_time_elapsed {
+	if status_info.selected_rows > 0 {
 		bar = bar.right(
-			Segment::label_value("time", format!("{time_elapsed:.3}s"), Tone::Normal)
+			Segment::label_value("selected", format_count(status_info.selected_rows), Tone::Accent)
 				.reserve_chars(12),
 		);
 	}
+	if status_info.active_filters > 0 {
+		bar = bar.right(
+			Segment::label_value("filters", format_count(status_info.active_filters), Tone::Accent)
+				.reserve_chars(11),
+		);
+	}
+	if status_info.hidden_cols > 0 {
+		bar = bar.right(
+			Segment::label_value("hidden", format_count(status_info.hidden_cols), Tone::Warning)
+				.reserve_chars(10),
+		);
+	}
+	if let Some(differing) = status_info.differing_cells {
+		bar = bar.right(
+			Segment::toned_text(
+				format!("{} cells differ", format_count(differing)),
+				if differing > 0 { Tone::Warning } else { Tone::Success },
+			)
+			.reserve_chars(20),
+		);
+	}
+	if let Some(elapsed_ms) = status_info.elapsed_ms {
+		let elapsed = if elapsed_ms < 1000 {
+			format!("{elapsed_ms}ms")
+		} else {
+			format!("{:.3}s", elapsed_ms as f64 / 1000.0)
+		};
+		bar = bar.right(Segment::label_value("query", elapsed, Tone::Normal).reserve_chars(12));
+	}
 	bar
 }
 
+pub fn format_count(n: usize) -> String {
+	let digits = n.to_string();
+	let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+	for (i, ch) in digits.chars().enumerate() {
+		if i > 0 && (digits.len() - i).is_multiple_of(3) {
+			out.push(',');
+		}
+		out.push(ch);
+	}
+	out
+}
+
 pub fn menu_bar<'a>(
 	menu_state: &'a MenuState,
 	saved_connections: &'a [SavedConnection],
 	saved_statements: &'a [SavedStatement],
+	colors: &'static Colors,
 ) -> Element<'a, Message> {
 	Element::from(MenuBar::new(
 		build_menu_roots(saved_connections, saved_statements),
 		menu_state,
 	)
-	.font_policy(MenuFontPolicy::SystemWithFallback))
+	.font_policy(MenuFontPolicy::SystemWithFallback)
+	.colors(colors))
 	.map(Message::Menu)
 }
 
@@ -340,6 +1490,14 @@ fn build_menu_roots(
 					id: "connect:new".into(),
 					label: "New".into(),
 				},
+				MenuItem::Action {
+					id: "connect:schema".into(),
+					label: "Browse Schema".into(),
+				},
+				MenuItem::Action {
+					id: "connect:reset".into(),
+					label: "Reset".into(),
+				},
 				MenuItem::Separator,
 				MenuItem::Submenu {
 					id: "connect:saved".into(),
@@ -356,10 +1514,34 @@ fn build_menu_roots(
 					id: "code:run".into(),
 					label: "Run  (Ctrl+Enter)".into(),
 				},
+				MenuItem::Action {
+					id: "code:explain".into(),
+					label: "Explain".into(),
+				},
 				MenuItem::Action {
 					id: "code:save".into(),
 					label: "Save...".into(),
 				},
+				MenuItem::Action {
+					id: "code:undo".into(),
+					label: "Undo  (Ctrl+Z)".into(),
+				},
+				MenuItem::Action {
+					id: "code:redo".into(),
+					label: "Redo  (Ctrl+Y)".into(),
+				},
+				MenuItem::Action {
+					id: "code:comment".into(),
+					label: "Toggle Comment  (Ctrl+/)".into(),
+				},
+				MenuItem::Action {
+					id: "code:find_replace".into(),
+					label: "Find and Replace  (Ctrl+H)".into(),
+				},
+				MenuItem::Action {
+					id: "code:history".into(),
+					label: "Query History  (Ctrl+Alt+H)".into(),
+				},
 				MenuItem::Separator,
 				MenuItem::Submenu {
 					id: "code:saved".into(),
@@ -400,6 +1582,37 @@ fn build_menu_roots(
 				},
 			],
 		},
+		MenuRoot {
+			id: "table".into(),
+			label: "Table".into(),
+			items: vec![
+				MenuItem::Action {
+					id: "table:export:csv".into(),
+					label: "Export CSV  (Ctrl+E)".into(),
+				},
+				MenuItem::Action {
+					id: "table:export:parquet".into(),
+					label: "Export Parquet".into(),
+				},
+				MenuItem::Action {
+					id: "table:export:markdown".into(),
+					label: "Copy as Markdown  (Ctrl+Shift+C)".into(),
+				},
+				MenuItem::Separator,
+				MenuItem::Action {
+					id: "table:filter".into(),
+					label: "Toggle Filter Row".into(),
+				},
+				MenuItem::Action {
+					id: "table:compare".into(),
+					label: "Compare With Current Results".into(),
+				},
+				MenuItem::Action {
+					id: "table:join_preview".into(),
+					label: "Join Preview".into(),
+				},
+			],
+		},
 		MenuRoot {
 			id: "settings".into(),
 			label: "Settings".into(),
@@ -473,17 +1686,17 @@ fn build_saved_statement_items(saved_statements: &[SavedStatement]) -> Vec<MenuI
 		.collect()
 }
 
-fn dashboard_view<'a>(state: &'a pane_grid::State<PlotState>) -> Element<'a, Message> {
+fn dashboard_view<'a>(state: &'a pane_grid::State<PlotState>, colors: &'static Colors) -> Element<'a, Message> {
 	pane_grid(state, |id, plot_state, _is_maximized| {
-		pane_grid::Content::new(plot_view(id, plot_state)).title_bar(
+		pane_grid::Content::new(plot_view(id, plot_state, colors)).title_bar(
 			pane_grid::TitleBar::new(
 				row![
 					container(space::horizontal().width(Fill))
 						.padding(5)
 						.width(Fill)
 						.style(|_| container::Style {
-							background: Some(Background::Color(colors::BG_SECONDARY)),
-							text_color: Some(colors::TEXT_PRIMARY),
+							background: Some(Background::Color(colors.bg_secondary)),
+							text_color: Some(colors.text_primary),
 							..Default::default()
 						}),
 					button(
@@ -500,13 +1713,13 @@ fn dashboard_view<'a>(state: &'a pane_grid::State<PlotState>) -> Element<'a, Mes
 					.height(26)
 					.style(|_theme: &Theme, status: button::Status| match status {
 						button::Status::Hovered => button::Style {
-							background: Some(Background::Color(colors::BRAND_PURPLE)),
-							text_color: colors::TEXT_TITLE_BUTTON_HOVER,
+							background: Some(Background::Color(colors.brand_purple)),
+							text_color: colors.text_title_button_hover,
 							..button::Style::default()
 						},
 						_ => button::Style {
 							background: Some(Background::Color(Color::TRANSPARENT)),
-							text_color: colors::TEXT_TITLE_BUTTON,
+							text_color: colors.text_title_button,
 							..button::Style::default()
 						},
 					})
@@ -525,7 +1738,7 @@ fn dashboard_view<'a>(state: &'a pane_grid::State<PlotState>) -> Element<'a, Mes
 	.into()
 }
 
-fn plot_view<'a>(id: pane_grid::Pane, state: &'a PlotState) -> Element<'a, Message> {
+fn plot_view<'a>(id: pane_grid::Pane, state: &'a PlotState, colors: &'static Colors) -> Element<'a, Message> {
 	let data_canvas: Element<PlotMessage> = canvas(PlotWidget {
 		kernel: state.kernel.as_ref(),
 		title: state.current_plot_type.to_string(),
@@ -576,7 +1789,7 @@ fn plot_view<'a>(id: pane_grid::Pane, state: &'a PlotState) -> Element<'a, Messa
 		);
 	}
 	if state.settings_open {
-		let settings_panel = plot_settings_panel(id, state);
+		let settings_panel = plot_settings_panel(id, state, colors);
 		let modal_overlay = container(opaque(
 			row![space::horizontal(), settings_panel].width(Fill),
 		))
@@ -600,7 +1813,7 @@ fn plot_view<'a>(id: pane_grid::Pane, state: &'a PlotState) -> Element<'a, Messa
 		.into()
 }
 
-fn plot_settings_panel<'a>(id: pane_grid::Pane, state: &'a PlotState) -> Element<'a, Message> {
+fn plot_settings_panel<'a>(id: pane_grid::Pane, state: &'a PlotState, colors: &'static Colors) -> Element<'a, Message> {
 	let plot_event = move |pm| Message::PlotEvent(id, pm);
 	container(
 		column![
@@ -1273,7 +2486,7 @@ fn plot_settings_panel<'a>(id: pane_grid::Pane, state: &'a PlotState) -> Element
 					background: Some(Background::Color(Color::TRANSPARENT)),
 					border: border::Border::default(),
 					scroller: scrollable::Scroller {
-						background: Background::Color(colors::SCROLLBAR_THUMB),
+						background: Background::Color(colors.scrollbar_thumb),
 						border: border::Border {
 							radius: 2.0.into(),
 							..Default::default()
@@ -1284,7 +2497,7 @@ fn plot_settings_panel<'a>(id: pane_grid::Pane, state: &'a PlotState) -> Element
 					background: Some(Background::Color(Color::TRANSPARENT)),
 					border: border::Border::default(),
 					scroller: scrollable::Scroller {
-						background: Background::Color(colors::SCROLLBAR_THUMB),
+						background: Background::Color(colors.scrollbar_thumb),
 						border: border::Border {
 							radius: 2.0.into(),
 							..Default::default()
@@ -1366,29 +2579,37 @@ fn window_decorations<'a>(
 	menu_state: &'a MenuState,
 	saved_connections: &'a [SavedConnection],
 	saved_statements: &'a [SavedStatement],
+	theme: ThemeMode,
+	is_fullscreen: bool,
 ) -> Element<'a, Message> {
+	let colors = theme.colors();
+	let title: Element<'a, Message> = if is_fullscreen {
+		space().into()
+	} else {
+		title_bar(theme)
+	};
 	let resize_thin = 6;
 	let resize_thick = 60;
 	let resize_area_northeast_top =
-		styled_resize_area(resize_thick, resize_thin / 2, Direction::NorthEast);
-	let resize_area_north = styled_resize_area(Fill, resize_thin / 2, Direction::North);
+		styled_resize_area(resize_thick, resize_thin / 2, Direction::NorthEast, colors);
+	let resize_area_north = styled_resize_area(Fill, resize_thin / 2, Direction::North, colors);
 	let resize_area_northwest_top =
-		styled_resize_area(resize_thick, resize_thin / 2, Direction::NorthWest);
+		styled_resize_area(resize_thick, resize_thin / 2, Direction::NorthWest, colors);
 	let resize_area_northwest_side =
-		styled_resize_area(resize_thin, resize_thick, Direction::NorthWest);
-	let resize_area_west = styled_resize_area(resize_thin, Fill, Direction::West);
+		styled_resize_area(resize_thin, resize_thick, Direction::NorthWest, colors);
+	let resize_area_west = styled_resize_area(resize_thin, Fill, Direction::West, colors);
 	let resize_area_southwest_side =
-		styled_resize_area(resize_thin, resize_thick, Direction::SouthWest);
+		styled_resize_area(resize_thin, resize_thick, Direction::SouthWest, colors);
 	let resize_area_southwest_bottom =
-		styled_resize_area(resize_thick, resize_thin, Direction::SouthWest);
-	let resize_area_south = styled_resize_area(Fill, resize_thin, Direction::South);
+		styled_resize_area(resize_thick, resize_thin, Direction::SouthWest, colors);
+	let resize_area_south = styled_resize_area(Fill, resize_thin, Direction::South, colors);
 	let resize_area_southeast_bottom =
-		styled_resize_area(resize_thick, resize_thin, Direction::SouthEast);
+		styled_resize_area(resize_thick, resize_thin, Direction::SouthEast, colors);
 	let resize_area_northeast_side =
-		styled_resize_area(resize_thin, resize_thick, Direction::NorthEast);
-	let resize_area_east = styled_resize_area(resize_thin, Fill, Direction::East);
+		styled_resize_area(resize_thin, resize_thick, Direction::NorthEast, colors);
+	let resize_area_east = styled_resize_area(resize_thin, Fill, Direction::East, colors);
 	let resize_area_southeast_side =
-		styled_resize_area(resize_thin, resize_thick, Direction::SouthEast);
+		styled_resize_area(resize_thin, resize_thick, Direction::SouthEast, colors);
 	column![
 		row![
 			resize_area_northwest_top,
@@ -1402,13 +2623,13 @@ fn window_decorations<'a>(
 				resize_area_southwest_side
 			],
 			column![
-				row![title_bar()],
+				row![title],
 				stack![
 					column![
 						space::vertical().height(32),
 						underlay.into(),
 					],
-					row![menu_bar(menu_state, saved_connections, saved_statements)],
+					row![menu_bar(menu_state, saved_connections, saved_statements, colors)],
 				],
 				row![
 					resize_area_southwest_bottom,
@@ -1429,60 +2650,103 @@ fn window_decorations<'a>(
 pub fn styled_text_input<'a, Message: Clone + 'a>(
 	default_str: &str,
 	input_str: &str,
+	colors: &'static Colors,
 ) -> TextInput<'a, Message> {
 	text_input(default_str, input_str)
 		.padding(10)
 		.size(18)
 		.style(|_theme: &Theme, status: text_input::Status| match status {
 			text_input::Status::Focused { .. } => text_input::Style {
-				background: Background::Color(colors::BG_INPUT_FOCUS),
+				background: Background::Color(colors.bg_input_focus),
 				border: border::Border {
-					color: colors::BORDER_ACCENT,
+					color: colors.border_accent,
 					width: 2.0,
 					radius: 5.0.into(),
 				},
-				icon: colors::TEXT_SECONDARY,
-				placeholder: colors::TEXT_PLACEHOLDER_HOVER,
-				value: colors::TEXT_SECONDARY,
-				selection: colors::SELECTION,
+				icon: colors.text_secondary,
+				placeholder: colors.text_placeholder_hover,
+				value: colors.text_secondary,
+				selection: colors.selection,
 			},
 			text_input::Status::Hovered => text_input::Style {
-				background: Background::Color(colors::BG_INPUT_HOVER),
+				background: Background::Color(colors.bg_input_hover),
 				border: border::Border {
-					color: colors::BORDER_HOVER,
+					color: colors.border_hover,
 					width: 1.5,
 					radius: 5.0.into(),
 				},
-				icon: colors::TEXT_SECONDARY,
-				placeholder: colors::TEXT_PLACEHOLDER,
-				value: colors::TEXT_SECONDARY,
-				selection: colors::SELECTION,
+				icon: colors.text_secondary,
+				placeholder: colors.text_placeholder,
+				value: colors.text_secondary,
+				selection: colors.selection,
 			},
 			_ => text_input::Style {
-				background: Background::Color(colors::BG_INPUT),
+				background: Background::Color(colors.bg_input),
 				border: border::Border {
-					color: colors::BORDER_PRIMARY,
+					color: colors.border_primary,
 					width: 1.0,
 					radius: 5.0.into(),
 				},
-				icon: colors::TEXT_SECONDARY,
-				placeholder: colors::TEXT_PLACEHOLDER,
-				value: colors::TEXT_SECONDARY,
-				selection: colors::SELECTION,
+				icon: colors.text_secondary,
+				placeholder: colors.text_placeholder,
+				value: colors.text_secondary,
+				selection: colors.selection,
 			},
 		})
 }
 
+/// Two side-by-side panes with a draggable splitter between them; a lighter
+/// alternative to `pane_grid` when the layout is fixed.
+pub fn split_view<'a>(
+	left: impl Into<Element<'a, Message>>,
+	right: impl Into<Element<'a, Message>>,
+	split: SplitState,
+	on_resize: impl Fn(f32) -> Message + 'a,
+	colors: &'static Colors,
+) -> Element<'a, Message> {
+	let left_portion = (split.ratio.clamp(SPLIT_RATIO_MIN, SPLIT_RATIO_MAX) * 1000.0).round() as u16;
+	let splitter = mouse_area(
+		container(space::horizontal().width(SPLITTER_WIDTH).height(Fill)).style(move |_| container::Style {
+			background: Some(
+				if split.dragging { colors.border_hover } else { colors.border_dim }.into(),
+			),
+			..Default::default()
+		}),
+	)
+	.interaction(mouse::Interaction::ResizingHorizontally)
+	.on_press(Message::SplitDragStarted);
+	let content = row![
+		container(left).width(FillPortion(left_portion)).height(Fill),
+		splitter,
+		container(right).width(FillPortion(1000 - left_portion)).height(Fill),
+	]
+	.height(Fill);
+	// The drag ends on a global button release (see `subscription`), since the
+	// cursor may leave the area or the release may be captured by a child.
+	let mut area = mouse_area(
+		sensor(content)
+			.on_show(|size| Message::SplitMeasured(size.width))
+			.on_resize(|size| Message::SplitMeasured(size.width)),
+	);
+	if split.dragging && split.width > 0.0 {
+		area = area.on_move(move |position| {
+			on_resize((position.x / split.width).clamp(SPLIT_RATIO_MIN, SPLIT_RATIO_MAX))
+		});
+	}
+	area.into()
+}
+
 fn styled_resize_area<'a, WT: Into<Length>, HT: Into<Length>>(
 	width: WT,
 	height: HT,
 	direction: Direction,
+	colors: &'static Colors,
 ) -> Element<'a, Message> {
 	mouse_area(
 		container(space::horizontal().width(width).height(height)).style(|_| container::Style {
-			background: Some(colors::BG_SECONDARY.into()),
+			background: Some(colors.bg_secondary.into()),
 			border: border::Border {
-				color: colors::BORDER_DIM,
+				color: colors.border_dim,
 				width: 1.0,
 				radius: 0.0.into(),
 			},
@@ -1503,6 +2767,7 @@ pub fn styled_button<'a, Message: Clone + 'a>(
 	label: &str,
 	msg: Message,
 	size: (u32, u32),
+	colors: &'static Colors,
 ) -> Element<'a, Message> {
 	button(
 		text(label.to_string())
@@ -1521,23 +2786,23 @@ pub fn styled_button<'a, Message: Clone + 'a>(
 		let base = button::primary(theme, status);
 		match status {
 			button::Status::Hovered => button::Style {
-				background: Some(Background::Color(colors::BG_BUTTON_HOVER)),
+				background: Some(Background::Color(colors.bg_button_hover)),
 				border: border::Border {
-					color: colors::BORDER_ACCENT,
+					color: colors.border_accent,
 					width: 2.0,
 					radius: 5.0.into(),
 				},
-				text_color: colors::TEXT_SECONDARY,
+				text_color: colors.text_secondary,
 				..base
 			},
 			_ => button::Style {
-				background: Some(Background::Color(colors::BG_BUTTON)),
+				background: Some(Background::Color(colors.bg_button)),
 				border: border::Border {
-					color: colors::BORDER_PRIMARY,
+					color: colors.border_primary,
 					width: 1.0,
 					radius: 5.0.into(),
 				},
-				text_color: colors::TEXT_SECONDARY,
+				text_color: colors.text_secondary,
 				..base
 			},
 		}
@@ -1546,11 +2811,11 @@ pub fn styled_button<'a, Message: Clone + 'a>(
 	.into()
 }
 
-pub fn adapter_view(adapter_state: &AdapterState) -> Element<'static, Message> {
+pub fn adapter_view(adapter_state: &AdapterState, colors: &'static Colors) -> Element<'static, Message> {
 	match adapter_state.stage {
 		AdapterStage::None => container(text("")).into(),
-		AdapterStage::Unselected => adapter_gallery_view(),
-		AdapterStage::Unconfigured => adapter_configuration_view(adapter_state),
+		AdapterStage::Unselected => adapter_gallery_view(colors),
+		AdapterStage::Unconfigured => adapter_configuration_view(adapter_state, colors),
 		AdapterStage::Configured => container(text("")).into(),
 		AdapterStage::Connected => container(text("")).into(),
 	}
@@ -1559,7 +2824,7 @@ pub fn adapter_view(adapter_state: &AdapterState) -> Element<'static, Message> {
 const MODAL_FILL_PORTION_V: u16 = 30;
 const MODAL_FILL_PORTION_H: u16 = 40;
 
-fn adapter_gallery_view() -> Element<'static, Message> {
+fn adapter_gallery_view(colors: &'static Colors) -> Element<'static, Message> {
 	let dialog: Element<Message> = container(column![
 		center(text("Select Adapter").size(24)),
 		center(
@@ -1567,27 +2832,38 @@ fn adapter_gallery_view() -> Element<'static, Message> {
 				styled_button(
 					"BigQuery",
 					Message::AdapterSelected(AdapterSelection::BigQuery),
-					BUTTON_SIZE_DEFAULT
+					BUTTON_SIZE_DEFAULT,
+					colors,
+				),
+				styled_button(
+					"DuckDB",
+					Message::AdapterSelected(AdapterSelection::DuckDB),
+					BUTTON_SIZE_DEFAULT,
+					colors,
 				),
 				styled_button(
 					"MySQL",
 					Message::AdapterSelected(AdapterSelection::MySQL),
-					BUTTON_SIZE_DEFAULT
+					BUTTON_SIZE_DEFAULT,
+					colors,
 				),
 				styled_button(
 					"Parquet",
 					Message::AdapterSelected(AdapterSelection::Parquet),
-					BUTTON_SIZE_DEFAULT
+					BUTTON_SIZE_DEFAULT,
+					colors,
 				),
 				styled_button(
 					"Postgres",
 					Message::AdapterSelected(AdapterSelection::Postgres),
-					BUTTON_SIZE_DEFAULT
+					BUTTON_SIZE_DEFAULT,
+					colors,
 				),
 				styled_button(
 					"SQLite",
 					Message::AdapterSelected(AdapterSelection::SQLite),
-					BUTTON_SIZE_DEFAULT
+					BUTTON_SIZE_DEFAULT,
+					colors,
 				)
 			]
 			.spacing(20)
@@ -1596,9 +2872,9 @@ fn adapter_gallery_view() -> Element<'static, Message> {
 	.width(FillPortion(MODAL_FILL_PORTION_H))
 	.height(FillPortion(MODAL_FILL_PORTION_V))
 	.style(|_| container::Style {
-		background: Some(colors::BG_MODAL.into()),
+		background: Some(colors.bg_modal.into()),
 		border: border::Border {
-			color: colors::BORDER_PRIMARY,
+			color: colors.border_primary,
 			width: 1.0,
 			radius: 5.0.into(),
 		},
@@ -1617,11 +2893,11 @@ fn adapter_gallery_view() -> Element<'static, Message> {
 	.into()
 }
 
-fn password_prompt_view<'a>(password_entry: &'a str, error: &'a str) -> Element<'a, Message> {
+fn password_prompt_view<'a>(password_entry: &'a str, error: &'a str, colors: &'static Colors) -> Element<'a, Message> {
 	let error_el: Element<Message> = if error.is_empty() {
 		space::vertical().height(24).into()
 	} else {
-		container(text(error).color(colors::DANGER).size(14))
+		container(text(error).color(colors.danger).size(14))
 			.height(24)
 			.into()
 	};
@@ -1631,7 +2907,7 @@ fn password_prompt_view<'a>(password_entry: &'a str, error: &'a str) -> Element<
 			section(
 				"Password",
 				column![
-					styled_text_input("Enter password", password_entry)
+					styled_text_input("Enter password", password_entry, colors)
 						.secure(true)
 						.on_input(Message::PasswordEntryChanged)
 						.on_submit(Message::PasswordEntrySubmit),
@@ -1641,7 +2917,7 @@ fn password_prompt_view<'a>(password_entry: &'a str, error: &'a str) -> Element<
 			),
 			row![
 				space::horizontal(),
-				styled_button("Unlock", Message::PasswordEntrySubmit, BUTTON_SIZE_DEFAULT),
+				styled_button("Unlock", Message::PasswordEntrySubmit, BUTTON_SIZE_DEFAULT, colors),
 			]
 			.align_y(Alignment::Center),
 		]
@@ -1650,9 +2926,9 @@ fn password_prompt_view<'a>(password_entry: &'a str, error: &'a str) -> Element<
 	)
 	.width(Length::Fixed(480.0))
 	.style(|_| container::Style {
-		background: Some(colors::BG_MODAL.into()),
+		background: Some(colors.bg_modal.into()),
 		border: border::Border {
-			color: colors::BORDER_PRIMARY,
+			color: colors.border_primary,
 			width: 1.0,
 			radius: 5.0.into(),
 		},
@@ -1667,7 +2943,53 @@ fn password_prompt_view<'a>(password_entry: &'a str, error: &'a str) -> Element<
 	.into()
 }
 
-fn save_statement_dialog_view<'a>(name: &'a str, is_editing: bool) -> Element<'a, Message> {
+fn command_palette_view<'a>(
+	frame: &'a Arc<DataFrame>,
+	query: &'a str,
+	selected: usize,
+	table_theme: TableTheme,
+	colors: &'static Colors,
+) -> Element<'a, Message> {
+	let highlight = colors.selection;
+	let table = Table::from_arc(Arc::clone(frame), 0)
+		.theme(table_theme)
+		.focused(false)
+		.row_numbers(false)
+		.zebra_striping(false)
+		.scroll_to_row(selected)
+		.empty_message("No matching commands")
+		.highlight_row(Arc::new(move |row| (row == selected).then_some(highlight)))
+		.on_row_click(Message::RunPaletteCommand);
+	let dialog: Element<Message> = container(
+		column![
+			styled_text_input("Type a command", query, colors)
+				.id(command_palette_id())
+				.on_input(Message::CommandPaletteQueryChanged)
+				.on_submit(Message::CommandPaletteSubmit),
+			container(table).height(Length::Fixed(320.0)),
+		]
+		.spacing(10)
+		.padding(12),
+	)
+	.width(Length::Fixed(560.0))
+	.style(|_| container::Style {
+		background: Some(colors.bg_modal.into()),
+		border: border::Border {
+			color: colors.border_primary,
+			width: 1.0,
+			radius: 5.0.into(),
+		},
+		..Default::default()
+	})
+	.into();
+	opaque(column![
+		space::vertical().height(FillPortion((100 - MODAL_FILL_PORTION_V) / 2)),
+		row![space::horizontal(), dialog, space::horizontal()],
+		space::vertical().height(FillPortion((100 - MODAL_FILL_PORTION_V) / 2)),
+	])
+}
+
+fn save_statement_dialog_view<'a>(name: &'a str, is_editing: bool, colors: &'static Colors) -> Element<'a, Message> {
 	let title = if is_editing { "Update Statement" } else { "Save Statement" };
 	let btn_label = if is_editing { "Update" } else { "Save" };
 	let dialog: Element<Message> = container(
@@ -1675,14 +2997,14 @@ fn save_statement_dialog_view<'a>(name: &'a str, is_editing: bool) -> Element<'a
 			row![text(title).size(24), space::horizontal()].align_y(Alignment::Center),
 			section(
 				"Name",
-				styled_text_input("Statement name", name)
+				styled_text_input("Statement name", name, colors)
 					.on_input(Message::SaveStatementNameChanged)
 					.on_submit(Message::SaveStatement),
 			),
 			row![
 				space::horizontal(),
-				styled_button("Cancel", Message::CloseSaveStatementDialog, (100, 40)),
-				styled_button(btn_label, Message::SaveStatement, BUTTON_SIZE_DEFAULT),
+				styled_button("Cancel", Message::CloseSaveStatementDialog, (100, 40), colors),
+				styled_button(btn_label, Message::SaveStatement, BUTTON_SIZE_DEFAULT, colors),
 			]
 			.spacing(8)
 			.align_y(Alignment::Center),
@@ -1692,9 +3014,9 @@ fn save_statement_dialog_view<'a>(name: &'a str, is_editing: bool) -> Element<'a
 	)
 	.width(Length::Fixed(480.0))
 	.style(|_| container::Style {
-		background: Some(colors::BG_MODAL.into()),
+		background: Some(colors.bg_modal.into()),
 		border: border::Border {
-			color: colors::BORDER_PRIMARY,
+			color: colors.border_primary,
 			width: 1.0,
 			radius: 5.0.into(),
 		},
@@ -1715,11 +3037,13 @@ fn settings_dialog_view<'a>(
 	error: &'a str,
 	is_password_protected: bool,
 	show_column_types: bool,
+	prefs: Prefs,
+	colors: &'static Colors,
 ) -> Element<'a, Message> {
 	let error_el: Element<Message> = if error.is_empty() {
 		space::vertical().height(24).into()
 	} else {
-		container(text(error).color(colors::DANGER).size(14))
+		container(text(error).color(colors.danger).size(14))
 			.height(24)
 			.into()
 	};
@@ -1733,7 +3057,8 @@ fn settings_dialog_view<'a>(
 		styled_button(
 			apply_label,
 			Message::SettingsApplyPassword,
-			BUTTON_SIZE_DEFAULT
+			BUTTON_SIZE_DEFAULT,
+			colors,
 		),
 	]
 	.spacing(10)
@@ -1743,6 +3068,7 @@ fn settings_dialog_view<'a>(
 			"Remove",
 			Message::SettingsRemovePassword,
 			BUTTON_SIZE_DEFAULT,
+			colors,
 		));
 	}
 	let dialog: Element<Message> = container(
@@ -1750,23 +3076,70 @@ fn settings_dialog_view<'a>(
 			row![
 				text("Settings").size(24),
 				space::horizontal(),
-				styled_button("✕", Message::CloseSettings, (40, 32)),
+				styled_button("✕", Message::CloseSettings, (40, 32), colors),
 			]
 			.align_y(Alignment::Center),
 			section(
 				"Table Display",
-				checkbox(show_column_types)
-					.label("Show column types in header")
-					.on_toggle(Message::ToggleShowColumnTypes),
+				column![
+					checkbox(show_column_types)
+						.label("Show column types in header")
+						.on_toggle(Message::ToggleShowColumnTypes),
+					checkbox(prefs.zebra_striping)
+						.label("Alternate row colors")
+						.on_toggle(move |zebra_striping| Message::PrefsChanged(Prefs { zebra_striping, ..prefs })),
+					row![
+						text("Font size").size(14),
+						space::horizontal(),
+						pick_list(&TABLE_FONT_SIZES[..], Some(prefs.table_font_size), move |table_font_size| {
+							Message::PrefsChanged(Prefs { table_font_size, ..prefs })
+						}),
+					]
+					.align_y(Alignment::Center),
+				]
+				.spacing(8),
+			),
+			section(
+				"Editor",
+				column![
+					checkbox(prefs.auto_close_brackets)
+						.label("Auto-close brackets and quotes")
+						.on_toggle(move |auto_close_brackets| {
+							Message::PrefsChanged(Prefs { auto_close_brackets, ..prefs })
+						}),
+					row![
+						text("Tab width").size(14),
+						space::horizontal(),
+						pick_list(&TAB_WIDTHS[..], Some(prefs.tab_width), move |tab_width| {
+							Message::PrefsChanged(Prefs { tab_width, ..prefs })
+						}),
+					]
+					.align_y(Alignment::Center),
+				]
+				.spacing(8),
+			),
+			section(
+				"Files",
+				row![
+					text("Default delimiter").size(14),
+					space::horizontal(),
+					pick_list(&Delimiter::ALL[..], Delimiter::from_char(prefs.default_delimiter), move |d| {
+						Message::PrefsChanged(Prefs {
+							default_delimiter: d.as_char(),
+							..prefs
+						})
+					}),
+				]
+				.align_y(Alignment::Center),
 			),
 			section(
 				"Security",
 				column![
-					styled_text_input("New password", new_password)
+					styled_text_input("New password", new_password, colors)
 						.secure(true)
 						.on_input(Message::SettingsNewPasswordChanged)
 						.on_submit(Message::SettingsApplyPassword),
-					styled_text_input("Confirm password", confirm_password)
+					styled_text_input("Confirm password", confirm_password, colors)
 						.secure(true)
 						.on_input(Message::SettingsConfirmPasswordChanged)
 						.on_submit(Message::SettingsApplyPassword),
@@ -1781,9 +3154,9 @@ fn settings_dialog_view<'a>(
 	)
 	.width(Length::Fixed(520.0))
 	.style(|_| container::Style {
-		background: Some(colors::BG_MODAL.into()),
+		background: Some(colors.bg_modal.into()),
 		border: border::Border {
-			color: colors::BORDER_PRIMARY,
+			color: colors.border_primary,
 			width: 1.0,
 			radius: 5.0.into(),
 		},
@@ -1798,11 +3171,12 @@ fn settings_dialog_view<'a>(
 	.into()
 }
 
-pub fn adapter_configuration_view(adapter_state: &AdapterState) -> Element<'static, Message> {
+pub fn adapter_configuration_view(adapter_state: &AdapterState, colors: &'static Colors) -> Element<'static, Message> {
 	let name = adapter_state.name.clone();
 	let adapter_label = match &adapter_state.selection {
 		AdapterSelection::None => "None",
 		AdapterSelection::BigQuery => "BigQuery",
+		AdapterSelection::DuckDB => "DuckDB",
 		AdapterSelection::MySQL => "MySQL",
 		AdapterSelection::Parquet => "Parquet",
 		AdapterSelection::Postgres => "Postgres",
@@ -1824,9 +3198,9 @@ pub fn adapter_configuration_view(adapter_state: &AdapterState) -> Element<'stat
 					match descriptor.field_type {
 						AdapterFieldType::Text => {
 							let input = if descriptor.is_secure {
-								styled_text_input(descriptor.value, &current_value).secure(true)
+								styled_text_input(descriptor.value, &current_value, colors).secure(true)
 							} else {
-								styled_text_input(descriptor.value, &current_value)
+								styled_text_input(descriptor.value, &current_value, colors)
 							};
 							let input = input
 								.on_input(move |val| {
@@ -1848,7 +3222,7 @@ pub fn adapter_configuration_view(adapter_state: &AdapterState) -> Element<'stat
 				"Connection",
 				field(
 					"Name",
-					styled_text_input("Required", &name)
+					styled_text_input("Required", &name, colors)
 						.on_input(Message::ConnectionNameChanged)
 						.on_submit(Message::AdapterConfigurationSubmitted),
 				),
@@ -1856,11 +3230,12 @@ pub fn adapter_configuration_view(adapter_state: &AdapterState) -> Element<'stat
 			fields_section,
 			row![
 				space::horizontal(),
-				styled_button("Save", Message::SaveConnection, BUTTON_SIZE_DEFAULT),
+				styled_button("Save", Message::SaveConnection, BUTTON_SIZE_DEFAULT, colors),
 				styled_button(
 					"Connect",
 					Message::AdapterConfigurationSubmitted,
 					BUTTON_SIZE_DEFAULT,
+					colors,
 				),
 			]
 			.spacing(10)
@@ -1871,9 +3246,9 @@ pub fn adapter_configuration_view(adapter_state: &AdapterState) -> Element<'stat
 	)
 	.width(Length::Fixed(800.0))
 	.style(|_| container::Style {
-		background: Some(colors::BG_MODAL.into()),
+		background: Some(colors.bg_modal.into()),
 		border: border::Border {
-			color: colors::BORDER_PRIMARY,
+			color: colors.border_primary,
 			width: 1.0,
 			radius: 5.0.into(),
 		},
@@ -12,7 +12,7 @@ use iced::window;
 use iced::{Background, Border, Color, Element, Length, Point, Rectangle, Shadow, Size, Vector};
 
 use crate::fonts::MENU_FONT;
-use crate::gui::colors::{BG_PRIMARY, BORDER_PRIMARY, TEXT_PRIMARY};
+use crate::gui::colors::{self, Colors};
 use super::geometry::{
 	ARROW_GUTTER, ARROW_TEXT_GAP, BAR_HEIGHT, BAR_ITEM_PADDING_X, Hit, ItemKind, LABEL_SIZE,
 	MenuGeometry, PANEL_TEXT_OFFSET,
@@ -84,6 +84,7 @@ pub struct MenuBar<'a> {
 	state: &'a MenuState,
 	font_policy: MenuFontPolicy,
 	close_delay: Duration,
+	colors: &'static Colors,
 }
 
 impl<'a> MenuBar<'a> {
@@ -93,6 +94,7 @@ impl<'a> MenuBar<'a> {
 			state,
 			font_policy: MenuFontPolicy::SystemWithFallback,
 			close_delay: Duration::from_millis(400),
+			colors: &colors::DARK,
 		}
 	}
 
@@ -106,6 +108,11 @@ impl<'a> MenuBar<'a> {
 		self
 	}
 
+	pub fn colors(mut self, colors: &'static Colors) -> Self {
+		self.colors = colors;
+		self
+	}
+
 	fn label_font<Renderer: text::Renderer<Font = iced::Font>>(
 		&self,
 		renderer: &Renderer,
@@ -197,7 +204,7 @@ where
 				bounds: geometry.bar_bounds,
 				..renderer::Quad::default()
 			},
-			Background::Color(bar_background()),
+			Background::Color(bar_background(self.colors)),
 		);
 
 		for root in &geometry.roots {
@@ -223,7 +230,7 @@ where
 				renderer,
 				root.label,
 				root.bounds,
-				text_color(),
+				text_color(self.colors),
 				viewport,
 				LabelAlignment::Bar,
 				label_font,
@@ -235,7 +242,7 @@ where
 				renderer::Quad {
 					bounds: panel.bounds,
 					border: Border {
-						color: panel_border(),
+						color: panel_border(self.colors),
 						width: 1.0,
 						radius: 4.0.into(),
 					},
@@ -246,7 +253,7 @@ where
 					},
 					snap: false,
 				},
-				Background::Color(panel_background()),
+				Background::Color(panel_background(self.colors)),
 			);
 
 			for item in &panel.items {
@@ -262,7 +269,7 @@ where
 								},
 								..renderer::Quad::default()
 							},
-							Background::Color(panel_border()),
+							Background::Color(panel_border(self.colors)),
 						);
 					}
 					ItemKind::Action { label, .. } | ItemKind::Submenu { label, .. } => {
@@ -296,7 +303,7 @@ where
 									},
 								..item.bounds
 							},
-							text_color(),
+							text_color(self.colors),
 							viewport,
 							LabelAlignment::Panel,
 							label_font,
@@ -311,7 +318,7 @@ where
 									width: ARROW_GUTTER,
 									..item.bounds
 								},
-								if hovered { text_color() } else { text_muted() },
+								if hovered { text_color(self.colors) } else { text_muted() },
 								viewport,
 								LabelAlignment::Arrow,
 								symbol_font,
@@ -668,8 +675,8 @@ fn draw_label<Renderer: text::Renderer<Font = iced::Font>>(
 	);
 }
 
-fn bar_background() -> Color {
-	BG_PRIMARY
+fn bar_background(colors: &Colors) -> Color {
+	colors.bg_primary
 }
 
 fn bar_hover() -> Color {
@@ -682,12 +689,12 @@ fn bar_active() -> Color {
 	Color::from_rgba(accent.r, accent.g, accent.b, 0.2)
 }
 
-fn panel_background() -> Color {
-	BG_PRIMARY
+fn panel_background(colors: &Colors) -> Color {
+	colors.bg_primary
 }
 
-fn panel_border() -> Color {
-	BORDER_PRIMARY
+fn panel_border(colors: &Colors) -> Color {
+	colors.border_primary
 }
 
 fn panel_hover() -> Color {
@@ -695,8 +702,8 @@ fn panel_hover() -> Color {
 	Color::from_rgba(accent.r, accent.g, accent.b, 0.18)
 }
 
-fn text_color() -> Color {
-	TEXT_PRIMARY
+fn text_color(colors: &Colors) -> Color {
+	colors.text_primary
 }
 
 fn text_muted() -> Color {
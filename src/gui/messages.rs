@@ -1,19 +1,26 @@
 use super::menu;
+use super::table::{CellImage, NumericFormat, SortOrder, SummaryMode};
 use crate::editor::EditorMsg;
 use crate::adapters::{
 	common::{DatabaseAdapter, ExecutionResult},
 	driver::AdapterSelection,
 };
-use crate::persistence::{PrivateDb, SavedConnection, SavedStatement};
+use crate::persistence::{Prefs, PrivateDb, SavedConnection, SavedStatement, TableLayout};
 use crate::plot::colors::ColorTheme;
 use crate::plot::common::{GridLineStyle, ScatterRenderMode};
 use crate::plot::common::PlotKernel;
 use crate::plot::core::PlotType;
-use iced::{widget::pane_grid, window};
-use iced::{Color, Rectangle};
+use iced::{keyboard, widget::{pane_grid, text_editor}, window};
+use iced::{Color, Point, Rectangle};
+use polars::{frame::DataFrame, prelude::IdxSize};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Filtered rows and the row behind each, plus the same again sorted when a
+/// sort is active.
+pub type FilteredRows = ((DataFrame, Vec<IdxSize>), Option<(DataFrame, Vec<IdxSize>)>);
+
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExportFormat {
@@ -32,6 +39,12 @@ impl std::fmt::Display for ExportFormat {
 	}
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinSide {
+	Left,
+	Right,
+}
+
 #[derive(Clone)]
 pub enum Message {
 	AdapterConfigurationChanged(String, String),
@@ -41,14 +54,42 @@ pub enum Message {
 	AddPlot(PlotType),
 	AddPlotReady(PlotType, Arc<dyn PlotKernel + Send + Sync>),
 	ClosePlot(pane_grid::Pane),
+	CancelCellEdit,
+	CellContextMenu {
+		row: usize,
+		col: usize,
+		screen_pos: Point,
+	},
+	CellDoubleClicked(usize, usize),
+	CellEditChanged(String),
+	CellEdited {
+		row: usize,
+		col: usize,
+		value: String,
+	},
+	CellImageLoaded(String, CellImage),
+	CellImagesRequested(Vec<String>),
+	CloseCellMenu,
+	CloseCommandPalette,
+	CloseColumnMenu,
 	CloseSaveStatementDialog,
 	CloseSettings,
+	CloseTableSearch,
 	CloseWindow,
 	CodeEditEvent(EditorMsg),
+	CommandPaletteKey(keyboard::key::Named),
+	CommandPaletteMove(isize),
+	CommandPaletteQueryChanged(String),
+	CommandPaletteSubmit,
+	ColumnsReordered(usize, usize),
 	Connect,
 	ConnectionNameChanged(String),
 	ConnectionSaved(Vec<SavedConnection>),
+	CopyCell(usize, usize),
+	CopyColumn(usize),
+	CopyRow(usize),
 	DashboardPaneDragged(pane_grid::DragEvent),
+	DataLoaded(Result<DataFrame, String>),
 	DashboardPaneResized(pane_grid::ResizeEvent),
 	DeleteConnection(i64),
 	DeleteStatement(i64),
@@ -56,16 +97,49 @@ pub enum Message {
 	DragWindow,
 	EditConnection(i64),
 	EditStatement(i64),
+	Explain,
+	ExplainResult(ExecutionResult),
 	Export(ExportFormat),
+	ExportCSV,
+	ExportCSVDone(Result<Option<String>, String>),
 	ExportDone(usize, ExportFormat),
+	ExportMarkdown,
+	ExportMarkdownShortcut,
+	ExportParquet,
+	ExportParquetDone(Result<Option<(String, usize, String)>, String>),
 	ExportWithWindowSize(ExportFormat, Option<iced::Size>),
+	FetchHistoryRows(usize, usize),
+	FileChosen(Option<PathBuf>),
+	FileDropped(PathBuf),
+	FilterChanged(usize, String),
+	FindReplace,
+	FindReplaceAll,
+	FindReplaceClose,
+	FindReplaceCurrent,
+	FindReplaceNext,
+	FindReplacePrev,
+	FindReplaceQueryChanged(String),
+	FindReplaceWithChanged(String),
+	FocusNextPane,
+	FocusPrevPane,
+	FullScreen,
+	JoinCounted {
+		keys: (usize, usize),
+		counts: (usize, usize, usize),
+	},
+	JumpToColumn(usize),
+	LoadHistoryEntry(String),
 	LoadSavedConnection(i64),
 	LoadSavedStatement(i64),
 	MaximizeWindow,
 	Menu(menu::MenuMessage),
 	MinimizeWindow,
+	OpenFile,
 	OpenSaveStatementDialog,
 	OpenSettings,
+	OpenTableSearch,
+	OpenUrl(String),
+	PaneClicked(pane_grid::Pane),
 	PaneDragged(pane_grid::DragEvent),
 	PaneResized(pane_grid::ResizeEvent),
 	PasswordDecryptFailed,
@@ -74,10 +148,19 @@ pub enum Message {
 	PlotEvent(pane_grid::Pane, PlotMessage),
 	RefreshPlotReady(pane_grid::Pane, PlotType, Arc<dyn PlotKernel + Send + Sync>),
 	ResizePlotsSettled,
+	RefreshSchema,
+	RestoreQuerySnapshot(usize),
+	RunPaletteCommand(usize),
+	PrefsChanged(Prefs),
+	PrefsSaved,
 	PrivateDbError(String),
+	QueryPlanAction(text_editor::Action),
 	PrivateDbReady(PrivateDb),
 	PrivateDbRekeyed(PrivateDb),
+	ResetDb,
 	ResizeWindow(window::Direction),
+	Redo,
+	RowsReordered(usize, usize),
 	Run,
 	RunResult(ExecutionResult),
 	SaveConnection,
@@ -86,14 +169,53 @@ pub enum Message {
 	SaveWindowSizeAndClose(iced::Size),
 	SavedConnectionsLoaded(Vec<SavedConnection>),
 	SavedStatementsLoaded(Vec<SavedStatement>),
+	SchemaKeyPressed(keyboard::key::Named),
+	SchemaLoaded(ExecutionResult),
+	SchemaColumnClicked(usize, usize),
+	SchemaTableClicked(usize),
+	Screenshot,
+	ScreenshotReady(window::Screenshot),
+	ScreenshotSaved(Result<String, String>),
+	SetDelimiter(char),
+	SetJoinKey(JoinSide, usize),
+	SetNumericFormat(usize, Option<NumericFormat>),
+	SetSummaryMode(Option<SummaryMode>),
 	SettingsApplyPassword,
 	SettingsConfirmPasswordChanged(String),
 	SettingsNewPasswordChanged(String),
 	SettingsPasswordSaved,
 	SettingsRemovePassword,
+	ShowColumnMenu(usize, Point),
 	ShowColumnTypesSaved,
+	SortRequested(usize, Option<SortOrder>),
+	SplitDragEnded,
+	SplitDragStarted,
+	SplitMeasured(f32),
+	SplitResized(f32),
 	StatementSaved(Vec<SavedStatement>),
+	TableLayoutChanged(TableLayout),
+	TableRowClicked(usize),
+	TableSearchChanged(String),
+	TableSearchNext,
+	TableSearchPrev,
+	TableSearchResult(String, Vec<(usize, usize)>),
+	TableFiltered(Vec<String>, Result<FilteredRows, String>),
+	TableSelectionChanged(usize),
+	TableSorted(Result<(DataFrame, Vec<IdxSize>), String>),
+	Tick,
+	ToggleColumnVisibility(usize, bool),
+	ToggleCommandPalette,
+	ToggleComment,
+	ToggleCompare,
+	ToggleFilterRow,
+	ToggleImageColumn(usize, bool),
+	ToggleJoinPreview,
+	ToggleQueryHistory,
+	ToggleSchemaTable(usize),
 	ToggleShowColumnTypes(bool),
+	ToggleTheme,
+	ToggleTransparency,
+	Undo,
 }
 
 #[allow(dead_code)]
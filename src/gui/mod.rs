@@ -6,6 +6,8 @@ pub mod plot_state;
 pub mod statusbar;
 mod state;
 mod table;
+mod table_export;
+mod table_import;
 
 pub use state::run;
 pub use state::Result;
@@ -1,40 +1,67 @@
 use crate::editor::highlight::SyntaxLanguage;
-use crate::editor::{CodeEditor, DEJAVU_SANS_MONO};
+use crate::editor::theme::EditorTheme;
+use crate::editor::widget::EditorAction;
+use crate::editor::{CodeEditor, EditorCommand, EditorMsg, DEJAVU_SANS_MONO};
 use crate::adapters::{
 	common::{AdapterStage, ExecutionResult},
 	driver::{AdapterConfiguration, AdapterSelection, AdapterState},
 };
-use crate::persistence::{self, SavedConnection, SavedStatement, StartupData};
+use crate::persistence::{self, SavedConnection, SavedStatement, StartupData, TableLayout};
 use crate::gui::{
+	colors::ThemeMode,
 	menu::MenuState,
-	components::{self, PaneType},
-	messages::{ExportFormat, Message, PlotMessage},
+	components::{self, Command, FindReplaceState, JoinPreviewState, PaneType, QueryHistoryEntry, QuerySnapshot, SplitState, StatusInfo, TableSchema},
+	messages::{ExportFormat, JoinSide, Message, PlotMessage},
 	plot_state::{PlotState, create_plot},
+	table::{self, CellImage, SortOrder},
+	table_export,
+	table_import,
 };
-use crate::plot::export::{AvifBackend, PngBackend, SvgBackend};
-use iced::{application, event, keyboard, time, widget::pane_grid, window, Element, Size, Subscription, Task};
-use polars::frame::DataFrame;
+use crate::plot::export::{self, AvifBackend, PngBackend, SvgBackend};
+use iced::{application, event, keyboard, mouse, time, widget::{pane_grid, text_editor}, window, Element, Point, Size, Subscription, Task};
+use polars::{
+	frame::{column::Column, DataFrame},
+	prelude::{IdxCa, IdxSize, NamedFrom, PolarsResult, Series, SortOptions},
+};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 
 struct AppState {
 	panes: pane_grid::State<PaneType>,
+	focused_pane: Option<pane_grid::Pane>,
 	dashboard: Option<pane_grid::State<PlotState>>,
 	menu_state: MenuState,
 	code_editor: CodeEditor,
+	find_replace: FindReplaceState,
 	data_frame: DataFrame,
+	sort: Option<(usize, SortOrder)>,
+	unsorted_data_frame: Option<DataFrame>,
+	/// Row of `unsorted_data_frame` behind each shown row while sorted.
+	sorted_rows: Vec<IdxSize>,
+	unfiltered_data_frame: Option<DataFrame>,
+	/// Row of `unfiltered_data_frame` behind each unsorted row while filtered.
+	filtered_rows: Vec<IdxSize>,
+	show_filter_row: bool,
+	col_filters: Vec<String>,
 	status_msg: String,
 	status_error: String,
 	status_df_size: Option<(usize, usize)>,
-	status_time_elapsed: Option<f64>,
+	status_elapsed_ms: Option<u64>,
+	selected_row_count: usize,
+	data_generation: u64,
 	adapter_state: AdapterState,
 	code_started: Instant,
 	is_maximized: bool,
+	is_fullscreen: bool,
 	saved_connections: Vec<SavedConnection>,
 	editing_connection_id: Option<i64>,
 	saved_statements: Vec<SavedStatement>,
 	showing_save_statement_dialog: bool,
 	save_statement_name: String,
 	editing_statement_id: Option<i64>,
+	transparent: bool,
+	theme: ThemeMode,
 	private_db: Option<persistence::PrivateDb>,
 	salt: Vec<u8>,
 	is_password_protected: bool,
@@ -46,10 +73,65 @@ struct AppState {
 	settings_confirm_password: String,
 	settings_error: String,
 	show_column_types: bool,
+	hidden_cols: BTreeSet<usize>,
+	column_menu: Option<(usize, Point)>,
+	cell_menu: Option<(usize, usize, Point)>,
+	table_search_query: String,
+	table_search_hits: Vec<(usize, usize)>,
+	table_search_index: Option<usize>,
+	jump_to_col: Option<(usize, u64)>,
+	table_layout: Option<TableLayout>,
+	editing_cell: Option<(usize, usize, String)>,
+	running_code: String,
+	table_loading: bool,
+	cell_images: HashMap<String, CellImage>,
+	image_cols: BTreeSet<usize>,
+	numeric_formats: HashMap<usize, table::NumericFormat>,
+	summary_mode: Option<table::SummaryMode>,
+	delimiter: char,
+	prefs: persistence::Prefs,
+	delimited_file: Option<std::path::PathBuf>,
+	compare_baseline: Option<Arc<DataFrame>>,
+	compare_diffs: Option<usize>,
+	previous_result: Option<DataFrame>,
+	join_preview: JoinPreviewState,
+	query_history: VecDeque<QueryHistoryEntry>,
+	query_snapshots: VecDeque<QuerySnapshot>,
+	commands: Vec<Command>,
+	command_palette_open: bool,
+	command_palette_query: String,
+	command_palette_index: usize,
+	command_palette_frame: Arc<DataFrame>,
+	query_history_frame: DataFrame,
+	query_history_window: (usize, usize),
+	editor_split: SplitState,
+	editor_table_split: pane_grid::Split,
+	query_plan: text_editor::Content,
+	schema: Vec<TableSchema>,
+	schema_expanded: BTreeSet<usize>,
+	schema_cursor: Option<usize>,
 	dashboard_resize_freeze_until: Option<Instant>,
 }
 
 pub type Result = iced::Result;
+type FileReader = Box<dyn FnOnce(&std::path::Path) -> std::result::Result<DataFrame, String> + Send>;
+
+const QUERY_HISTORY_LIMIT: usize = 100;
+const QUERY_SNAPSHOT_LIMIT: usize = 50;
+const CELL_IMAGE_THUMBNAIL: u32 = 64;
+const CELL_IMAGE_CACHE_LIMIT: usize = 512;
+const CELL_IMAGE_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+const CELL_IMAGE_MAX_BYTES: usize = 16 * 1024 * 1024;
+const MARKDOWN_EXPORT_ROWS: usize = 10_000;
+const INFORMATION_SCHEMA_QUERY: &str = "SELECT table_schema, table_name, column_name, data_type \
+	FROM information_schema.columns \
+	WHERE table_schema NOT IN ('information_schema', 'pg_catalog') \
+	ORDER BY table_schema, table_name, ordinal_position";
+const SQLITE_SCHEMA_QUERY: &str = "SELECT NULL AS table_schema, m.name AS table_name, \
+	p.name AS column_name, p.type AS data_type \
+	FROM sqlite_master m JOIN pragma_table_info(m.name) p \
+	WHERE m.type IN ('table', 'view') \
+	ORDER BY m.name, p.cid";
 
 fn build_plot_task(
 	df: DataFrame,
@@ -75,16 +157,134 @@ fn subscription(state: &AppState) -> Subscription<Message> {
 			modifiers,
 			..
 		}) if modifiers.control() => Some(Message::Run),
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Character(c),
+			modifiers,
+			..
+		}) if modifiers.control() && c.as_str() == "h" => Some(if modifiers.alt() {
+			Message::ToggleQueryHistory
+		} else {
+			Message::FindReplace
+		}),
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Character(c),
+			modifiers,
+			..
+		}) if modifiers.control() && c.as_str() == "e" => Some(Message::ExportCSV),
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Character(c),
+			modifiers,
+			..
+		}) if modifiers.control() && c.as_str() == "p" => Some(Message::ToggleCommandPalette),
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Character(c),
+			modifiers,
+			..
+		}) if modifiers.control() && modifiers.shift() && c.eq_ignore_ascii_case("t") => {
+			Some(Message::ToggleTransparency)
+		}
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Character(c),
+			modifiers,
+			..
+		}) if modifiers.control() && modifiers.shift() && c.eq_ignore_ascii_case("c") => {
+			Some(Message::ExportMarkdownShortcut)
+		}
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Character(c),
+			modifiers,
+			..
+		}) if modifiers.control() && modifiers.shift() && c.eq_ignore_ascii_case("s") => {
+			Some(Message::Screenshot)
+		}
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Named(keyboard::key::Named::Tab),
+			modifiers,
+			..
+		}) if modifiers.control() => Some(if modifiers.shift() {
+			Message::FocusPrevPane
+		} else {
+			Message::FocusNextPane
+		}),
+		event::Event::Window(window::Event::FileDropped(path)) => Some(Message::FileDropped(path)),
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Named(keyboard::key::Named::F11),
+			..
+		}) => Some(Message::FullScreen),
+		event::Event::Keyboard(keyboard::Event::KeyPressed {
+			key: keyboard::Key::Named(keyboard::key::Named::F3),
+			modifiers,
+			..
+		}) => Some(if modifiers.shift() {
+			Message::TableSearchPrev
+		} else {
+			Message::TableSearchNext
+		}),
 		_ => None,
 	});
+	// Arrow keys, Enter and Escape go to the command palette while it's open,
+	// and to the schema tree and cell menu otherwise.
+	let overlay_keys = if state.command_palette_open {
+		event::listen_with(|ev, status, _window| match ev {
+			event::Event::Keyboard(keyboard::Event::KeyPressed {
+				key: keyboard::Key::Named(
+					named @ (keyboard::key::Named::ArrowUp
+					| keyboard::key::Named::ArrowDown
+					| keyboard::key::Named::Enter),
+				),
+				modifiers,
+				..
+			}) if status == event::Status::Ignored && modifiers.is_empty() => Some(Message::CommandPaletteKey(named)),
+			event::Event::Keyboard(keyboard::Event::KeyPressed {
+				key: keyboard::Key::Named(keyboard::key::Named::Escape),
+				..
+			}) => Some(Message::CloseCommandPalette),
+			_ => None,
+		})
+	} else {
+		event::listen_with(|ev, status, _window| match ev {
+			event::Event::Keyboard(keyboard::Event::KeyPressed {
+				key: keyboard::Key::Named(
+					named @ (keyboard::key::Named::ArrowUp
+					| keyboard::key::Named::ArrowDown
+					| keyboard::key::Named::ArrowLeft
+					| keyboard::key::Named::ArrowRight
+					| keyboard::key::Named::Enter),
+				),
+				modifiers,
+				..
+			}) if status == event::Status::Ignored && modifiers.is_empty() => Some(Message::SchemaKeyPressed(named)),
+			event::Event::Keyboard(keyboard::Event::KeyPressed {
+				key: keyboard::Key::Named(keyboard::key::Named::Escape),
+				..
+			}) => Some(Message::CloseCellMenu),
+			_ => None,
+		})
+	};
 	let resize_tick = if state.dashboard_resize_freeze_until.is_some() {
 		time::every(Duration::from_millis(75)).map(|_| Message::ResizePlotsSettled)
 	} else {
 		Subscription::none()
 	};
+	let split_release = if state.editor_split.dragging {
+		event::listen_with(|ev, _status, _window| match ev {
+			event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => Some(Message::SplitDragEnded),
+			_ => None,
+		})
+	} else {
+		Subscription::none()
+	};
+	let loading_tick = if state.table_loading {
+		time::every(Duration::from_millis(100)).map(|_| Message::Tick)
+	} else {
+		Subscription::none()
+	};
 	Subscription::batch([
 		events,
+		overlay_keys,
 		resize_tick,
+		split_release,
+		loading_tick,
 		state.code_editor.subscription().map(Message::CodeEditEvent),
 	])
 }
@@ -96,7 +296,7 @@ pub fn run(startup_data: StartupData) -> Result {
 		.unwrap_or(Size::new(1920.0, 1080.0));
 	application(move || new(startup_data.clone()), update, view)
 		.subscription(subscription)
-		.theme(components::theme())
+		.theme(|app_state: &AppState| components::theme(app_state.transparent, app_state.theme))
 		.title("Polariton")
 		.font(DEJAVU_SANS_MONO)
 		.window(window::Settings {
@@ -106,7 +306,7 @@ pub fn run(startup_data: StartupData) -> Result {
 			position: window::Position::Centered,
 			resizable: true,
 			size,
-			transparent: false,
+			transparent: true,
 			..Default::default()
 		})
 		.run()
@@ -115,38 +315,51 @@ pub fn run(startup_data: StartupData) -> Result {
 fn new(startup_data: StartupData) -> (AppState, Task<Message>) {
 	let data_frame = DataFrame::default();
 	let (mut panes, editor_pane) = pane_grid::State::new(PaneType::CodeEditor);
-	let (_data_pane, _) = panes
+	let (_data_pane, editor_table_split) = panes
 		.split(
-			pane_grid::Axis::Horizontal,
+			pane_grid::Axis::Vertical,
 			editor_pane,
 			PaneType::DataTable,
 		)
 		.unwrap();
-	let _ = panes
-		.split(pane_grid::Axis::Vertical, editor_pane, PaneType::Dashboard)
-		.unwrap();
-	let code_editor = CodeEditor::new("", SyntaxLanguage::Sql);
+	let mut code_editor = CodeEditor::new("", SyntaxLanguage::Sql);
+	code_editor.auto_pairs = startup_data.prefs.auto_close_brackets;
+	code_editor.tab_width = startup_data.prefs.tab_width;
 	let is_password_protected = startup_data.is_password_protected;
 	let salt = startup_data.salt.clone();
 	let state = AppState {
 		panes,
+		focused_pane: None,
 		dashboard: None,
 		menu_state: MenuState::default(),
 		code_editor,
+		find_replace: FindReplaceState::default(),
 		data_frame,
+		sort: None,
+		unsorted_data_frame: None,
+		sorted_rows: Vec::new(),
+		unfiltered_data_frame: None,
+		filtered_rows: Vec::new(),
+		show_filter_row: false,
+		col_filters: Vec::new(),
 		status_msg: "".to_string(),
 		status_error: "".to_string(),
 		status_df_size: None,
-		status_time_elapsed: None,
+		status_elapsed_ms: None,
+		selected_row_count: 0,
+		data_generation: 0,
 		adapter_state: AdapterState::default(),
 		code_started: Instant::now(),
 		is_maximized: false,
+		is_fullscreen: false,
 		saved_connections: vec![],
 		editing_connection_id: None,
 		saved_statements: vec![],
 		showing_save_statement_dialog: false,
 		save_statement_name: String::new(),
 		editing_statement_id: None,
+		transparent: false,
+		theme: ThemeMode::default(),
 		private_db: None,
 		salt: startup_data.salt,
 		is_password_protected,
@@ -158,6 +371,43 @@ fn new(startup_data: StartupData) -> (AppState, Task<Message>) {
 		settings_confirm_password: String::new(),
 		settings_error: String::new(),
 		show_column_types: startup_data.show_column_types,
+		hidden_cols: BTreeSet::new(),
+		column_menu: None,
+		cell_menu: None,
+		table_search_query: String::new(),
+		table_search_hits: Vec::new(),
+		table_search_index: None,
+		jump_to_col: None,
+		table_layout: startup_data.table_layout,
+		editing_cell: None,
+		running_code: String::new(),
+		table_loading: false,
+		cell_images: HashMap::new(),
+		image_cols: BTreeSet::new(),
+		numeric_formats: HashMap::new(),
+		summary_mode: None,
+		delimiter: startup_data.prefs.default_delimiter,
+		prefs: startup_data.prefs,
+		delimited_file: None,
+		compare_baseline: None,
+		compare_diffs: None,
+		previous_result: None,
+		join_preview: JoinPreviewState::default(),
+		query_history: VecDeque::new(),
+		query_snapshots: VecDeque::new(),
+		commands: components::palette_commands(),
+		command_palette_open: false,
+		command_palette_query: String::new(),
+		command_palette_index: 0,
+		command_palette_frame: Arc::new(DataFrame::default()),
+		query_history_frame: DataFrame::default(),
+		query_history_window: (0, 0),
+		editor_split: SplitState::default(),
+		editor_table_split,
+		query_plan: text_editor::Content::new(),
+		schema: Vec::new(),
+		schema_expanded: BTreeSet::new(),
+		schema_cursor: None,
 		dashboard_resize_freeze_until: None,
 	};
 	let task = if !is_password_protected {
@@ -177,14 +427,23 @@ fn new(startup_data: StartupData) -> (AppState, Task<Message>) {
 fn view(app_state: &AppState) -> Element<'_, Message> {
 	components::main_screen(
 		&app_state.panes,
+		app_state.focused_pane,
 		&app_state.dashboard,
 		&app_state.menu_state,
 		&app_state.code_editor,
+		&app_state.find_replace,
 		&app_state.data_frame,
+		app_state.data_generation,
 		&app_state.status_msg,
 		&app_state.status_error,
-		app_state.status_df_size,
-		app_state.status_time_elapsed,
+		StatusInfo {
+			table_size: app_state.status_df_size,
+			selected_rows: app_state.selected_row_count,
+			active_filters: app_state.col_filters.iter().filter(|filter| !filter.is_empty()).count(),
+			hidden_cols: app_state.hidden_cols.len(),
+			elapsed_ms: app_state.status_elapsed_ms,
+			differing_cells: app_state.compare_diffs,
+		},
 		&app_state.adapter_state,
 		&app_state.saved_connections,
 		&app_state.saved_statements,
@@ -197,9 +456,49 @@ fn view(app_state: &AppState) -> Element<'_, Message> {
 		&app_state.settings_error,
 		app_state.is_password_protected,
 		app_state.show_column_types,
+		&app_state.hidden_cols,
+		app_state.column_menu,
+		app_state.cell_menu,
+		&app_state.table_search_query,
+		&app_state.table_search_hits,
+		app_state.table_search_index,
+		app_state.jump_to_col,
+		app_state.sort,
+		app_state.table_layout.as_ref(),
+		app_state
+			.editing_cell
+			.as_ref()
+			.map(|(row, col, value)| (*row, *col, value.as_str())),
+		app_state.show_filter_row.then_some(app_state.col_filters.as_slice()),
+		app_state.table_loading,
+		&app_state.cell_images,
+		&app_state.image_cols,
+		&app_state.numeric_formats,
+		app_state.summary_mode,
+		app_state.compare_baseline.as_ref(),
+		app_state.previous_result.as_ref(),
+		app_state.join_preview,
+		app_state.delimiter,
+		app_state.prefs,
+		&app_state.query_history,
+		&app_state.query_snapshots,
+		&app_state.query_history_frame,
+		app_state.query_history_window.0,
+		app_state.editor_split,
+		&app_state.query_plan,
+		&app_state.schema,
+		&app_state.schema_expanded,
+		app_state.schema_cursor,
 		app_state.showing_save_statement_dialog,
 		&app_state.save_statement_name,
 		app_state.editing_statement_id,
+		&app_state.command_palette_frame,
+		app_state
+			.command_palette_open
+			.then_some((app_state.command_palette_query.as_str(), app_state.command_palette_index)),
+		app_state.transparent,
+		app_state.theme,
+		app_state.is_fullscreen,
 	)
 }
 
@@ -211,8 +510,14 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 			});
 		}
 		Message::SaveWindowSizeAndClose(size) => {
+			let table_layout = app_state.table_layout.take();
 			return Task::perform(
-				async move { persistence::save_window_size(size.width, size.height).await },
+				async move {
+					persistence::save_window_size(size.width, size.height).await;
+					if let Some(layout) = table_layout {
+						persistence::save_table_layout(layout).await;
+					}
+				},
 				|()| Message::DoCloseWindow,
 			);
 		}
@@ -230,16 +535,87 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 			return window::latest().and_then(window::drag);
 		}
 		Message::CodeEditEvent(edit_event) => {
+			if let EditorMsg::Action(EditorAction::MouseDown(_) | EditorAction::AddCaret(_)) = edit_event {
+				app_state.focused_pane = find_pane(app_state, PaneType::CodeEditor);
+			}
 			return app_state
 				.code_editor
 				.update(edit_event)
 				.map(Message::CodeEditEvent);
 		}
+		Message::FindReplace => {
+			app_state.find_replace.open = true;
+			let open = editor_command(app_state, EditorCommand::SearchOpen);
+			app_state.find_replace.find = app_state.code_editor.search_query().to_string();
+			let replace = app_state.find_replace.replace.clone();
+			let replacement = editor_command(app_state, EditorCommand::SearchSetReplacement(replace));
+			return Task::batch([
+				open,
+				replacement,
+				iced::widget::operation::focus(components::find_replace_id()),
+			]);
+		}
+		Message::Undo => {
+			return editor_command(app_state, EditorCommand::Undo);
+		}
+		Message::Redo => {
+			return editor_command(app_state, EditorCommand::Redo);
+		}
+		Message::ToggleComment => {
+			return editor_command(app_state, EditorCommand::ToggleComment);
+		}
+		Message::FindReplaceClose => {
+			app_state.find_replace.open = false;
+			return editor_command(app_state, EditorCommand::SearchClose);
+		}
+		Message::FindReplaceQueryChanged(find) => {
+			app_state.find_replace.find = find.clone();
+			return editor_command(app_state, EditorCommand::SearchSetQuery(find));
+		}
+		Message::FindReplaceWithChanged(replace) => {
+			app_state.find_replace.replace = replace.clone();
+			return editor_command(app_state, EditorCommand::SearchSetReplacement(replace));
+		}
+		Message::FindReplaceNext => {
+			return editor_command(app_state, EditorCommand::SearchNext);
+		}
+		Message::FindReplacePrev => {
+			return editor_command(app_state, EditorCommand::SearchPrev);
+		}
+		Message::FindReplaceCurrent => {
+			return editor_command(app_state, EditorCommand::SearchReplaceCurrent);
+		}
+		Message::FindReplaceAll => {
+			let (_, total) = app_state.code_editor.search_position();
+			app_state.status_msg = format!("Replaced {total} matches.");
+			return editor_command(app_state, EditorCommand::SearchReplaceAll);
+		}
 		Message::MaximizeWindow => {
 			app_state.is_maximized = !app_state.is_maximized;
 			let is_maximized = app_state.is_maximized;
 			return window::latest().and_then(move |id| window::maximize(id, is_maximized));
 		}
+		Message::FullScreen => {
+			app_state.is_fullscreen = !app_state.is_fullscreen;
+			let is_fullscreen = app_state.is_fullscreen;
+			if !cfg!(target_os = "macos") {
+				app_state.is_maximized = is_fullscreen;
+			}
+			return window::latest().and_then(move |id| {
+				if cfg!(target_os = "macos") {
+					window::set_mode(
+						id,
+						if is_fullscreen {
+							window::Mode::Fullscreen
+						} else {
+							window::Mode::Windowed
+						},
+					)
+				} else {
+					window::maximize(id, is_fullscreen)
+				}
+			});
+		}
 		Message::MinimizeWindow => {
 			return window::latest().and_then(move |id| window::minimize(id, true));
 		}
@@ -248,6 +624,27 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 		}
 		Message::PaneResized(pane_grid::ResizeEvent { split, ratio }) => {
 			app_state.panes.resize(split, ratio);
+			if split == app_state.editor_table_split {
+				app_state.editor_split.ratio = ratio;
+			}
+		}
+		Message::PaneClicked(pane) => {
+			app_state.focused_pane = Some(pane);
+		}
+		Message::FocusNextPane | Message::FocusPrevPane => {
+			let panes: Vec<pane_grid::Pane> = app_state.panes.iter().map(|(pane, _)| *pane).collect();
+			if !panes.is_empty() {
+				let count = panes.len();
+				let current = app_state
+					.focused_pane
+					.and_then(|focused| panes.iter().position(|pane| *pane == focused));
+				let next = match (current, matches!(message, Message::FocusNextPane)) {
+					(None, _) => 0,
+					(Some(i), true) => (i + 1) % count,
+					(Some(i), false) => (i + count - 1) % count,
+				};
+				app_state.focused_pane = Some(panes[next]);
+			}
 		}
 		Message::PaneDragged(drag_event) => {
 			if let pane_grid::DragEvent::Dropped { pane, target } = drag_event {
@@ -340,28 +737,300 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 				"Adapter connection failed.".into()
 			};
 		}
-		Message::Run => match &mut app_state.adapter_state.connection {
-			None => {}
+		Message::ResetDb => {
+			if app_state.adapter_state.connection.take().is_none() {
+				return Task::none();
+			}
+			app_state.status_msg = "Reconnecting...".into();
+			let config = app_state.adapter_state.configuration.clone();
+			return Task::perform(
+				async move { AdapterState::connect(config).await },
+				Message::AdapterConnected,
+			);
+		}
+		Message::Run => {
+			let code = app_state.code_editor.content();
+			if app_state.query_snapshots.front().is_none_or(|snapshot| snapshot.sql != code) {
+				app_state.query_snapshots.push_front(QuerySnapshot {
+					sql: code,
+					taken_at: chrono::Local::now(),
+				});
+				app_state.query_snapshots.truncate(QUERY_SNAPSHOT_LIMIT);
+			}
+			match &mut app_state.adapter_state.connection {
+				None => {
+					app_state.status_msg = "".to_string();
+					app_state.status_error = "Not connected: configure an adapter to run code.".to_string();
+				}
+				Some(db) => {
+					let code = app_state.code_editor.content();
+					app_state.running_code = code.clone();
+					app_state.table_loading = true;
+					app_state.delimited_file = None;
+					let db = db.clone();
+					app_state.status_msg = "Code running...".into();
+					app_state.status_error = "".to_string();
+					app_state.status_elapsed_ms = None;
+					app_state.code_started = Instant::now();
+					return Task::perform(
+						async move {
+							let mut guard = db.write().await;
+							guard.dispatch(&code).await
+						},
+						Message::RunResult,
+					);
+				}
+			}
+		}
+		Message::RowsReordered(from, to) => {
+			let height = app_state.data_frame.height();
+			if from >= height || to >= height {
+				return Task::none();
+			}
+			let mut order: Vec<IdxSize> = (0..height as IdxSize).collect();
+			let row = order.remove(from);
+			order.insert(to, row);
+			// The moved order becomes the original order, so any sort is
+			// dropped. The filter base keeps the shown rows in the same slots,
+			// filled in the new order.
+			let result = app_state.data_frame.take(&IdxCa::from_vec("order".into(), order.clone())).and_then(|df| {
+				let Some(base) = &app_state.unfiltered_data_frame else {
+					return Ok((df, None));
+				};
+				let sources: Vec<IdxSize> = (0..height).map(|row| source_rows(app_state, row).1 as IdxSize).collect();
+				let mut slots = sources.clone();
+				slots.sort_unstable();
+				let mut base_order: Vec<IdxSize> = (0..base.height() as IdxSize).collect();
+				for (&slot, &row) in slots.iter().zip(&order) {
+					base_order[slot as usize] = sources[row as usize];
+				}
+				let base = base.take(&IdxCa::from_vec("order".into(), base_order))?;
+				Ok((df, Some((base, slots))))
+			});
+			match result {
+				Ok((df, unfiltered)) => {
+					app_state.data_frame = df;
+					app_state.sort = None;
+					app_state.unsorted_data_frame = None;
+					app_state.sorted_rows.clear();
+					if let Some((base, rows)) = unfiltered {
+						app_state.unfiltered_data_frame = Some(base);
+						app_state.filtered_rows = rows;
+					}
+					rows_changed(app_state);
+					refresh_compare(app_state);
+					return table_search_task(app_state);
+				}
+				Err(e) => {
+					app_state.status_error = format!("Row move failed: {e}");
+				}
+			}
+		}
+		Message::RestoreQuerySnapshot(index) => {
+			if let Some(snapshot) = app_state.query_snapshots.get(index) {
+				app_state.code_editor.set_content(&snapshot.sql);
+				app_state.status_msg = "Query restored from snapshot.".to_string();
+			}
+		}
+		Message::Explain => match &app_state.adapter_state.connection {
+			None => {
+				app_state.status_msg = "".to_string();
+				app_state.status_error = "Not connected: configure an adapter to explain code.".to_string();
+			}
 			Some(db) => {
-				let code = app_state.code_editor.content();
+				let explain = match app_state.adapter_state.selection {
+					AdapterSelection::SQLite => "EXPLAIN QUERY PLAN",
+					_ => "EXPLAIN",
+				};
+				let code = format!("{explain} {}", app_state.code_editor.content().trim());
 				let db = db.clone();
-				app_state.status_msg = "Code running...".into();
+				app_state.status_msg = "Explaining query...".into();
 				app_state.status_error = "".to_string();
-				app_state.status_time_elapsed = None;
-				app_state.code_started = Instant::now();
 				return Task::perform(
 					async move {
 						let mut guard = db.write().await;
 						guard.dispatch(&code).await
 					},
-					Message::RunResult,
+					Message::ExplainResult,
+				);
+			}
+		},
+		Message::ExplainResult(er) => {
+			let plan = match er {
+				ExecutionResult::Rows(df) => query_plan_text(&df),
+				ExecutionResult::Err(e) => {
+					app_state.status_msg = "".to_string();
+					app_state.status_error = format!("Explain failed: {e}");
+					return Task::none();
+				}
+				other => format!("{other:?}"),
+			};
+			app_state.query_plan = text_editor::Content::with_text(&plan);
+			app_state.status_msg = "Query plan ready.".to_string();
+			let has_plan_pane = app_state.panes.iter().any(|(_, pane_type)| *pane_type == PaneType::QueryPlan);
+			let editor_pane = app_state
+				.panes
+				.iter()
+				.find(|(_, pane_type)| **pane_type == PaneType::CodeEditor)
+				.map(|(pane, _)| *pane);
+			if !has_plan_pane && let Some(editor_pane) = editor_pane {
+				let _ = app_state.panes.split(pane_grid::Axis::Vertical, editor_pane, PaneType::QueryPlan);
+			}
+		}
+		Message::RefreshSchema => match &app_state.adapter_state.connection {
+			None => {
+				app_state.status_msg = "".to_string();
+				app_state.status_error = "Not connected: configure an adapter to browse the schema.".to_string();
+			}
+			Some(db) => {
+				let code = match app_state.adapter_state.selection {
+					AdapterSelection::SQLite => SQLITE_SCHEMA_QUERY,
+					_ => INFORMATION_SCHEMA_QUERY,
+				};
+				let db = db.clone();
+				app_state.status_msg = "Loading schema...".into();
+				app_state.status_error = "".to_string();
+				return Task::perform(
+					async move {
+						let mut guard = db.write().await;
+						guard.dispatch(code).await
+					},
+					Message::SchemaLoaded,
 				);
 			}
 		},
+		Message::SchemaLoaded(er) => {
+			let df = match er {
+				ExecutionResult::Rows(df) => df,
+				ExecutionResult::Err(e) => {
+					app_state.status_msg = "".to_string();
+					app_state.status_error = format!("Schema load failed: {e}");
+					return Task::none();
+				}
+				_ => DataFrame::default(),
+			};
+			app_state.schema = schema_from_frame(&df);
+			app_state.schema_expanded.clear();
+			app_state.schema_cursor = None;
+			app_state.status_msg = format!("Loaded schema for {} tables.", app_state.schema.len());
+			let has_schema_pane = app_state.panes.iter().any(|(_, pane_type)| *pane_type == PaneType::Schema);
+			let editor_pane = app_state
+				.panes
+				.iter()
+				.find(|(_, pane_type)| **pane_type == PaneType::CodeEditor)
+				.map(|(pane, _)| *pane);
+			if !has_schema_pane
+				&& let Some(editor_pane) = editor_pane
+				&& let Some((schema_pane, split)) =
+					app_state.panes.split(pane_grid::Axis::Vertical, editor_pane, PaneType::Schema)
+			{
+				app_state.panes.swap(editor_pane, schema_pane);
+				app_state.panes.resize(split, 0.2);
+			}
+		}
+		Message::SchemaKeyPressed(key) => {
+			let schema_focused = app_state
+				.focused_pane
+				.and_then(|pane| app_state.panes.get(pane))
+				.is_some_and(|pane_type| *pane_type == PaneType::Schema);
+			let nodes = components::schema_nodes(&app_state.schema, &app_state.schema_expanded);
+			if !schema_focused || nodes.is_empty() {
+				return Task::none();
+			}
+			let cursor = app_state.schema_cursor.map(|cursor| cursor.min(nodes.len() - 1));
+			let (table_idx, _) = nodes[cursor.unwrap_or(0)];
+			match key {
+				keyboard::key::Named::ArrowDown => {
+					app_state.schema_cursor = Some(cursor.map_or(0, |cursor| (cursor + 1).min(nodes.len() - 1)));
+				}
+				keyboard::key::Named::ArrowUp => {
+					app_state.schema_cursor = Some(cursor.map_or(0, |cursor| cursor.saturating_sub(1)));
+				}
+				keyboard::key::Named::ArrowRight => {
+					app_state.schema_expanded.insert(table_idx);
+				}
+				keyboard::key::Named::ArrowLeft => {
+					app_state.schema_expanded.remove(&table_idx);
+					app_state.schema_cursor = components::schema_nodes(&app_state.schema, &app_state.schema_expanded)
+						.iter()
+						.position(|&node| node == (table_idx, None));
+				}
+				keyboard::key::Named::Enter => {
+					return update(
+						app_state,
+						match nodes[cursor.unwrap_or(0)] {
+							(_, Some(col_idx)) => Message::SchemaColumnClicked(table_idx, col_idx),
+							(_, None) => Message::SchemaTableClicked(table_idx),
+						},
+					);
+				}
+				_ => {}
+			}
+		}
+		Message::SchemaTableClicked(table_idx) => {
+			let Some(table) = app_state.schema.get(table_idx) else {
+				return Task::none();
+			};
+			let quote = |name: &str| match app_state.adapter_state.selection {
+				AdapterSelection::BigQuery | AdapterSelection::MySQL => format!("`{}`", name.replace('`', "``")),
+				_ => format!("\"{}\"", name.replace('"', "\"\"")),
+			};
+			let sql = if table.schema.is_empty() {
+				format!("SELECT * FROM {} LIMIT 100", quote(&table.name))
+			} else {
+				format!("SELECT * FROM {}.{} LIMIT 100", quote(&table.schema), quote(&table.name))
+			};
+			app_state.schema_cursor = components::schema_nodes(&app_state.schema, &app_state.schema_expanded)
+				.iter()
+				.position(|&node| node == (table_idx, None));
+			return app_state
+				.code_editor
+				.update(EditorMsg::Paste(sql))
+				.map(Message::CodeEditEvent);
+		}
+		Message::SchemaColumnClicked(table_idx, col_idx) => {
+			app_state.schema_cursor = components::schema_nodes(&app_state.schema, &app_state.schema_expanded)
+				.iter()
+				.position(|&node| node == (table_idx, Some(col_idx)));
+			let Some((name, _)) = app_state.schema.get(table_idx).and_then(|table| table.columns.get(col_idx)) else {
+				return Task::none();
+			};
+			if let Some(col_idx) = app_state.data_frame.get_column_index(name) {
+				return update(app_state, Message::JumpToColumn(col_idx));
+			}
+		}
+		Message::JumpToColumn(col_idx) => {
+			let request = app_state.jump_to_col.map_or(0, |(_, request)| request + 1);
+			app_state.jump_to_col = Some((col_idx, request));
+			app_state.hidden_cols.remove(&col_idx);
+		}
+		Message::ToggleSchemaTable(table_idx) => {
+			if !app_state.schema_expanded.remove(&table_idx) {
+				app_state.schema_expanded.insert(table_idx);
+			}
+			app_state.schema_cursor = components::schema_nodes(&app_state.schema, &app_state.schema_expanded)
+				.iter()
+				.position(|&node| node == (table_idx, None));
+		}
+		Message::QueryPlanAction(action) => {
+			if !action.is_edit() {
+				app_state.query_plan.perform(action);
+			}
+		}
 		Message::RunResult(er) => {
-			let time_elapsed = (app_state.code_started.elapsed().as_millis() as f64) / 1000.0;
-			app_state.status_time_elapsed = Some(time_elapsed);
+			let elapsed_ms = app_state.code_started.elapsed().as_millis() as u64;
+			app_state.table_loading = false;
+			app_state.status_elapsed_ms = Some(elapsed_ms);
 			app_state.status_error = "".to_string();
+			let row_count = match &er {
+				ExecutionResult::Err(_) => None,
+				ExecutionResult::Rows(df) => Some(df.height()),
+				ExecutionResult::Affected(rows_affected) => Some(*rows_affected as usize),
+				_ => Some(0),
+			};
+			if let Some(row_count) = row_count {
+				push_query_history(app_state, row_count, elapsed_ms);
+			}
 			match er {
 				ExecutionResult::Affected(rows_affected) => {
 					app_state.status_msg = format!("Rows affected: {rows_affected}");
@@ -381,17 +1050,52 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 					app_state.status_msg = "".to_string();
 				}
 				ExecutionResult::Rows(df) => {
-					let rows = df.height();
-					let cols = df.width();
-					app_state.data_frame = df;
-					app_state.status_df_size = Some((rows, cols));
 					app_state.status_msg = "Code finished.".to_string();
+					if app_state.data_frame.width() > 0 {
+						app_state.previous_result = Some(app_state.data_frame.clone());
+					}
+					app_state.join_preview = JoinPreviewState::default();
+					return replace_data_frame(app_state, df);
 				}
 				ExecutionResult::None => {
 					app_state.status_msg = "Noop finished.".to_string();
 				}
 			}
 		}
+		Message::FileDropped(path) | Message::FileChosen(Some(path)) => {
+			return load_file(app_state, path, None);
+		}
+		Message::FileChosen(None) => {}
+		Message::SetDelimiter(delimiter) => {
+			app_state.delimiter = delimiter;
+			if let Some(path) = app_state.delimited_file.clone() {
+				return load_file(app_state, path, Some(delimiter));
+			}
+		}
+		Message::OpenFile => {
+			return Task::perform(
+				async {
+					rfd::AsyncFileDialog::new()
+						.add_filter("Data", &["csv", "tsv", "parquet", "json"])
+						.pick_file()
+						.await
+						.map(|file| file.path().to_path_buf())
+				},
+				Message::FileChosen,
+			);
+		}
+		Message::DataLoaded(result) => match result {
+			Ok(df) => {
+				app_state.table_loading = false;
+				app_state.status_msg = format!("Loaded {} rows.", components::format_count(df.height()));
+				return replace_data_frame(app_state, df);
+			}
+			Err(e) => {
+				app_state.status_msg = "".to_string();
+				app_state.status_error = format!("Load failed: {e}");
+				app_state.table_loading = false;
+			}
+		},
 		Message::AddPlot(plot_type) => {
 			app_state.status_msg = format!("Generating {plot_type} plot...");
 			return build_plot_task(app_state.data_frame.clone(), plot_type, move |kernel| {
@@ -400,6 +1104,15 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 		}
 		Message::AddPlotReady(plot_type, kernel) => {
 			let new_plot = PlotState::with_kernel(plot_type, kernel, 1200, 1200);
+			let has_dashboard_pane = app_state.panes.iter().any(|(_, pane_type)| *pane_type == PaneType::Dashboard);
+			let data_pane = app_state
+				.panes
+				.iter()
+				.find(|(_, pane_type)| **pane_type == PaneType::DataTable)
+				.map(|(pane, _)| *pane);
+			if !has_dashboard_pane && let Some(data_pane) = data_pane {
+				let _ = app_state.panes.split(pane_grid::Axis::Horizontal, data_pane, PaneType::Dashboard);
+			}
 			if let Some(dashboard) = &mut app_state.dashboard {
 				let last_pane = *dashboard.panes.keys().next().unwrap();
 				let _ = dashboard.split(pane_grid::Axis::Horizontal, last_pane, new_plot);
@@ -413,6 +1126,17 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 			if let Some(dashboard) = &mut app_state.dashboard {
 				if dashboard.panes.len() <= 1 {
 					app_state.dashboard = None;
+					let dashboard_pane = app_state
+						.panes
+						.iter()
+						.find(|(_, pane_type)| **pane_type == PaneType::Dashboard)
+						.map(|(pane, _)| *pane);
+					if let Some(pane) = dashboard_pane {
+						app_state.panes.close(pane);
+						if app_state.focused_pane == Some(pane) {
+							app_state.focused_pane = None;
+						}
+					}
 				} else {
 					let _ = dashboard.close(pane);
 				}
@@ -573,9 +1297,150 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 				);
 			}
 		}
+		Message::ExportCSV => {
+			let df = app_state.data_frame.clone();
+			let filename = format!("table_{}.csv", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+			app_state.status_msg = format!("Exporting {} rows to CSV...", df.height());
+			return Task::perform(
+				async move {
+					let Some(file) = rfd::AsyncFileDialog::new()
+						.add_filter("CSV", &["csv"])
+						.set_file_name(filename)
+						.save_file()
+						.await
+					else {
+						return Ok(None);
+					};
+					let path = file.path().to_path_buf();
+					tokio::task::spawn_blocking(move || {
+						table_export::write_csv(&df, &path).map_err(|e| e.to_string())?;
+						Ok(Some(path.display().to_string()))
+					})
+					.await
+					.expect("csv export task panicked")
+				},
+				Message::ExportCSVDone,
+			);
+		}
+		Message::ExportCSVDone(result) => match result {
+			Ok(Some(path)) => {
+				app_state.status_msg = format!("Exported CSV to {path}.");
+			}
+			Ok(None) => {
+				app_state.status_msg = "CSV export cancelled.".to_string();
+			}
+			Err(e) => {
+				app_state.status_error = format!("CSV export failed: {e}");
+			}
+		},
+		Message::ExportMarkdown => {
+			let rows = app_state.data_frame.height();
+			let visible = app_state
+				.data_frame
+				.get_column_names_owned()
+				.into_iter()
+				.enumerate()
+				.filter(|(col_idx, _)| !app_state.hidden_cols.contains(col_idx))
+				.map(|(_, name)| name);
+			let frame = match app_state.data_frame.select(visible) {
+				Ok(frame) => frame,
+				Err(e) => {
+					app_state.status_error = format!("Markdown export failed: {e}");
+					return Task::none();
+				}
+			};
+			let mut markdown = table_export::to_markdown(&frame, MARKDOWN_EXPORT_ROWS);
+			if rows > MARKDOWN_EXPORT_ROWS {
+				markdown.push_str(&format!(
+					"\n_Showing the first {} of {} rows._\n",
+					components::format_count(MARKDOWN_EXPORT_ROWS),
+					components::format_count(rows)
+				));
+			}
+			app_state.status_msg = format!(
+				"Copied {} rows as Markdown.",
+				components::format_count(rows.min(MARKDOWN_EXPORT_ROWS))
+			);
+			return iced::clipboard::write(markdown);
+		}
+		Message::ExportMarkdownShortcut => {
+			if app_state.focused_pane.is_some() && app_state.focused_pane == find_pane(app_state, PaneType::DataTable) {
+				return update(app_state, Message::ExportMarkdown);
+			}
+		}
 		Message::ExportDone(count, format) => {
 			app_state.status_msg = format!("Exported {count} plots as {format}.");
 		}
+		Message::ExportParquet => {
+			let df = app_state.data_frame.clone();
+			let filename = format!("table_{}.parquet", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+			app_state.status_msg = format!("Exporting {} rows to Parquet...", df.height());
+			return Task::perform(
+				async move {
+					let Some(file) = rfd::AsyncFileDialog::new()
+						.add_filter("Parquet", &["parquet"])
+						.set_file_name(filename)
+						.save_file()
+						.await
+					else {
+						return Ok(None);
+					};
+					let path = file.path().to_path_buf();
+					tokio::task::spawn_blocking(move || {
+						let schema = table_export::write_parquet(&df, &path).map_err(|e| e.to_string())?;
+						Ok(Some((path.display().to_string(), df.height(), schema)))
+					})
+					.await
+					.expect("parquet export task panicked")
+				},
+				Message::ExportParquetDone,
+			);
+		}
+		Message::ExportParquetDone(result) => match result {
+			Ok(Some((path, rows, schema))) => {
+				app_state.status_msg = format!(
+					"Exported {} rows to {path}: {schema}",
+					components::format_count(rows)
+				);
+			}
+			Ok(None) => {
+				app_state.status_msg = "Parquet export cancelled.".to_string();
+			}
+			Err(e) => {
+				app_state.status_error = format!("Parquet export failed: {e}");
+			}
+		},
+		Message::Screenshot => {
+			return window::latest()
+				.and_then(window::screenshot)
+				.map(Message::ScreenshotReady);
+		}
+		Message::ScreenshotReady(screenshot) => {
+			let filename = format!("polariton_{}.png", chrono::Local::now().format("%Y-%m-%d_%H%M%S"));
+			return Task::perform(
+				async move {
+					tokio::task::spawn_blocking(move || {
+						let path = std::env::home_dir()
+							.ok_or_else(|| "no home directory to save into".to_string())?
+							.join(&filename);
+						export::save_rgba_png(&path, screenshot.size.width, screenshot.size.height, &screenshot.rgba)
+							.map(|()| path.display().to_string())
+							.map_err(|e| e.to_string())
+					})
+					.await
+					.expect("screenshot task panicked")
+				},
+				Message::ScreenshotSaved,
+			);
+		}
+		Message::ScreenshotSaved(result) => match result {
+			Ok(path) => {
+				app_state.status_msg = format!("Saved screenshot to {path}");
+			}
+			Err(e) => {
+				app_state.status_error = format!("Screenshot failed: {e}");
+			}
+		},
 		Message::ConnectionNameChanged(name) => {
 			app_state.adapter_state.name = name;
 		}
@@ -639,6 +1504,7 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 		Message::EditConnection(id) => {
 			if let Some(saved) = app_state.saved_connections.iter().find(|c| c.id == id) {
 				let selection = match saved.adapter_type.as_str() {
+					"DuckDB" => AdapterSelection::DuckDB,
 					"Parquet" => AdapterSelection::Parquet,
 					"Postgres" => AdapterSelection::Postgres,
 					"SQLite" => AdapterSelection::SQLite,
@@ -730,8 +1596,7 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 		}
 		Message::LoadSavedStatement(id) => {
 			if let Some(stmt) = app_state.saved_statements.iter().find(|s| s.id == id) {
-				let new_editor = CodeEditor::new(&stmt.code, SyntaxLanguage::Sql);
-				app_state.code_editor = new_editor;
+				app_state.code_editor.set_content(&stmt.code);
 				app_state.status_msg = format!("Statement '{}' loaded.", stmt.name);
 			}
 		}
@@ -851,16 +1716,871 @@ fn update(app_state: &mut AppState, message: Message) -> Task<Message> {
 			);
 		}
 		Message::ShowColumnTypesSaved => {}
+		Message::PrefsChanged(prefs) => {
+			app_state.prefs = prefs;
+			app_state.code_editor.auto_pairs = prefs.auto_close_brackets;
+			app_state.code_editor.tab_width = prefs.tab_width;
+			return Task::perform(persistence::save_prefs(prefs), |()| Message::PrefsSaved);
+		}
+		Message::PrefsSaved => {}
+		Message::OpenTableSearch => {
+			return iced::widget::operation::focus(components::table_search_id());
+		}
+		Message::OpenUrl(url) => {
+			let opener = if cfg!(target_os = "macos") {
+				"open"
+			} else if cfg!(target_os = "windows") {
+				"explorer"
+			} else {
+				"xdg-open"
+			};
+			match std::process::Command::new(opener).arg(&url).spawn() {
+				Ok(mut child) => {
+					std::thread::spawn(move || child.wait());
+				}
+				Err(e) => {
+					app_state.status_error = format!("Failed to open {url}: {e}");
+				}
+			}
+		}
+		Message::CloseTableSearch => {
+			app_state.table_search_query.clear();
+			app_state.table_search_hits.clear();
+			app_state.table_search_index = None;
+		}
+		Message::TableSearchChanged(query) => {
+			app_state.table_search_query = query;
+			if app_state.table_search_query.is_empty() {
+				app_state.table_search_hits.clear();
+				app_state.table_search_index = None;
+				return Task::none();
+			}
+			return table_search_task(app_state);
+		}
+		Message::TableSearchResult(query, hits) => {
+			if query == app_state.table_search_query {
+				app_state.table_search_index = (!hits.is_empty()).then_some(0);
+				app_state.table_search_hits = hits;
+			}
+		}
+		Message::TableSearchNext => {
+			let count = app_state.table_search_hits.len();
+			if count > 0 {
+				app_state.table_search_index =
+					Some(app_state.table_search_index.map_or(0, |i| (i + 1) % count));
+			}
+		}
+		Message::TableSearchPrev => {
+			let count = app_state.table_search_hits.len();
+			if count > 0 {
+				app_state.table_search_index =
+					Some(app_state.table_search_index.map_or(0, |i| (i + count - 1) % count));
+			}
+		}
+		Message::CellDoubleClicked(row, col) => {
+			let value = app_state
+				.data_frame
+				.columns()
+				.get(col)
+				.map(|column| table::cell_to_string(column, row))
+				.unwrap_or_default();
+			app_state.editing_cell = Some((row, col, value));
+			return iced::widget::operation::focus(components::cell_edit_id());
+		}
+		Message::CellImagesRequested(sources) => {
+			if app_state.cell_images.len() + sources.len() > CELL_IMAGE_CACHE_LIMIT {
+				app_state.cell_images.clear();
+			}
+			let mut tasks = Vec::new();
+			for source in sources {
+				if app_state.cell_images.contains_key(&source) {
+					continue;
+				}
+				app_state.cell_images.insert(source.clone(), CellImage::Loading);
+				tasks.push(Task::perform(load_cell_image(source), |(source, image)| {
+					Message::CellImageLoaded(source, image)
+				}));
+			}
+			return Task::batch(tasks);
+		}
+		Message::CellImageLoaded(source, image) => {
+			// Loads that finish after the cache was dropped are discarded.
+			if let Some(entry) = app_state.cell_images.get_mut(&source) {
+				*entry = image;
+			}
+		}
+		Message::CellEditChanged(value) => {
+			if let Some((_, _, editing)) = &mut app_state.editing_cell {
+				*editing = value;
+			}
+		}
+		Message::CancelCellEdit => {
+			app_state.editing_cell = None;
+		}
+		Message::CellEdited { row, col, value } => {
+			app_state.editing_cell = None;
+			// The edit goes to the sort and filter bases too so it survives
+			// clearing or changing either.
+			let (unsorted_row, unfiltered_row) = source_rows(app_state, row);
+			let result = set_cell(&mut app_state.data_frame, row, col, &value)
+				.and_then(|()| match &mut app_state.unsorted_data_frame {
+					Some(df) => set_cell(df, unsorted_row, col, &value),
+					None => Ok(()),
+				})
+				.and_then(|()| match &mut app_state.unfiltered_data_frame {
+					Some(df) => set_cell(df, unfiltered_row, col, &value),
+					None => Ok(()),
+				});
+			match result {
+				Ok(()) => {
+					rows_changed(app_state);
+					refresh_compare(app_state);
+					app_state.status_msg = format!("Row {} updated.", row + 1);
+					return table_search_task(app_state);
+				}
+				Err(e) => {
+					app_state.status_error = format!("Cell edit failed: {e}");
+				}
+			}
+		}
+		Message::ToggleQueryHistory => {
+			let find_pane = |target: PaneType| {
+				app_state
+					.panes
+					.iter()
+					.find(|(_, pane_type)| **pane_type == target)
+					.map(|(pane, _)| *pane)
+			};
+			let history_pane = find_pane(PaneType::QueryHistory);
+			let data_pane = find_pane(PaneType::DataTable);
+			if let Some(pane) = history_pane {
+				app_state.panes.close(pane);
+				if app_state.focused_pane == Some(pane) {
+					app_state.focused_pane = None;
+				}
+			} else if let Some(data_pane) = data_pane {
+				let _ = app_state.panes.split(
+					pane_grid::Axis::Vertical,
+					data_pane,
+					PaneType::QueryHistory,
+				);
+			}
+		}
+		Message::SplitDragStarted => {
+			app_state.editor_split.dragging = true;
+		}
+		Message::SplitDragEnded => {
+			app_state.editor_split.dragging = false;
+		}
+		Message::SplitMeasured(width) => {
+			app_state.editor_split.width = width;
+		}
+		Message::SplitResized(ratio) => {
+			app_state.editor_split.ratio = ratio;
+			// Keeps the pane grid at the same split once another pane opens.
+			app_state.panes.resize(app_state.editor_table_split, ratio);
+		}
+		Message::FetchHistoryRows(start, count) => {
+			app_state.query_history_window = (start, count);
+			refresh_query_history_frame(app_state);
+		}
+		Message::LoadHistoryEntry(sql) => {
+			app_state.code_editor.set_content(&sql);
+			app_state.status_msg = "Query restored from history.".to_string();
+		}
+		Message::TableSelectionChanged(count) => {
+			app_state.selected_row_count = count;
+		}
+		Message::TableLayoutChanged(layout) => {
+			app_state.table_layout = Some(layout);
+		}
+		Message::TableRowClicked(row) => {
+			app_state.focused_pane = find_pane(app_state, PaneType::DataTable);
+			app_state.status_msg = format!("Row {} selected.", row + 1);
+		}
+		Message::SortRequested(col_idx, order) => {
+			app_state.sort = order.map(|order| (col_idx, order));
+			let base = app_state
+				.unsorted_data_frame
+				.get_or_insert_with(|| app_state.data_frame.clone())
+				.clone();
+			let Some(order) = order else {
+				app_state.data_frame = base;
+				app_state.unsorted_data_frame = None;
+				app_state.sorted_rows.clear();
+				rows_changed(app_state);
+				app_state.status_msg = "Sort cleared.".to_string();
+				return table_search_task(app_state);
+			};
+			let Some(col_name) = base.get_column_names().get(col_idx).map(|n| n.to_string()) else {
+				return Task::none();
+			};
+			app_state.status_msg = format!("Sorting by {col_name}...");
+			return Task::perform(
+				async move {
+					tokio::task::spawn_blocking(move || sort_frame(&base, col_idx, order))
+					.await
+					.expect("sort task panicked")
+				},
+				Message::TableSorted,
+			);
+		}
+		Message::ColumnsReordered(from, to) => {
+			let mut names = app_state.data_frame.get_column_names_owned();
+			if from >= names.len() || to >= names.len() {
+				return Task::none();
+			}
+			let name = names.remove(from);
+			names.insert(to, name);
+			match app_state.data_frame.select(names.clone()) {
+				Ok(df) => app_state.data_frame = df,
+				Err(e) => {
+					app_state.status_error = format!("Column reorder failed: {e}");
+					return Task::none();
+				}
+			}
+			if let Some(base) = &app_state.unsorted_data_frame
+				&& let Ok(df) = base.select(names.clone())
+			{
+				app_state.unsorted_data_frame = Some(df);
+			}
+			if let Some(base) = &app_state.unfiltered_data_frame
+				&& let Ok(df) = base.select(names)
+			{
+				app_state.unfiltered_data_frame = Some(df);
+			}
+			app_state.sort = app_state
+				.sort
+				.map(|(col, order)| (table::moved_col_index(col, from, to), order));
+			if from < app_state.col_filters.len() {
+				let filter = app_state.col_filters.remove(from);
+				app_state.col_filters.insert(to.min(app_state.col_filters.len()), filter);
+			}
+			app_state.hidden_cols = app_state
+				.hidden_cols
+				.iter()
+				.map(|&col| table::moved_col_index(col, from, to))
+				.collect();
+			app_state.image_cols = app_state
+				.image_cols
+				.iter()
+				.map(|&col| table::moved_col_index(col, from, to))
+				.collect();
+			app_state.numeric_formats = app_state
+				.numeric_formats
+				.drain()
+				.map(|(col, format)| (table::moved_col_index(col, from, to), format))
+				.collect();
+			refresh_compare(app_state);
+			return table_search_task(app_state);
+		}
+		Message::ToggleFilterRow => {
+			app_state.show_filter_row = !app_state.show_filter_row;
+			app_state.col_filters.clear();
+			if let Some(df) = app_state.unfiltered_data_frame.take() {
+				app_state.status_df_size = Some((df.height(), df.width()));
+				app_state.data_frame = df;
+				app_state.unsorted_data_frame = None;
+				app_state.sorted_rows.clear();
+				app_state.filtered_rows.clear();
+				rows_changed(app_state);
+				refresh_compare(app_state);
+				if let Some((col_idx, order)) = app_state.sort {
+					return update(app_state, Message::SortRequested(col_idx, Some(order)));
+				}
+				return table_search_task(app_state);
+			}
+		}
+		Message::FilterChanged(col_idx, value) => {
+			if app_state.col_filters.len() <= col_idx {
+				app_state.col_filters.resize(col_idx + 1, String::new());
+			}
+			app_state.col_filters[col_idx] = value;
+			// Filters apply to the rows in their original order; the active sort
+			// is applied again to what's left.
+			let base = app_state
+				.unfiltered_data_frame
+				.get_or_insert_with(|| app_state.unsorted_data_frame.as_ref().unwrap_or(&app_state.data_frame).clone())
+				.clone();
+			let filters = app_state.col_filters.clone();
+			let sort = app_state.sort;
+			return Task::perform(
+				async move {
+					tokio::task::spawn_blocking(move || {
+						let result = table::filter_rows(&base, &filters).and_then(|rows| {
+							let df = base
+								.take(&IdxCa::from_vec("rows".into(), rows.clone()))
+								.map_err(|e| e.to_string())?;
+							let sorted = sort
+								.map(|(col_idx, order)| sort_frame(&df, col_idx, order))
+								.transpose()?;
+							Ok(((df, rows), sorted))
+						});
+						(filters, result)
+					})
+					.await
+					.expect("filter task panicked")
+				},
+				|(filters, result)| Message::TableFiltered(filters, result),
+			);
+		}
+		Message::TableFiltered(filters, result) => {
+			if filters != app_state.col_filters {
+				return Task::none();
+			}
+			match result {
+				Ok(((df, rows), sorted)) => {
+					app_state.status_error = "".to_string();
+					let total = app_state.unfiltered_data_frame.as_ref().map_or(0, DataFrame::height);
+					app_state.status_msg = format!(
+						"{} of {} rows match.",
+						components::format_count(df.height()),
+						components::format_count(total)
+					);
+					app_state.status_df_size = Some((df.height(), df.width()));
+					app_state.filtered_rows = rows;
+					match sorted {
+						Some((sorted_df, sorted_rows)) => {
+							app_state.data_frame = sorted_df;
+							app_state.unsorted_data_frame = Some(df);
+							app_state.sorted_rows = sorted_rows;
+						}
+						None => {
+							app_state.data_frame = df;
+							app_state.unsorted_data_frame = None;
+							app_state.sorted_rows.clear();
+						}
+					}
+					app_state.editing_cell = None;
+					rows_changed(app_state);
+					refresh_compare(app_state);
+					return table_search_task(app_state);
+				}
+				Err(e) => {
+					app_state.status_error = format!("Filter failed: {e}");
+				}
+			}
+		}
+		Message::ShowColumnMenu(col_idx, position) => {
+			app_state.column_menu = Some((col_idx, position));
+		}
+		Message::CloseColumnMenu => {
+			app_state.column_menu = None;
+		}
+		Message::CellContextMenu { row, col, screen_pos } => {
+			app_state.cell_menu = Some((row, col, screen_pos));
+		}
+		Message::CloseCellMenu => {
+			app_state.cell_menu = None;
+		}
+		Message::CloseCommandPalette => {
+			app_state.command_palette_open = false;
+			app_state.command_palette_query.clear();
+			app_state.command_palette_index = 0;
+		}
+		Message::ToggleCommandPalette => {
+			app_state.command_palette_open = !app_state.command_palette_open;
+			app_state.command_palette_query.clear();
+			app_state.command_palette_index = 0;
+			if app_state.command_palette_open {
+				app_state.command_palette_frame =
+					Arc::new(components::command_palette_frame(&app_state.commands, ""));
+				return iced::widget::operation::focus(components::command_palette_id());
+			}
+		}
+		Message::CommandPaletteQueryChanged(query) => {
+			app_state.command_palette_frame =
+				Arc::new(components::command_palette_frame(&app_state.commands, &query));
+			app_state.command_palette_query = query;
+			app_state.command_palette_index = 0;
+		}
+		Message::CommandPaletteKey(key) => match key {
+			keyboard::key::Named::ArrowDown => return update(app_state, Message::CommandPaletteMove(1)),
+			keyboard::key::Named::ArrowUp => return update(app_state, Message::CommandPaletteMove(-1)),
+			keyboard::key::Named::Enter => return update(app_state, Message::CommandPaletteSubmit),
+			_ => {}
+		},
+		Message::CommandPaletteMove(delta) => {
+			let count = components::filter_commands(&app_state.commands, &app_state.command_palette_query).len();
+			app_state.command_palette_index = app_state
+				.command_palette_index
+				.saturating_add_signed(delta)
+				.min(count.saturating_sub(1));
+		}
+		Message::CommandPaletteSubmit => {
+			return update(app_state, Message::RunPaletteCommand(app_state.command_palette_index));
+		}
+		Message::RunPaletteCommand(index) => {
+			let Some(message) = components::filter_commands(&app_state.commands, &app_state.command_palette_query)
+				.get(index)
+				.map(|command| command.msg.clone())
+			else {
+				return Task::none();
+			};
+			app_state.command_palette_open = false;
+			app_state.command_palette_query.clear();
+			app_state.command_palette_index = 0;
+			return update(app_state, message);
+		}
+		Message::CopyCell(row, col) => {
+			app_state.cell_menu = None;
+			let Some(column) = app_state.data_frame.columns().get(col) else {
+				return Task::none();
+			};
+			app_state.status_msg = "Copied cell.".to_string();
+			return iced::clipboard::write(table::cell_to_string(column, row));
+		}
+		Message::CopyRow(row) => {
+			app_state.cell_menu = None;
+			let line: Vec<String> = visible_columns(app_state)
+				.map(|column| table::cell_to_string(column, row))
+				.collect();
+			app_state.status_msg = "Copied row.".to_string();
+			return iced::clipboard::write(line.join("\t"));
+		}
+		Message::CopyColumn(col) => {
+			app_state.cell_menu = None;
+			let Some(column) = app_state.data_frame.columns().get(col) else {
+				return Task::none();
+			};
+			let lines: Vec<String> = (0..column.len()).map(|row| table::cell_to_string(column, row)).collect();
+			app_state.status_msg = format!("Copied {} values.", components::format_count(lines.len()));
+			return iced::clipboard::write(lines.join("\n"));
+		}
+		Message::SetNumericFormat(col_idx, format) => {
+			match format {
+				Some(format) => app_state.numeric_formats.insert(col_idx, format),
+				None => app_state.numeric_formats.remove(&col_idx),
+			};
+		}
+		Message::SetSummaryMode(mode) => {
+			app_state.summary_mode = mode;
+		}
+		Message::ToggleImageColumn(col_idx, enabled) => {
+			if enabled {
+				app_state.image_cols.insert(col_idx);
+			} else {
+				app_state.image_cols.remove(&col_idx);
+			}
+		}
+		Message::ToggleColumnVisibility(col_idx, visible) => {
+			if visible {
+				app_state.hidden_cols.remove(&col_idx);
+			} else {
+				app_state.hidden_cols.insert(col_idx);
+			}
+		}
+		Message::Tick => {}
+		Message::ToggleCompare => {
+			if app_state.compare_baseline.take().is_some() {
+				app_state.status_msg = "Compare mode off.".to_string();
+			} else {
+				app_state.compare_baseline = Some(Arc::new(app_state.data_frame.clone()));
+				app_state.status_msg = "Baseline captured; later results are compared against it.".to_string();
+			}
+			refresh_compare(app_state);
+		}
+		Message::ToggleJoinPreview => {
+			let find_pane = |target: PaneType| {
+				app_state
+					.panes
+					.iter()
+					.find(|(_, pane_type)| **pane_type == target)
+					.map(|(pane, _)| *pane)
+			};
+			if let Some(pane) = find_pane(PaneType::JoinPreview) {
+				app_state.panes.close(pane);
+				if app_state.focused_pane == Some(pane) {
+					app_state.focused_pane = None;
+				}
+			} else if let Some(data_pane) = find_pane(PaneType::DataTable) {
+				let _ = app_state.panes.split(pane_grid::Axis::Horizontal, data_pane, PaneType::JoinPreview);
+			}
+		}
+		Message::SetJoinKey(side, col_idx) => {
+			match side {
+				JoinSide::Left => app_state.join_preview.left_key = Some(col_idx),
+				JoinSide::Right => app_state.join_preview.right_key = Some(col_idx),
+			}
+			app_state.join_preview.counts = None;
+			let JoinPreviewState {
+				left_key: Some(left_key),
+				right_key: Some(right_key),
+				..
+			} = app_state.join_preview
+			else {
+				return Task::none();
+			};
+			let (Some(left), Some(right)) = (
+				app_state
+					.previous_result
+					.as_ref()
+					.and_then(|df| df.columns().get(left_key).cloned()),
+				app_state.data_frame.columns().get(right_key).cloned(),
+			) else {
+				return Task::none();
+			};
+			return Task::perform(
+				async move {
+					tokio::task::spawn_blocking(move || table_export::join_counts(&left, &right))
+						.await
+						.expect("join count task panicked")
+				},
+				move |counts| Message::JoinCounted {
+					keys: (left_key, right_key),
+					counts,
+				},
+			);
+		}
+		Message::JoinCounted { keys, counts } => {
+			if (app_state.join_preview.left_key, app_state.join_preview.right_key) == (Some(keys.0), Some(keys.1)) {
+				app_state.join_preview.counts = Some(counts);
+			}
+		}
+		Message::ToggleTheme => {
+			app_state.theme = app_state.theme.toggled();
+			app_state.code_editor.set_theme(match app_state.theme {
+				ThemeMode::Dark => EditorTheme::dark(),
+				ThemeMode::Light => EditorTheme::light(),
+			});
+		}
+		Message::ToggleTransparency => {
+			app_state.transparent = !app_state.transparent;
+		}
+		Message::TableSorted(result) => match result {
+			Ok((df, rows)) => {
+				app_state.data_frame = df;
+				app_state.sorted_rows = rows;
+				rows_changed(app_state);
+				refresh_compare(app_state);
+				app_state.status_msg = "Sort applied.".to_string();
+				return table_search_task(app_state);
+			}
+			Err(e) => {
+				app_state.status_error = format!("Sort failed: {e}");
+			}
+		},
 	}
 	Task::none()
 }
 
+/// Reads a data file in the background by extension. Delimited files use
+/// `delimiter` when given and the detected delimiter otherwise.
+fn load_file(app_state: &mut AppState, path: std::path::PathBuf, delimiter: Option<char>) -> Task<Message> {
+	let extension = path
+		.extension()
+		.and_then(|ext| ext.to_str())
+		.map(str::to_ascii_lowercase);
+	app_state.delimited_file = None;
+	let read_file: FileReader = match extension.as_deref() {
+		Some("csv" | "tsv") => {
+			let delimiter = delimiter
+				.and_then(|c| u8::try_from(c).ok())
+				.unwrap_or_else(|| {
+					let preferred = u8::try_from(app_state.prefs.default_delimiter).unwrap_or(b',');
+					table_import::detect_delimiter(&path, preferred)
+				});
+			app_state.delimiter = char::from(delimiter);
+			app_state.delimited_file = Some(path.clone());
+			Box::new(move |path: &std::path::Path| table_import::read_csv(path, delimiter))
+		}
+		Some("parquet") => Box::new(table_import::read_parquet),
+		Some("json") => Box::new(table_import::read_json),
+		_ => {
+			app_state.status_error = format!("Unsupported file type: {}", path.display());
+			return Task::none();
+		}
+	};
+	app_state.status_error = "".to_string();
+	app_state.status_msg = format!("Loading {}...", path.display());
+	app_state.table_loading = true;
+	Task::perform(
+		async move {
+			tokio::task::spawn_blocking(move || {
+				read_file(&path).map_err(|e| format!("{}: {e}", path.display()))
+			})
+			.await
+			.expect("file load task panicked")
+		},
+		Message::DataLoaded,
+	)
+}
+
+/// Reads a local path with `image::open`, or fetches an http(s) URL, and
+/// shrinks the result to a thumbnail off the UI thread.
+async fn load_cell_image(source: String) -> (String, CellImage) {
+	let decoded = if source.starts_with("http://") || source.starts_with("https://") {
+		match fetch_cell_image(&source).await {
+			Some(bytes) => tokio::task::spawn_blocking(move || image::load_from_memory(&bytes).ok())
+				.await
+				.ok()
+				.flatten(),
+			None => None,
+		}
+	} else {
+		let path = source.clone();
+		tokio::task::spawn_blocking(move || image::open(path).ok()).await.ok().flatten()
+	};
+	let image = match decoded {
+		Some(decoded) => {
+			let thumbnail = decoded.thumbnail(CELL_IMAGE_THUMBNAIL, CELL_IMAGE_THUMBNAIL).into_rgba8();
+			let (width, height) = thumbnail.dimensions();
+			CellImage::Loaded(iced::widget::image::Handle::from_rgba(width, height, thumbnail.into_raw()))
+		}
+		None => CellImage::Failed,
+	};
+	(source, image)
+}
+
+/// Downloads an image cell's URL through a shared client with a timeout,
+/// giving up on bodies over `CELL_IMAGE_MAX_BYTES`.
+async fn fetch_cell_image(url: &str) -> Option<Vec<u8>> {
+	static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+	let client = CLIENT.get_or_init(|| {
+		reqwest::Client::builder()
+			.timeout(CELL_IMAGE_FETCH_TIMEOUT)
+			.build()
+			.unwrap_or_default()
+	});
+	let mut response = client.get(url).send().await.ok()?.error_for_status().ok()?;
+	if response.content_length().is_some_and(|len| len > CELL_IMAGE_MAX_BYTES as u64) {
+		return None;
+	}
+	let mut bytes = Vec::new();
+	while let Some(chunk) = response.chunk().await.ok()? {
+		bytes.extend_from_slice(&chunk);
+		if bytes.len() > CELL_IMAGE_MAX_BYTES {
+			return None;
+		}
+	}
+	Some(bytes)
+}
+
+fn editor_command(app_state: &mut AppState, command: EditorCommand) -> Task<Message> {
+	app_state
+		.code_editor
+		.execute_command(command)
+		.map(Message::CodeEditEvent)
+}
+
+fn visible_columns(app_state: &AppState) -> impl Iterator<Item = &Column> {
+	app_state
+		.data_frame
+		.columns()
+		.iter()
+		.enumerate()
+		.filter(|(col_idx, _)| !app_state.hidden_cols.contains(col_idx))
+		.map(|(_, column)| column)
+}
+
+fn replace_data_frame(app_state: &mut AppState, df: DataFrame) -> Task<Message> {
+	app_state.status_df_size = Some((df.height(), df.width()));
+	app_state.data_frame = df;
+	app_state.sort = None;
+	app_state.unsorted_data_frame = None;
+	app_state.sorted_rows.clear();
+	app_state.unfiltered_data_frame = None;
+	app_state.filtered_rows.clear();
+	app_state.col_filters.clear();
+	app_state.hidden_cols.clear();
+	app_state.image_cols.clear();
+	app_state.numeric_formats.clear();
+	app_state.cell_images.clear();
+	app_state.column_menu = None;
+	app_state.cell_menu = None;
+	app_state.jump_to_col = None;
+	app_state.table_search_hits.clear();
+	app_state.table_search_index = None;
+	app_state.editing_cell = None;
+	rows_changed(app_state);
+	refresh_compare(app_state);
+	table_search_task(app_state)
+}
+
+fn find_pane(app_state: &AppState, target: PaneType) -> Option<pane_grid::Pane> {
+	app_state
+		.panes
+		.iter()
+		.find(|(_, pane_type)| **pane_type == target)
+		.map(|(pane, _)| *pane)
+}
+
+/// Sorts `df` on one column, with the row of `df` behind each sorted row.
+fn sort_frame(df: &DataFrame, col_idx: usize, order: SortOrder) -> std::result::Result<(DataFrame, Vec<IdxSize>), String> {
+	let column = df.columns().get(col_idx).ok_or_else(|| format!("No column {col_idx}"))?;
+	let rows = column
+		.as_materialized_series()
+		.arg_sort(SortOptions::default().with_order_descending(order == SortOrder::Descending));
+	let sorted = df.take(&rows).map_err(|e| e.to_string())?;
+	Ok((sorted, rows.into_no_null_iter().collect()))
+}
+
+/// Maps a shown row to its row in the sort base and in the filter base.
+fn source_rows(app_state: &AppState, row: usize) -> (usize, usize) {
+	let unsorted_row = match app_state.unsorted_data_frame {
+		Some(_) => app_state.sorted_rows.get(row).map_or(row, |&row| row as usize),
+		None => row,
+	};
+	let unfiltered_row = match app_state.unfiltered_data_frame {
+		Some(_) => app_state.filtered_rows.get(unsorted_row).map_or(unsorted_row, |&row| row as usize),
+		None => unsorted_row,
+	};
+	(unsorted_row, unfiltered_row)
+}
+
+/// Tells the results table its rows changed so it drops the old selection.
+fn rows_changed(app_state: &mut AppState) {
+	app_state.data_generation += 1;
+	app_state.selected_row_count = 0;
+}
+
+fn refresh_compare(app_state: &mut AppState) {
+	app_state.compare_diffs = app_state
+		.compare_baseline
+		.as_deref()
+		.map(|baseline| table_export::count_differences(&app_state.data_frame, baseline));
+}
+
+/// Groups (schema, table, column, type) rows, ordered by schema and table,
+/// into one entry per table.
+fn schema_from_frame(df: &DataFrame) -> Vec<TableSchema> {
+	let mut schema: Vec<TableSchema> = Vec::new();
+	let [table_schema, table, column, dtype] = [0, 1, 2, 3].map(|idx| df.columns().get(idx));
+	let (Some(table_schema), Some(table), Some(column), Some(dtype)) = (table_schema, table, column, dtype) else {
+		return schema;
+	};
+	for row in 0..df.height() {
+		let schema_name = table::cell_to_string(table_schema, row);
+		let name = table::cell_to_string(table, row);
+		let entry = (table::cell_to_string(column, row), table::cell_to_string(dtype, row));
+		match schema.last_mut() {
+			Some(last) if last.schema == schema_name && last.name == name => last.columns.push(entry),
+			_ => schema.push(TableSchema {
+				schema: schema_name,
+				name,
+				columns: vec![entry],
+			}),
+		}
+	}
+	schema
+}
+
+fn query_plan_text(df: &DataFrame) -> String {
+	(0..df.height())
+		.map(|row| {
+			df.columns()
+				.iter()
+				.map(|column| table::cell_to_string(column, row))
+				.collect::<Vec<_>>()
+				.join(" | ")
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+fn push_query_history(app_state: &mut AppState, row_count: usize, elapsed_ms: u64) {
+	app_state.query_history.push_front(QueryHistoryEntry {
+		sql: app_state.running_code.clone(),
+		executed_at: chrono::Local::now(),
+		row_count,
+		elapsed_ms,
+	});
+	app_state.query_history.truncate(QUERY_HISTORY_LIMIT);
+	refresh_query_history_frame(app_state);
+}
+
+/// Rebuilds the history table's frame for the rows it last asked for.
+fn refresh_query_history_frame(app_state: &mut AppState) {
+	let (start, count) = app_state.query_history_window;
+	let history: Vec<_> = app_state.query_history.iter().skip(start).take(count).collect();
+	let columns = vec![
+		Column::new(
+			"executed_at".into(),
+			history
+				.iter()
+				.map(|entry| entry.executed_at.format("%Y-%m-%d %H:%M:%S").to_string())
+				.collect::<Vec<_>>(),
+		),
+		Column::new(
+			"rows".into(),
+			history.iter().map(|entry| entry.row_count as u64).collect::<Vec<_>>(),
+		),
+		Column::new(
+			"ms".into(),
+			history.iter().map(|entry| entry.elapsed_ms).collect::<Vec<_>>(),
+		),
+		Column::new(
+			"sql".into(),
+			history
+				.iter()
+				.map(|entry| entry.sql.split_whitespace().collect::<Vec<_>>().join(" "))
+				.collect::<Vec<_>>(),
+		),
+	];
+	if let Ok(df) = DataFrame::new(history.len(), columns) {
+		app_state.query_history_frame = df;
+	}
+}
+
+fn set_cell(df: &mut DataFrame, row: usize, col: usize, value: &str) -> PolarsResult<()> {
+	let Some(column) = df.columns().get(col) else {
+		return Ok(());
+	};
+	let series = column.as_materialized_series();
+	if row >= series.len() {
+		return Ok(());
+	}
+	let replacement = if value.is_empty() {
+		Series::full_null(series.name().clone(), 1, series.dtype())
+	} else {
+		Series::new(series.name().clone(), [value]).strict_cast(series.dtype())?
+	};
+	let mut updated = series.slice(0, row);
+	updated.append(&replacement)?;
+	updated.append(&series.slice(row as i64 + 1, series.len() - row - 1))?;
+	df.with_column(updated)?;
+	Ok(())
+}
+
+fn table_search_task(app_state: &AppState) -> Task<Message> {
+	let query = app_state.table_search_query.clone();
+	if query.is_empty() {
+		return Task::none();
+	}
+	let df = app_state.data_frame.clone();
+	Task::perform(
+		async move {
+			tokio::task::spawn_blocking(move || {
+				let hits = table::find_cells(&df, &query);
+				(query, hits)
+			})
+			.await
+			.expect("table search task panicked")
+		},
+		|(query, hits)| Message::TableSearchResult(query, hits),
+	)
+}
+
 fn menu_action_to_message(action: &str) -> Option<Message> {
 	match action {
 		"noop" => None,
 		"connect:new" => Some(Message::Connect),
+		"connect:schema" => Some(Message::RefreshSchema),
+		"connect:reset" => Some(Message::ResetDb),
 		"code:run" => Some(Message::Run),
+		"code:explain" => Some(Message::Explain),
+		"table:export:csv" => Some(Message::ExportCSV),
+		"table:export:parquet" => Some(Message::ExportParquet),
+		"table:export:markdown" => Some(Message::ExportMarkdown),
+		"table:filter" => Some(Message::ToggleFilterRow),
+		"table:compare" => Some(Message::ToggleCompare),
+		"table:join_preview" => Some(Message::ToggleJoinPreview),
 		"code:save" => Some(Message::OpenSaveStatementDialog),
+		"code:undo" => Some(Message::Undo),
+		"code:redo" => Some(Message::Redo),
+		"code:comment" => Some(Message::ToggleComment),
+		"code:find_replace" => Some(Message::FindReplace),
+		"code:history" => Some(Message::ToggleQueryHistory),
 		"settings:preferences" => Some(Message::OpenSettings),
 		_ => {
 			if let Some(id) = action.strip_prefix("connect:load:") {
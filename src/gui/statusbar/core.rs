@@ -3,7 +3,7 @@ use std::time::Duration;
 use iced::widget::{Space, column, container, progress_bar, row, text};
 use iced::{Alignment, Background, Border, Color, Element, Length, Shadow};
 
-use crate::gui::colors;
+use crate::gui::colors::{self, Colors};
 use crate::gui::statusbar::spinner;
 
 const STATUS_BAR_HEIGHT: f32 = 26.0;
@@ -572,18 +572,24 @@ impl Default for LayoutConfig {
 
 impl Default for StatusBarStyle {
 	fn default() -> Self {
+		Self::from(&colors::DARK)
+	}
+}
+
+impl From<&Colors> for StatusBarStyle {
+	fn from(colors: &Colors) -> Self {
 		Self {
-			rail_background: colors::STATUS_BAR_RAIL_BACKGROUND,
-			rail_separator: colors::STATUS_BAR_RAIL_SEPARATOR,
-			segment_background: colors::STATUS_BAR_SEGMENT_BACKGROUND,
-			segment_border: colors::STATUS_BAR_SEGMENT_BORDER,
-			progress_background: colors::PROGRESS_BAR_TRACK_BACKGROUND,
-			progress_bar: colors::PROGRESS_BAR_FILL,
-			text_normal: colors::STATUS_BAR_TEXT,
-			text_accent: colors::STATUS_BAR_TEXT_ACCENT,
-			text_success: colors::STATUS_BAR_TEXT_SUCCESS,
-			text_warning: colors::STATUS_BAR_TEXT_WARNING,
-			text_danger: colors::STATUS_BAR_TEXT_DANGER,
+			rail_background: colors.status_bar_rail_background,
+			rail_separator: colors.border_dim,
+			segment_background: colors.bg_button,
+			segment_border: colors.border_dim,
+			progress_background: colors.bg_primary,
+			progress_bar: colors.brand_purple,
+			text_normal: colors.status_bar_text,
+			text_accent: colors.status_bar_text_accent,
+			text_success: colors.success,
+			text_warning: colors.warning,
+			text_danger: colors.danger,
 		}
 	}
 }
@@ -1,76 +1,1147 @@
-use crate::gui::colors;
+use crate::gui::colors::{self, Colors};
+use crate::persistence::TableLayout;
 use iced::{
 	advanced::{
 		layout::{Limits, Node},
 		mouse::{self, Cursor, Interaction, ScrollDelta},
+		overlay,
 		renderer::{self, Style},
-		text::{self, Renderer as TextRenderer, Text},
-		widget::{tree, Tree},
+		text::{self, Paragraph as _, Renderer as TextRenderer, Text},
+		widget::{operation::Focusable, tree, Operation, Tree},
+		image::{self, Renderer as ImageRenderer},
 		Clipboard, Layout, Shell, Widget,
 	},
 	alignment::{Horizontal, Vertical},
-	border, keyboard, Color, Element, Event,
+	border, keyboard, window, Color, Element, Event,
 	Length::{self, Fill},
-	Pixels, Point, Rectangle, Size,
+	Padding, Pixels, Point, Rectangle, Size, Vector,
 };
 use polars::{
-	datatypes::AnyValue,
+	datatypes::{AnyValue, TimeUnit},
 	frame::{column::Column, DataFrame},
+	prelude::IdxSize,
 };
+use std::collections::{BTreeSet, HashMap};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 
 const ROW_HEIGHT: f32 = 28.0;
 const HEADER_HEIGHT: f32 = 32.0;
 const HEADER_HEIGHT_WITH_TYPES: f32 = 48.0;
 const TYPE_LABEL_FONT_SIZE: f32 = 13.0;
+const FILTER_ROW_HEIGHT: f32 = 26.0;
+const FILTER_FONT_SIZE: f32 = 12.0;
+const FILTER_CLEAR_WIDTH: f32 = 18.0;
+const FILTER_TEXT_INSET: f32 = 4.0;
 const CELL_PADDING_X: f32 = 8.0;
+/// Font size the layout constants are designed for; they scale with `Table::font_size`.
 const FONT_SIZE: f32 = 14.0;
 const MIN_COL_WIDTH: f32 = 28.0;
 const MAX_COL_WIDTH: f32 = 320.0;
 const V_SCROLLBAR_WIDTH: f32 = 12.0;
 const H_SCROLLBAR_HEIGHT: f32 = 12.0;
+const OVERLAY_SCROLLBAR_OPACITY: f32 = 0.7;
+const OVERLAY_SCROLLBAR_FADE_STEP: f32 = 0.02;
+const OVERLAY_SCROLLBAR_FADE_TAIL: f32 = 0.3;
 const COL_RESIZE_GRAB_ZONE: f32 = 4.0;
+const COLUMN_MENU_BUTTON_WIDTH: f32 = 20.0;
+const SORT_ARROW_FONT_SIZE: f32 = 10.0;
+const SORT_ARROW_WIDTH: f32 = 12.0;
+const RESIZE_GHOST_DASH: f32 = 6.0;
+const ALTERNATING_COL_TINT: Color = Color::from_rgba(1.0, 1.0, 1.0, 0.04);
+const RESIZE_GHOST_GAP: f32 = 4.0;
+const COL_REORDER_THRESHOLD: f32 = 8.0;
+const ROW_REORDER_THRESHOLD: f32 = 4.0;
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(200);
+const SCROLL_EASING: f64 = 0.25;
+const ELASTIC_RESISTANCE: f64 = 0.3;
+const ELASTIC_MAX_FRACTION: f32 = 0.3;
+const ELASTIC_SETTLE_DELAY: Duration = Duration::from_millis(80);
+const RESIZE_HISTORY_LIMIT: usize = 20;
+const SPINNER_RADIUS: f32 = 14.0;
+const SPINNER_DOT: f32 = 6.0;
+const TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+const HEADER_TOOLTIP_DELAY: Duration = Duration::from_millis(300);
+const TOOLTIP_MAX_WIDTH: f32 = 360.0;
+const TOOLTIP_PADDING: f32 = 6.0;
+const SPARKLINE_BAR_WIDTH: f32 = 3.0;
+const FETCH_MARGIN_ROWS: usize = 50;
+const MAX_RENDERED_ROWS: usize = 500;
+const JSON_PREVIEW_MAX_LEN: usize = 2048;
 
-pub struct Table<'a> {
-	data_frame: &'a DataFrame,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+	Ascending,
+	Descending,
+}
+
+impl SortOrder {
+	fn arrow(self) -> &'static str {
+		match self {
+			SortOrder::Ascending => "▲",
+			SortOrder::Descending => "▼",
+		}
+	}
+}
+
+/// Which end of the header cell the sort arrow sits at; `Left` suits
+/// right-to-left layouts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortPosition {
+	Left,
+	#[default]
+	Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+	Fixed(f32),
+	/// Grows each row to fit its wrapped text, up to three times the base height.
+	Auto,
+}
+
+impl Default for RowHeight {
+	fn default() -> Self {
+		RowHeight::Fixed(ROW_HEIGHT)
+	}
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColumnAlignment {
+	#[default]
+	Left,
+	Center,
+	Right,
+}
+
+impl From<ColumnAlignment> for Horizontal {
+	fn from(alignment: ColumnAlignment) -> Self {
+		match alignment {
+			ColumnAlignment::Left => Horizontal::Left,
+			ColumnAlignment::Center => Horizontal::Center,
+			ColumnAlignment::Right => Horizontal::Right,
+		}
+	}
+}
+
+/// Which cell dividers are drawn in the data area, and how.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum GridLinesStyle {
+	#[default]
+	Both,
+	HorizontalOnly,
+	VerticalOnly,
+	None,
+	/// Separate colours and widths for row and column dividers; a width of
+	/// zero hides that direction.
+	Custom {
+		h_color: Color,
+		v_color: Color,
+		h_width: f32,
+		v_width: f32,
+	},
+}
+
+impl GridLinesStyle {
+	fn horizontal(self, color: Color) -> Option<(Color, f32)> {
+		match self {
+			GridLinesStyle::Both | GridLinesStyle::HorizontalOnly => Some((color, 1.0)),
+			GridLinesStyle::Custom { h_color, h_width, .. } if h_width > 0.0 => Some((h_color, h_width)),
+			_ => None,
+		}
+	}
+
+	fn vertical(self, color: Color) -> Option<(Color, f32)> {
+		match self {
+			GridLinesStyle::Both | GridLinesStyle::VerticalOnly => Some((color, 1.0)),
+			GridLinesStyle::Custom { v_color, v_width, .. } if v_width > 0.0 => Some((v_color, v_width)),
+			_ => None,
+		}
+	}
+}
+
+/// How the scrollbars are laid out and when they are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScrollbarStyle {
+	/// Scrollbars sit beside the content and are always drawn.
+	#[default]
+	Always,
+	/// Scrollbars float over the content and fade out shortly after scrolling stops.
+	Overlay,
+	/// No scrollbars; wheel and keyboard scrolling still work.
+	Never,
+}
+
+/// A line drawn on the hovered or dragged column divider. With `show_ghost`,
+/// a dashed line also marks where the cursor would put the edge, which runs
+/// ahead of it once the column hits its minimum or maximum width.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResizeIndicatorStyle {
+	pub color: Color,
+	pub width: f32,
+	pub show_ghost: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnDef {
+	pub header: String,
+	pub min_width: f32,
+	pub max_width: Option<f32>,
+	pub initial_width: Option<f32>,
+}
+
+/// Display format for a numeric column; values that don't parse as numbers
+/// are shown unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumericFormat {
+	Integer,
+	Float(u8),
+	Currency(String),
+	Percentage(u8),
+}
+
+/// Host-side load state of an image cell, keyed by the cell text.
+#[derive(Debug, Clone)]
+pub enum CellImage {
+	Loading,
+	Loaded(image::Handle),
+	Failed,
+}
+
+/// Aggregate shown for every column in the summary footer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryMode {
+	Sum,
+	Count,
+	Average,
+	Min,
+	Max,
+}
+
+impl SummaryMode {
+	pub const ALL: [SummaryMode; 5] = [
+		SummaryMode::Sum,
+		SummaryMode::Count,
+		SummaryMode::Average,
+		SummaryMode::Min,
+		SummaryMode::Max,
+	];
+
+	fn label(self) -> &'static str {
+		match self {
+			SummaryMode::Sum => "Σ",
+			SummaryMode::Count => "n",
+			SummaryMode::Average => "avg",
+			SummaryMode::Min => "min",
+			SummaryMode::Max => "max",
+		}
+	}
+}
+
+/// Parent header label spanning `span` adjacent columns.
+#[derive(Debug, Clone)]
+pub struct ColumnGroup {
+	pub label: String,
+	pub span: usize,
+}
+
+impl ColumnDef {
+	pub fn new(header: impl Into<String>) -> Self {
+		Self {
+			header: header.into(),
+			min_width: MIN_COL_WIDTH,
+			max_width: None,
+			initial_width: None,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TableTheme {
+	pub background: Color,
+	pub header_background: Color,
+	pub group_header_background: Color,
+	pub header_text: Color,
+	pub type_label: Color,
+	pub text: Color,
+	pub border: Color,
+	pub row_even: Color,
+	pub row_odd: Color,
+	pub row_selected: Color,
+	pub selection: Color,
+	pub search_hit: Color,
+	pub scrollbar_thumb: Color,
+	pub reorder_ghost: Color,
+	pub drop_indicator: Color,
+	pub focused_header: Color,
+	pub focus_border: Color,
+	pub summary_background: Color,
+	pub diff_changed: Color,
+	pub diff_added: Color,
+	pub sparkline: Color,
+	pub sparkline_current: Color,
+	pub filter_background: Color,
+	pub filter_border: Color,
+	pub filter_border_focused: Color,
+	pub filter_text: Color,
+	pub filter_placeholder: Color,
+	pub json_key: Color,
+	pub link: Color,
+}
+
+impl Default for TableTheme {
+	fn default() -> Self {
+		Self::from(&colors::DARK)
+	}
+}
+
+impl From<&Colors> for TableTheme {
+	fn from(colors: &Colors) -> Self {
+		Self {
+			background: colors.bg_primary,
+			header_background: colors.bg_secondary,
+			group_header_background: colors.table_group_header,
+			header_text: colors.table_text_header,
+			type_label: colors.table_type_label,
+			text: colors.text_primary,
+			border: colors.table_border,
+			row_even: colors.table_row_even,
+			row_odd: colors.table_row_odd,
+			row_selected: colors.table_row_selected,
+			selection: colors.table_selection,
+			search_hit: colors.selection,
+			scrollbar_thumb: colors.scrollbar_thumb,
+			reorder_ghost: colors.table_reorder_ghost,
+			drop_indicator: colors.border_accent,
+			focused_header: colors.border_accent,
+			focus_border: colors.border_accent,
+			summary_background: colors.bg_secondary,
+			diff_changed: Color { a: 0.3, ..colors.danger },
+			diff_added: Color { a: 0.3, ..colors.success },
+			sparkline: colors.table_type_label,
+			sparkline_current: colors.text_title,
+			filter_background: colors.bg_input,
+			filter_border: colors.border_primary,
+			filter_border_focused: colors.border_accent,
+			filter_text: colors.text_secondary,
+			filter_placeholder: colors.text_placeholder,
+			json_key: colors.success,
+			link: colors.primary,
+		}
+	}
+}
+
+type SortCallback<'a, Message> = Box<dyn Fn(usize, Option<SortOrder>) -> Message + 'a>;
+type ColumnMenuCallback<'a, Message> = Box<dyn Fn(usize, Point) -> Message + 'a>;
+type CellMenuCallback<'a, Message> = Box<dyn Fn(usize, usize, Point) -> Message + 'a>;
+pub type CellColorFn = Arc<dyn Fn(usize, usize, &str) -> Option<Color> + Send + Sync>;
+pub type RowColorFn = Arc<dyn Fn(usize) -> Option<Color> + Send + Sync>;
+pub type CellTooltipFn = Arc<dyn Fn(usize, usize, &str) -> Option<String> + Send + Sync>;
+
+enum TableData<'a> {
+	Borrowed(&'a DataFrame),
+	Shared(Arc<DataFrame>),
+}
+
+impl std::ops::Deref for TableData<'_> {
+	type Target = DataFrame;
+
+	fn deref(&self) -> &DataFrame {
+		match self {
+			TableData::Borrowed(data_frame) => data_frame,
+			TableData::Shared(data_frame) => data_frame,
+		}
+	}
+}
+
+pub struct Table<'a, Message> {
+	data_frame: TableData<'a>,
 	row_offset: usize,
-	col_width: Option<f32>,
+	data_generation: u64,
+	sorted_by: Option<Option<(usize, SortOrder)>>,
+	total_rows: Option<usize>,
+	scroll_to_row: Option<usize>,
+	focus_col: Option<(usize, u64)>,
+	scroll_offset: Option<(f32, f32)>,
+	columns_def: &'a [ColumnDef],
+	column_groups: &'a [ColumnGroup],
+	hidden_columns: Option<&'a BTreeSet<usize>>,
+	search_hits: &'a [(usize, usize)],
+	initial_layout: Option<&'a TableLayout>,
+	col_widths_override: Option<&'a [f32]>,
 	show_column_types: bool,
+	sort_indicator_position: SortPosition,
+	show_filter_row: bool,
+	column_menu_button: bool,
+	hide_header: bool,
+	col_filters: &'a [String],
+	row_height: Option<RowHeight>,
+	header_height: Option<f32>,
+	font_size: f32,
+	custom_font: Option<iced::Font>,
+	pinned_cols: usize,
+	pinned_bottom_rows: usize,
+	cell_color: Option<CellColorFn>,
+	highlight_row: Option<RowColorFn>,
+	tooltip_cell: Option<CellTooltipFn>,
+	max_cell_length: Option<usize>,
+	header_tooltips: Vec<Option<String>>,
+	compare_baseline: Option<Arc<DataFrame>>,
+	column_alignments: &'a [ColumnAlignment],
+	sparkline_cols: &'a [usize],
+	image_cols: Vec<usize>,
+	image_cache: Option<&'a HashMap<String, CellImage>>,
+	numeric_formats: HashMap<usize, NumericFormat>,
+	row_numbers: bool,
+	max_rendered_rows: usize,
+	zebra_striping: bool,
+	alternating_col_background: bool,
+	json_preview: bool,
+	url_cells: bool,
+	stripe_colors: Option<(Color, Color)>,
+	grid_lines_style: GridLinesStyle,
+	border_color: Option<Color>,
+	scrollbar_style: ScrollbarStyle,
+	resize_indicator: Option<ResizeIndicatorStyle>,
+	selected_cell_border: Option<(Color, f32)>,
+	cell_padding: Padding,
+	summary_mode: Option<SummaryMode>,
+	empty_message: Option<&'a str>,
+	loading: bool,
+	copy_includes_headers: bool,
+	row_click_select_range: bool,
+	focused: Option<bool>,
+	theme: TableTheme,
+	on_row_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+	on_sort: Option<SortCallback<'a, Message>>,
+	on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+	on_row_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+	on_column_menu: Option<ColumnMenuCallback<'a, Message>>,
+	on_cell_menu: Option<CellMenuCallback<'a, Message>>,
+	on_header_right_click: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+	on_load_more: Option<Box<dyn Fn() -> Message + 'a>>,
+	on_fetch_rows: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+	on_find: Option<Box<dyn Fn() -> Message + 'a>>,
+	on_layout_change: Option<Box<dyn Fn(TableLayout) -> Message + 'a>>,
+	on_scroll: Option<Box<dyn Fn(f32, f32) -> Message + 'a>>,
+	on_column_resize_end: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+	on_cell_double_click: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+	on_open_url: Option<Box<dyn Fn(String) -> Message + 'a>>,
+	on_image_request: Option<Box<dyn Fn(Vec<String>) -> Message + 'a>>,
+	on_selection_change: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+	on_filter_change: Option<Box<dyn Fn(usize, String) -> Message + 'a>>,
+	tab_wraps: bool,
+	on_tab_out: Option<Box<dyn Fn() -> Message + 'a>>,
 }
 
-impl<'a> Table<'a> {
-	pub fn new(data_frame: &'a DataFrame, row_offset: usize) -> Self {
-		Self {
-			data_frame,
-			row_offset,
-			col_width: None,
-			show_column_types: false,
+impl<'a, Message> Table<'a, Message> {
+	pub fn new(data_frame: &'a DataFrame, row_offset: usize) -> Self {
+		Self::with_data(TableData::Borrowed(data_frame), row_offset)
+	}
+
+	/// Builds the table from a shared frame so hosts can hand over a cheap
+	/// `Arc` clone instead of keeping a borrow of their state alive.
+	pub fn from_arc(data_frame: Arc<DataFrame>, row_offset: usize) -> Self {
+		Self::with_data(TableData::Shared(data_frame), row_offset)
+	}
+
+	fn with_data(data_frame: TableData<'a>, row_offset: usize) -> Self {
+		Self {
+			data_frame,
+			row_offset,
+			data_generation: 0,
+			sorted_by: None,
+			total_rows: None,
+			scroll_to_row: None,
+			focus_col: None,
+			scroll_offset: None,
+			columns_def: &[],
+			column_groups: &[],
+			hidden_columns: None,
+			search_hits: &[],
+			initial_layout: None,
+			col_widths_override: None,
+			show_column_types: false,
+			sort_indicator_position: SortPosition::Right,
+			show_filter_row: false,
+			column_menu_button: false,
+			hide_header: false,
+			col_filters: &[],
+			row_height: None,
+			header_height: None,
+			font_size: FONT_SIZE,
+			custom_font: None,
+			pinned_cols: 0,
+			pinned_bottom_rows: 0,
+			cell_color: None,
+			highlight_row: None,
+			tooltip_cell: None,
+			max_cell_length: None,
+			header_tooltips: Vec::new(),
+			compare_baseline: None,
+			column_alignments: &[],
+			sparkline_cols: &[],
+			image_cols: Vec::new(),
+			image_cache: None,
+			numeric_formats: HashMap::new(),
+			row_numbers: true,
+			max_rendered_rows: MAX_RENDERED_ROWS,
+			zebra_striping: true,
+			alternating_col_background: false,
+			json_preview: false,
+			url_cells: false,
+			stripe_colors: None,
+			grid_lines_style: GridLinesStyle::Both,
+			border_color: None,
+			scrollbar_style: ScrollbarStyle::Always,
+			resize_indicator: None,
+			selected_cell_border: None,
+			cell_padding: Padding::ZERO.left(CELL_PADDING_X).right(CELL_PADDING_X),
+			summary_mode: None,
+			empty_message: None,
+			loading: false,
+			copy_includes_headers: false,
+			row_click_select_range: false,
+			focused: None,
+			theme: TableTheme::default(),
+			on_row_click: None,
+			on_sort: None,
+			on_reorder: None,
+			on_row_reorder: None,
+			on_column_menu: None,
+			on_cell_menu: None,
+			on_header_right_click: None,
+			on_load_more: None,
+			on_fetch_rows: None,
+			on_find: None,
+			on_layout_change: None,
+			on_scroll: None,
+			on_column_resize_end: None,
+			on_cell_double_click: None,
+			on_open_url: None,
+			on_image_request: None,
+			on_selection_change: None,
+			on_filter_change: None,
+			tab_wraps: true,
+			on_tab_out: None,
+		}
+	}
+
+	pub fn total_rows(mut self, total_rows: usize) -> Self {
+		self.total_rows = Some(total_rows);
+		self
+	}
+
+	/// Bump whenever the rows behind the table change, e.g. a new result, a
+	/// sort or a filter; the row and cell selection is dropped and the summary
+	/// row recomputed when it does.
+	pub fn data_generation(mut self, generation: u64) -> Self {
+		self.data_generation = generation;
+		self
+	}
+
+	/// Shows the sort arrow for the host's sort rather than the last header
+	/// click, e.g. after new rows replace a sorted result.
+	pub fn sorted_by(mut self, sort: Option<(usize, SortOrder)>) -> Self {
+		self.sorted_by = Some(sort);
+		self
+	}
+
+	pub fn scroll_to_row(mut self, row_idx: usize) -> Self {
+		self.scroll_to_row = Some(row_idx);
+		self
+	}
+
+	/// Scrolls a column to the middle of the view unless it is already fully
+	/// visible. Applied once per `request`; bump it to jump to the same column again.
+	pub fn focus_col(mut self, col_idx: usize, request: u64) -> Self {
+		self.focus_col = Some((col_idx, request));
+		self
+	}
+
+	/// Horizontal and vertical scroll offsets, applied whenever they differ
+	/// from the last ones given or reported through `on_scroll`.
+	pub fn scroll_offset(mut self, h: f32, v: f32) -> Self {
+		self.scroll_offset = Some((h, v));
+		self
+	}
+
+	pub fn columns_def(mut self, columns_def: &'a [ColumnDef]) -> Self {
+		self.columns_def = columns_def;
+		self
+	}
+
+	/// Groups consecutive columns, from the first, under shared labels in an
+	/// extra header row. Columns past the last group get an empty group cell.
+	pub fn column_groups(mut self, groups: &'a [ColumnGroup]) -> Self {
+		self.column_groups = groups;
+		self
+	}
+
+	pub fn hidden_columns(mut self, hidden_columns: &'a BTreeSet<usize>) -> Self {
+		self.hidden_columns = Some(hidden_columns);
+		self
+	}
+
+	/// Cells to highlight as `(row, col)` pairs, sorted in row-major order.
+	pub fn search_hits(mut self, search_hits: &'a [(usize, usize)]) -> Self {
+		self.search_hits = search_hits;
+		self
+	}
+
+	/// Column widths and scroll offsets to restore once a frame with a matching
+	/// column count is shown.
+	pub fn initial_layout(mut self, layout: Option<&'a TableLayout>) -> Self {
+		self.initial_layout = layout;
+		self
+	}
+
+	/// Column widths to start from whenever the column count changes, used
+	/// only when the length matches the new column count.
+	pub fn col_widths_override(mut self, widths: &'a [f32]) -> Self {
+		self.col_widths_override = Some(widths);
+		self
+	}
+
+	pub fn show_column_types(mut self, show: bool) -> Self {
+		self.show_column_types = show;
+		self
+	}
+
+	pub fn sort_indicator_position(mut self, position: SortPosition) -> Self {
+		self.sort_indicator_position = position;
+		self
+	}
+
+	pub fn show_filter_row(mut self, show: bool) -> Self {
+		self.show_filter_row = show;
+		self
+	}
+
+	/// Drops the header, group and filter rows so the body starts at the top;
+	/// with no dividers to grab, columns can't be resized or reordered.
+	pub fn hide_header(mut self, hide: bool) -> Self {
+		self.hide_header = hide;
+		self
+	}
+
+	/// Current filter text per column, shown in the filter row.
+	pub fn col_filters(mut self, col_filters: &'a [String]) -> Self {
+		self.col_filters = col_filters;
+		self
+	}
+
+	/// Overrides the row height, which otherwise follows the font size.
+	pub fn row_height(mut self, row_height: RowHeight) -> Self {
+		self.row_height = Some(row_height);
+		self
+	}
+
+	/// Overrides the height of the column name row, which otherwise follows
+	/// the font size. The type label row and filter row keep their own heights.
+	pub fn header_height(mut self, header_height: f32) -> Self {
+		self.header_height = Some(header_height);
+		self
+	}
+
+	/// Text size for headers and cells; row and header heights scale with it.
+	pub fn font_size(mut self, font_size: f32) -> Self {
+		self.font_size = font_size;
+		self
+	}
+
+	/// Base font for headers and cells, e.g. one covering CJK or Arabic script.
+	/// Text is shaped with `Shaping::Advanced` while a custom font is set.
+	pub fn custom_font(mut self, font: iced::Font) -> Self {
+		self.custom_font = Some(font);
+		self
+	}
+
+	/// Keeps the leftmost `count` columns in place during horizontal scrolling.
+	pub fn pinned_cols(mut self, count: usize) -> Self {
+		self.pinned_cols = count;
+		self
+	}
+
+	/// Keeps the last `count` loaded rows, such as totals, fixed at the bottom.
+	pub fn pinned_bottom_rows(mut self, count: usize) -> Self {
+		self.pinned_bottom_rows = count;
+		self
+	}
+
+	/// Background override per cell, called with the absolute row, the column
+	/// and the raw cell text. Kept behind an `Arc` so hosts can reuse it per view.
+	pub fn cell_color(mut self, cell_color: CellColorFn) -> Self {
+		self.cell_color = Some(cell_color);
+		self
+	}
+
+	/// Tint laid over the stripe background of rows, called with the absolute
+	/// row index; use a translucent color to keep the stripes visible.
+	pub fn highlight_row(mut self, highlight_row: RowColorFn) -> Self {
+		self.highlight_row = Some(highlight_row);
+		self
+	}
+
+	/// Tooltip text for a cell hovered for half a second, called with the
+	/// absolute row, the column and the raw cell text.
+	pub fn tooltip_cell(mut self, tooltip_cell: CellTooltipFn) -> Self {
+		self.tooltip_cell = Some(tooltip_cell);
+		self
+	}
+
+	/// Cuts cell text longer than `max_chars` short with an ellipsis; hovering a
+	/// truncated cell shows the full text when `tooltip_cell` has nothing to say.
+	pub fn max_cell_length(mut self, max_chars: usize) -> Self {
+		self.max_cell_length = Some(max_chars);
+		self
+	}
+
+	/// Replaces the column name shown when the header of `col_idx` is hovered.
+	pub fn header_tooltip(mut self, col_idx: usize, text: String) -> Self {
+		if self.header_tooltips.len() <= col_idx {
+			self.header_tooltips.resize(col_idx + 1, None);
+		}
+		self.header_tooltips[col_idx] = Some(text);
+		self
+	}
+
+	/// Tints cells whose text differs from the same cell of `baseline`, and
+	/// cells past the baseline's rows or columns, as they are drawn.
+	pub fn compare_mode(mut self, baseline: Arc<DataFrame>) -> Self {
+		self.compare_baseline = Some(baseline);
+		self
+	}
+
+	/// Cell text alignment by column index; missing entries are left aligned.
+	pub fn column_alignments(mut self, aligns: &'a [ColumnAlignment]) -> Self {
+		self.column_alignments = aligns;
+		self
+	}
+
+	/// Draws these columns as bar sparklines of the whole column instead of
+	/// text, with the bar nearest each row brightened.
+	pub fn sparkline_cols(mut self, cols: &'a [usize]) -> Self {
+		self.sparkline_cols = cols;
+		self
+	}
+
+	/// Treats the column's cells as image paths or URLs and draws a thumbnail
+	/// from `image_cache`. Uncached visible cells are passed to
+	/// `on_image_request`; failed loads fall back to the text.
+	pub fn image_cells(mut self, col_idx: usize) -> Self {
+		self.image_cols.push(col_idx);
+		self
+	}
+
+	pub fn image_cache(mut self, cache: &'a HashMap<String, CellImage>) -> Self {
+		self.image_cache = Some(cache);
+		self
+	}
+
+	pub fn on_image_request(mut self, on_image_request: impl Fn(Vec<String>) -> Message + 'a) -> Self {
+		self.on_image_request = Some(Box::new(on_image_request));
+		self
+	}
+
+	pub fn numeric_format(mut self, col_idx: usize, format: NumericFormat) -> Self {
+		self.numeric_formats.insert(col_idx, format);
+		self
+	}
+
+	/// Shows the 1-based row number gutter; never part of copies or exports.
+	pub fn row_numbers(mut self, show: bool) -> Self {
+		self.row_numbers = show;
+		self
+	}
+
+	/// Upper bound on body rows drawn per frame, as a guard against runaway
+	/// draws from a bad row count. Defaults to 500.
+	pub fn max_rendered_rows(mut self, max_rows: usize) -> Self {
+		self.max_rendered_rows = max_rows;
+		self
+	}
+
+	/// Pins a footer row below the data showing `mode` for each column over the
+	/// loaded rows. Columns where most values aren't numbers show a dash.
+	pub fn show_summary_row(mut self, mode: SummaryMode) -> Self {
+		self.summary_mode = Some(mode);
+		self
+	}
+
+	/// Placeholder drawn in the data area while the table has no rows.
+	pub fn empty_message(mut self, text: &'a str) -> Self {
+		self.empty_message = Some(text);
+		self
+	}
+
+	/// Dims the table under a spinner while new data is being fetched. The
+	/// spinner only advances when the host redraws, e.g. from a timer.
+	pub fn loading(mut self, loading: bool) -> Self {
+		self.loading = loading;
+		self
+	}
+
+	pub fn copy_includes_headers(mut self, include: bool) -> Self {
+		self.copy_includes_headers = include;
+		self
+	}
+
+	/// Routes keyboard events by focus instead of by cursor hover.
+	pub fn focused(mut self, focused: bool) -> Self {
+		self.focused = Some(focused);
+		self
+	}
+
+	pub fn theme(mut self, theme: TableTheme) -> Self {
+		self.theme = theme;
+		self
+	}
+
+	/// With striping off every data row uses the plain background colour.
+	pub fn zebra_striping(mut self, zebra_striping: bool) -> Self {
+		self.zebra_striping = zebra_striping;
+		self
+	}
+
+	/// Lightens columns 0, 2, 4, … with a faint white overlay on top of the
+	/// row background, so it composes with zebra striping.
+	pub fn alternating_col_background(mut self, alternating: bool) -> Self {
+		self.alternating_col_background = alternating;
+		self
+	}
+
+	/// Shows cells holding a JSON object or array as a one-line preview with
+	/// coloured keys; clicking one opens the pretty-printed value.
+	pub fn json_preview(mut self, json_preview: bool) -> Self {
+		self.json_preview = json_preview;
+		self
+	}
+
+	/// Underlines cells whose text starts with `http://` or `https://` in
+	/// the link colour; a Ctrl+click on one fires `on_open_url`.
+	pub fn url_cells(mut self, url_cells: bool) -> Self {
+		self.url_cells = url_cells;
+		self
+	}
+
+	pub fn on_open_url(mut self, on_open_url: impl Fn(String) -> Message + 'a) -> Self {
+		self.on_open_url = Some(Box::new(on_open_url));
+		self
+	}
+
+	/// Overrides the theme's even and odd row colours.
+	pub fn stripe_color(mut self, even: Color, odd: Color) -> Self {
+		self.stripe_colors = Some((even, odd));
+		self
+	}
+
+	/// Header and pinned-area separators are always drawn.
+	pub fn grid_lines_style(mut self, grid_lines_style: GridLinesStyle) -> Self {
+		self.grid_lines_style = grid_lines_style;
+		self
+	}
+
+	/// Space around data and summary cell content. Left-aligned text that
+	/// doesn't wrap runs to the cell edge and ignores the right side.
+	pub fn cell_padding(mut self, padding: impl Into<Padding>) -> Self {
+		self.cell_padding = padding.into();
+		self
+	}
+
+	/// Overrides the theme's border colour for this table.
+	pub fn border_color(mut self, color: Color) -> Self {
+		self.border_color = Some(color);
+		self
+	}
+
+	pub fn column_resize_indicator(mut self, style: ResizeIndicatorStyle) -> Self {
+		self.resize_indicator = Some(style);
+		self
+	}
+
+	/// Outlines the keyboard-focused cell. Tab picks the column on the first
+	/// visible row; with a focused cell the arrow keys move it instead of
+	/// scrolling.
+	pub fn selected_cell_border(mut self, color: Color, width: f32) -> Self {
+		self.selected_cell_border = Some((color, width));
+		self
+	}
+
+	pub fn scrollbar_style(mut self, scrollbar_style: ScrollbarStyle) -> Self {
+		self.scrollbar_style = scrollbar_style;
+		self
+	}
+
+	pub fn on_row_click(mut self, on_row_click: impl Fn(usize) -> Message + 'a) -> Self {
+		self.on_row_click = Some(Box::new(on_row_click));
+		self
+	}
+
+	pub fn on_sort(mut self, on_sort: impl Fn(usize, Option<SortOrder>) -> Message + 'a) -> Self {
+		self.on_sort = Some(Box::new(on_sort));
+		self
+	}
+
+	pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+		self.on_reorder = Some(Box::new(on_reorder));
+		self
+	}
+
+	/// Pressing on a row number and dragging selects every row between the
+	/// press and the cursor, replacing the current row selection. Drags that
+	/// start in the body still select a cell range.
+	pub fn row_click_select_range(mut self, enabled: bool) -> Self {
+		self.row_click_select_range = enabled;
+		self
+	}
+
+	/// Lets rows be dragged by their row number to a new position. Only active
+	/// while every row is loaded; the host reorders its data on the message.
+	/// With `row_click_select_range`, only a selected row can be dragged, and
+	/// a press on any other row number starts a range selection instead.
+	pub fn row_drag(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+		self.on_row_reorder = Some(Box::new(on_reorder));
+		self
+	}
+
+	pub fn on_column_menu(mut self, on_column_menu: impl Fn(usize, Point) -> Message + 'a) -> Self {
+		self.on_column_menu = Some(Box::new(on_column_menu));
+		self
+	}
+
+	/// Shows a "⋮" button at the right end of the hovered header cell that
+	/// opens the column menu on a left click. Needs `on_column_menu`.
+	pub fn column_menu_button(mut self, show: bool) -> Self {
+		self.column_menu_button = show;
+		self
+	}
+
+	/// Fires with the absolute row, the column and the cursor position on a
+	/// right click over a data cell.
+	pub fn on_cell_menu(mut self, on_cell_menu: impl Fn(usize, usize, Point) -> Message + 'a) -> Self {
+		self.on_cell_menu = Some(Box::new(on_cell_menu));
+		self
+	}
+
+	/// Fires with the column under the cursor on a right click in the header,
+	/// except over a resize divider.
+	pub fn on_header_right_click(mut self, on_header_right_click: impl Fn(usize) -> Message + 'a) -> Self {
+		self.on_header_right_click = Some(Box::new(on_header_right_click));
+		self
+	}
+
+	pub fn on_load_more(mut self, on_load_more: impl Fn() -> Message + 'a) -> Self {
+		self.on_load_more = Some(Box::new(on_load_more));
+		self
+	}
+
+	/// Scrolls over `total_rows` rows while the frame only holds the window
+	/// starting at `row_offset`. Rows outside the window draw as "…", and
+	/// `fetch(start, count)` asks for the visible rows plus a margin whenever
+	/// they aren't all in the window.
+	pub fn virtual_rows(mut self, total_rows: usize, fetch: impl Fn(usize, usize) -> Message + 'a) -> Self {
+		self.total_rows = Some(total_rows);
+		self.on_fetch_rows = Some(Box::new(fetch));
+		self
+	}
+
+	pub fn on_find(mut self, on_find: impl Fn() -> Message + 'a) -> Self {
+		self.on_find = Some(Box::new(on_find));
+		self
+	}
+
+	pub fn on_layout_change(mut self, on_layout_change: impl Fn(TableLayout) -> Message + 'a) -> Self {
+		self.on_layout_change = Some(Box::new(on_layout_change));
+		self
+	}
+
+	/// Called with the column and its new width when a divider drag ends.
+	pub fn on_column_resize_end(mut self, on_column_resize_end: impl Fn(usize, f32) -> Message + 'a) -> Self {
+		self.on_column_resize_end = Some(Box::new(on_column_resize_end));
+		self
+	}
+
+	/// Called with the horizontal and vertical offsets whenever the user scrolls.
+	pub fn on_scroll(mut self, on_scroll: impl Fn(f32, f32) -> Message + 'a) -> Self {
+		self.on_scroll = Some(Box::new(on_scroll));
+		self
+	}
+
+	pub fn on_cell_double_click(
+		mut self,
+		on_cell_double_click: impl Fn(usize, usize) -> Message + 'a,
+	) -> Self {
+		self.on_cell_double_click = Some(Box::new(on_cell_double_click));
+		self
+	}
+
+	pub fn on_selection_change(mut self, on_selection_change: impl Fn(usize) -> Message + 'a) -> Self {
+		self.on_selection_change = Some(Box::new(on_selection_change));
+		self
+	}
+
+	pub fn on_filter_change(mut self, on_filter_change: impl Fn(usize, String) -> Message + 'a) -> Self {
+		self.on_filter_change = Some(Box::new(on_filter_change));
+		self
+	}
+
+	/// Whether Tab past the last column (or Shift+Tab before the first) wraps
+	/// around; when it doesn't, focus leaves the table and `on_tab_out` fires.
+	pub fn tab_wraps(mut self, wraps: bool) -> Self {
+		self.tab_wraps = wraps;
+		self
+	}
+
+	pub fn on_tab_out(mut self, on_tab_out: impl Fn() -> Message + 'a) -> Self {
+		self.on_tab_out = Some(Box::new(on_tab_out));
+		self
+	}
+
+	/// Loaded rows whose cell in `col_idx` contains `query`, ignoring case.
+	/// Indexes are into the frame the table was built with.
+	pub fn column_search(&self, col_idx: usize, query: &str) -> Vec<usize> {
+		let needle = query.to_lowercase();
+		column_matches(&self.data_frame, col_idx, |cell| cell.to_lowercase().contains(&needle))
+	}
+
+	/// Like `column_search`, but matching the cell text against `re`.
+	pub fn column_search_regex(&self, col_idx: usize, re: &regex::Regex) -> Vec<usize> {
+		column_matches(&self.data_frame, col_idx, |cell| re.is_match(cell))
+	}
+
+	/// Next visible column for Tab navigation, or `None` when focus leaves the table.
+	fn step_focused_col(&self, current: Option<usize>, backward: bool) -> Option<usize> {
+		let visible: Vec<usize> = (0..self.col_count()).filter(|&col| !self.is_hidden(col)).collect();
+		let last = visible.len().checked_sub(1)?;
+		let pos = current.and_then(|col| visible.iter().position(|&v| v == col));
+		let next = match (pos, backward) {
+			(None, false) => Some(0),
+			(None, true) => Some(last),
+			(Some(pos), false) => Some(pos + 1).filter(|&next| next <= last),
+			(Some(pos), true) => pos.checked_sub(1),
+		};
+		match next {
+			Some(next) => Some(visible[next]),
+			None if self.tab_wraps => Some(if backward { visible[last] } else { visible[0] }),
+			None => None,
+		}
+	}
+
+	fn scroll_col_into_view(&self, state: &mut TableState, col_idx: usize, max_h_scroll: f64) {
+		if col_idx >= self.pinned_col_count() {
+			let left = self.col_left_edges(state)[col_idx] - self.pinned_width(state);
+			state.scroll_h_to(left as f64, max_h_scroll);
 		}
 	}
 
-	pub fn col_width(mut self, width: f32) -> Self {
-		self.col_width = Some(width);
-		self
+	/// Moves the focused cell one step for an arrow key and scrolls it into
+	/// view, staying put at the edges.
+	fn step_focused_cell(&self, state: &mut TableState, key: keyboard::key::Named, body_h: f64, max_v_scroll: f64, max_h_scroll: f64) {
+		let Some((col_idx, row_idx)) = state.focused_cell else {
+			return;
+		};
+		let (col_idx, row_idx) = match key {
+			keyboard::key::Named::ArrowDown => (col_idx, (row_idx + 1).min(self.loaded_row_count().saturating_sub(1))),
+			keyboard::key::Named::ArrowUp => (col_idx, row_idx.saturating_sub(1)),
+			keyboard::key::Named::ArrowRight => (self.step_focused_col(Some(col_idx), false).unwrap_or(col_idx), row_idx),
+			keyboard::key::Named::ArrowLeft => (self.step_focused_col(Some(col_idx), true).unwrap_or(col_idx), row_idx),
+			_ => return,
+		};
+		state.focused_cell = Some((col_idx, row_idx));
+		state.focused_col = Some(col_idx);
+		let top = self.row_top(state, row_idx);
+		let bottom = top + self.row_height_of(state, row_idx) as f64;
+		let from = state.scroll_target_v.unwrap_or(state.v_scroll_offset);
+		if top < from {
+			state.scroll_v_to(top, max_v_scroll);
+		} else if bottom > from + body_h {
+			state.scroll_v_to(bottom - body_h, max_v_scroll);
+		}
+		self.scroll_col_into_view(state, col_idx, max_h_scroll);
 	}
 
-	pub fn show_column_types(mut self, show: bool) -> Self {
-		self.show_column_types = show;
-		self
+	fn scaled(&self, size: f32) -> f32 {
+		size * self.font_size / FONT_SIZE
+	}
+
+	fn text_font(&self, is_bold: bool, size: f32) -> TextFont {
+		let base = self.custom_font.unwrap_or(iced::Font::DEFAULT);
+		TextFont {
+			font: if is_bold {
+				iced::Font {
+					weight: iced::font::Weight::Bold,
+					..base
+				}
+			} else {
+				base
+			},
+			size,
+			shaping: if self.custom_font.is_some() {
+				text::Shaping::Advanced
+			} else {
+				text::Shaping::Basic
+			},
+		}
+	}
+
+	fn wraps_rows(&self) -> bool {
+		self.row_height == Some(RowHeight::Auto)
+	}
+
+	/// Height of the row holding the column names, also used for group labels.
+	fn name_row_height(&self) -> f32 {
+		self.header_height.unwrap_or_else(|| self.scaled(HEADER_HEIGHT))
+	}
+
+	fn label_height(&self) -> f32 {
+		if self.hide_header {
+			0.0
+		} else if self.show_column_types {
+			self.name_row_height() + self.scaled(HEADER_HEIGHT_WITH_TYPES - HEADER_HEIGHT)
+		} else {
+			self.name_row_height()
+		}
+	}
+
+	fn group_row_height(&self) -> f32 {
+		if self.column_groups.is_empty() || self.hide_header {
+			0.0
+		} else {
+			self.name_row_height()
+		}
+	}
+
+	/// Top of the per-column header labels, below the group row if any.
+	fn label_top(&self, bounds: Rectangle) -> f32 {
+		bounds.y + self.group_row_height()
+	}
+
+	fn filter_row_shown(&self) -> bool {
+		self.show_filter_row && !self.hide_header
 	}
 
-	fn header_height(&self) -> f32 {
-		if self.show_column_types {
-			HEADER_HEIGHT_WITH_TYPES
+	fn header_total_height(&self) -> f32 {
+		let label_height = self.group_row_height() + self.label_height();
+		if self.filter_row_shown() {
+			label_height + FILTER_ROW_HEIGHT
 		} else {
-			HEADER_HEIGHT
+			label_height
 		}
 	}
 
+	fn col_filter(&self, col_idx: usize) -> &str {
+		self.col_filters.get(col_idx).map_or("", String::as_str)
+	}
+
 	fn col_count(&self) -> usize {
 		self.data_frame.width()
 	}
 
 	fn total_row_count(&self) -> usize {
-		self.data_frame.height()
+		self.total_rows.unwrap_or(0).max(self.data_frame.height())
 	}
 
 	fn measure_col_width(&self, col_idx: usize) -> f32 {
@@ -82,65 +1153,617 @@ impl<'a> Table<'a> {
 			.unwrap_or("");
 		let sample_rows = self.data_frame.height().min(100);
 		let max_content_chars = (0..sample_rows)
-			.map(|row| self.cell_str(col_idx, row).len())
+			.map(|row| self.cell_shown_str(col_idx, row).chars().count())
 			.max()
 			.unwrap_or(0);
 		let max_chars = max_content_chars.max(col_name.len());
-		let text_width = max_chars as f32 * FONT_SIZE * 0.6;
-		(text_width + CELL_PADDING_X * 2.0).clamp(MIN_COL_WIDTH, MAX_COL_WIDTH)
+		let text_width = max_chars as f32 * self.font_size * 0.6;
+		(text_width + self.cell_padding.x()).clamp(MIN_COL_WIDTH, MAX_COL_WIDTH)
+	}
+
+	fn column_def(&self, col_idx: usize) -> Option<&'a ColumnDef> {
+		let names = self.data_frame.get_column_names();
+		let name = names.get(col_idx)?;
+		self.columns_def.iter().find(|def| def.header == name.as_str())
+	}
+
+	fn col_width_bounds(&self, col_idx: usize) -> (f32, f32) {
+		match self.column_def(col_idx) {
+			Some(def) => (def.min_width, def.max_width.unwrap_or(f32::INFINITY)),
+			None => (MIN_COL_WIDTH, f32::INFINITY),
+		}
 	}
 
 	fn col_widths<'s>(&self, state: &'s mut TableState, viewport_width: f32) -> &'s [f32] {
 		let col_count = self.col_count();
 		if state.col_widths.len() != col_count {
-			state.col_widths = (0..col_count).map(|i| self.measure_col_width(i)).collect();
+			state.sort_col = None;
+			if col_count > 0
+				&& let Some(layout) = state.pending_layout.take()
+				&& layout.col_widths.len() == col_count
+			{
+				state.col_widths = layout.col_widths;
+				state.h_scroll_offset = layout.h_scroll_offset;
+				state.v_scroll_offset = layout.v_scroll_offset;
+				return &state.col_widths;
+			}
+			if let Some(widths) = self.col_widths_override
+				&& widths.len() == col_count
+			{
+				state.col_widths = widths.to_vec();
+				return &state.col_widths;
+			}
+			let initial: Vec<Option<f32>> = (0..col_count)
+				.map(|i| self.column_def(i).and_then(|def| def.initial_width))
+				.collect();
+			state.col_widths = initial
+				.iter()
+				.enumerate()
+				.map(|(i, w)| w.unwrap_or_else(|| self.measure_col_width(i)))
+				.collect();
 			let total: f32 = state.col_widths.iter().sum();
-			if total < viewport_width {
-				let scale = viewport_width / total;
-				for w in &mut state.col_widths {
-					*w *= scale;
+			let fixed: f32 = initial.iter().flatten().sum();
+			if total < viewport_width && total > fixed {
+				let scale = (viewport_width - fixed) / (total - fixed);
+				for (w, init) in state.col_widths.iter_mut().zip(&initial) {
+					if init.is_none() {
+						*w *= scale;
+					}
 				}
 			}
+			for (i, w) in state.col_widths.iter_mut().enumerate() {
+				let (min_w, max_w) = self.col_width_bounds(i);
+				*w = w.clamp(min_w, max_w);
+			}
 		}
 		&state.col_widths
 	}
 
-	fn col_widths_ref<'s>(&self, state: &'s TableState) -> &'s [f32] {
-		&state.col_widths
+	fn is_hidden(&self, col_idx: usize) -> bool {
+		self.hidden_columns.is_some_and(|hidden| hidden.contains(&col_idx))
+	}
+
+	fn visible_col_widths(&self, state: &TableState) -> Vec<f32> {
+		state
+			.col_widths
+			.iter()
+			.enumerate()
+			.map(|(i, &w)| if self.is_hidden(i) { 0.0 } else { w })
+			.collect()
 	}
 
 	fn total_content_width(&self, state: &TableState) -> f32 {
-		state.col_widths.iter().sum()
+		self.visible_col_widths(state).iter().sum()
+	}
+
+	fn pinned_col_count(&self) -> usize {
+		self.pinned_cols.min(self.col_count())
+	}
+
+	fn pinned_width(&self, state: &TableState) -> f32 {
+		self.visible_col_widths(state).iter().take(self.pinned_col_count()).sum()
+	}
+
+	fn col_screen_left(&self, state: &TableState, edges: &[f32], data_left: f32, col_idx: usize) -> f32 {
+		let left = edges
+			.get(col_idx)
+			.copied()
+			.unwrap_or_else(|| self.total_content_width(state));
+		if col_idx < self.pinned_col_count() {
+			data_left + left
+		} else {
+			data_left + left - state.h_scroll_offset as f32
+		}
+	}
+
+	fn col_at_screen_x(&self, state: &TableState, data_left: f32, x: f32) -> Option<usize> {
+		let pinned = self.pinned_col_count();
+		let widths = self.visible_col_widths(state);
+		let (content_x, cols) = if x - data_left < self.pinned_width(state) {
+			(x - data_left, 0..pinned)
+		} else {
+			(x - data_left + state.h_scroll_offset as f32, pinned..widths.len())
+		};
+		let mut x_acc = 0.0f32;
+		for (col_idx, &w) in widths.iter().enumerate() {
+			if cols.contains(&col_idx) && content_x >= x_acc && content_x < x_acc + w {
+				return Some(col_idx);
+			}
+			x_acc += w;
+		}
+		None
+	}
+
+	fn total_content_height(&self, state: &TableState) -> f32 {
+		self.header_total_height() + self.row_top(state, self.total_row_count()) as f32 + self.summary_height()
+	}
+
+	fn summary_height(&self) -> f32 {
+		if self.summary_mode.is_some() { self.scaled(ROW_HEIGHT) } else { 0.0 }
+	}
+
+	fn update_summaries(&self, state: &mut TableState) {
+		let Some(mode) = self.summary_mode else {
+			return;
+		};
+		let key = (self.data_generation, self.data_frame.height(), self.col_count(), mode);
+		if state.summary_key == Some(key) {
+			return;
+		}
+		state.summary_key = Some(key);
+		state.col_summaries = self
+			.data_frame
+			.columns()
+			.iter()
+			.map(|column| summarize(column, key.1, mode))
+			.collect();
+	}
+
+	fn summary_str(&self, col_idx: usize, value: f64) -> String {
+		match self.numeric_formats.get(&col_idx) {
+			Some(format) if self.summary_mode != Some(SummaryMode::Count) => {
+				format_numeric(&value.to_string(), format).unwrap_or_default()
+			}
+			_ if value.fract() == 0.0 => group_digits(&format!("{value:.0}")),
+			_ => group_digits(&format!("{value:.2}")),
+		}
+	}
+
+	fn base_row_height(&self) -> f32 {
+		match self.row_height {
+			Some(RowHeight::Fixed(height)) => height,
+			_ => self.scaled(ROW_HEIGHT),
+		}
+	}
+
+	fn row_height_of(&self, state: &TableState, row_idx: usize) -> f32 {
+		state
+			.row_heights
+			.get(row_idx)
+			.copied()
+			.unwrap_or_else(|| self.base_row_height())
+	}
+
+	fn row_top(&self, state: &TableState, row_idx: usize) -> f64 {
+		let base = self.base_row_height() as f64;
+		match state.row_tops.get(row_idx) {
+			Some(&top) => top,
+			None => match state.row_tops.last() {
+				Some(&last) => last + (row_idx + 1 - state.row_tops.len()) as f64 * base,
+				None => row_idx as f64 * base,
+			},
+		}
+	}
+
+	fn pinned_row_range(&self) -> Range<usize> {
+		let loaded = self.loaded_row_count();
+		loaded - self.pinned_bottom_rows.min(loaded)..loaded
+	}
+
+	/// Height of the band under the scrolled rows: pinned rows plus the summary footer.
+	fn pinned_rows_height(&self, state: &TableState) -> f32 {
+		self.pinned_row_range()
+			.map(|row_idx| self.row_height_of(state, row_idx))
+			.sum::<f32>()
+			+ self.summary_height()
+	}
+
+	fn row_at_screen_y(&self, state: &TableState, bounds: Rectangle, y: f32) -> Option<usize> {
+		let pinned = self.pinned_row_range();
+		let mut row_y = bounds.y + bounds.height - self.h_scrollbar_height() - self.pinned_rows_height(state);
+		if y >= row_y {
+			for row_idx in pinned {
+				let row_h = self.row_height_of(state, row_idx);
+				if y < row_y + row_h {
+					return Some(row_idx);
+				}
+				row_y += row_h;
+			}
+			return None;
+		}
+		let data_top = bounds.y + self.header_total_height();
+		let row_idx = self.row_at(state, (y - data_top) as f64 + state.v_scroll_offset);
+		(row_idx < pinned.start).then_some(row_idx)
+	}
+
+	fn row_at(&self, state: &TableState, content_y: f64) -> usize {
+		let base = self.base_row_height() as f64;
+		match state.row_tops.last() {
+			Some(&last) if content_y < last => {
+				state.row_tops.partition_point(|&top| top <= content_y).saturating_sub(1)
+			}
+			Some(&last) => state.row_tops.len() - 1 + ((content_y - last) / base).floor() as usize,
+			None => (content_y / base).floor() as usize,
+		}
+	}
+
+	/// Re-measures visible rows whose text or column widths changed since they
+	/// were last measured, then rebuilds the row offsets if any height moved.
+	fn update_row_heights<Renderer>(&self, state: &mut TableState, viewport_h: f32)
+	where
+		Renderer: TextRenderer<Font = iced::Font>,
+	{
+		if !self.wraps_rows() {
+			state.row_heights.clear();
+			state.row_hashes.clear();
+			state.row_tops.clear();
+			return;
+		}
+		let loaded = self.loaded_row_count();
+		let mut changed = state.row_heights.len() != loaded;
+		state.row_heights.resize(loaded, self.base_row_height());
+		state.row_hashes.resize(loaded, None);
+		if changed {
+			state.rebuild_row_tops();
+		}
+		let col_widths = self.visible_col_widths(state);
+		let pinned = self.pinned_row_range();
+		for row_idx in pinned.clone() {
+			changed |= self.measure_row::<Renderer>(state, row_idx, &col_widths);
+		}
+		let bottom = state.v_scroll_offset
+			+ (viewport_h - self.header_total_height() - self.pinned_rows_height(state)) as f64;
+		let mut row_idx = self.row_at(state, state.v_scroll_offset);
+		let mut row_y = self.row_top(state, row_idx);
+		while row_idx < pinned.start && row_y < bottom {
+			changed |= self.measure_row::<Renderer>(state, row_idx, &col_widths);
+			row_y += state.row_heights[row_idx] as f64;
+			row_idx += 1;
+		}
+		if changed {
+			state.rebuild_row_tops();
+		}
+	}
+
+	fn measure_row<Renderer>(&self, state: &mut TableState, row_idx: usize, col_widths: &[f32]) -> bool
+	where
+		Renderer: TextRenderer<Font = iced::Font>,
+	{
+		let cells: Vec<String> = col_widths
+			.iter()
+			.enumerate()
+			.map(|(col_idx, &w)| if w > 0.0 { self.cell_shown_str(col_idx, row_idx) } else { String::new() })
+			.collect();
+		let mut hasher = DefaultHasher::new();
+		cells.hash(&mut hasher);
+		col_widths.iter().for_each(|w| w.to_bits().hash(&mut hasher));
+		let hash = hasher.finish();
+		if state.row_hashes[row_idx] == Some(hash) {
+			return false;
+		}
+		state.row_hashes[row_idx] = Some(hash);
+		let line_h = text::LineHeight::default().to_absolute(Pixels(self.font_size)).0;
+		let font = self.text_font(false, self.font_size);
+		let text_h = cells
+			.iter()
+			.zip(col_widths)
+			.filter(|(cell, w)| **w > 0.0 && !cell.is_empty())
+			.map(|(cell, w)| measure_text_height::<Renderer>(cell, w - self.cell_padding.x(), font))
+			.fold(0.0, f32::max);
+		let base = self.base_row_height();
+		let height = (text_h + self.cell_padding.y() + base - line_h).clamp(base, base * 3.0);
+		if height == state.row_heights[row_idx] {
+			return false;
+		}
+		state.row_heights[row_idx] = height;
+		true
+	}
+
+	fn draw_empty_message<Renderer>(&self, renderer: &mut Renderer, area: Rectangle)
+	where
+		Renderer: renderer::Renderer + TextRenderer<Font = iced::Font>,
+	{
+		if let Some(message) = self.empty_message
+			&& self.total_row_count() == 0
+			&& !self.loading
+		{
+			draw_text(renderer, message, area, self.theme.filter_placeholder, self.text_font(false, 16.0), Horizontal::Center);
+		}
+	}
+
+	fn draw_loading<Renderer>(&self, renderer: &mut Renderer, bounds: Rectangle, area: Rectangle)
+	where
+		Renderer: renderer::Renderer,
+	{
+		if !self.loading {
+			return;
+		}
+		renderer.fill_quad(
+			renderer::Quad {
+				bounds,
+				border: border::rounded(4),
+				..renderer::Quad::default()
+			},
+			Color {
+				a: 0.6,
+				..self.theme.background
+			},
+		);
+		let center = area.center();
+		let step = SystemTime::now()
+			.duration_since(SystemTime::UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_millis()
+			/ 100 % 8;
+		for i in 0..8u128 {
+			let angle = i as f32 * std::f32::consts::TAU / 8.0;
+			let age = (step + 8 - i) % 8;
+			renderer.fill_quad(
+				renderer::Quad {
+					bounds: Rectangle {
+						x: center.x + SPINNER_RADIUS * angle.cos() - SPINNER_DOT / 2.0,
+						y: center.y + SPINNER_RADIUS * angle.sin() - SPINNER_DOT / 2.0,
+						width: SPINNER_DOT,
+						height: SPINNER_DOT,
+					},
+					border: border::rounded(SPINNER_DOT / 2.0),
+					..renderer::Quad::default()
+				},
+				Color {
+					a: 1.0 - age as f32 / 8.0,
+					..self.theme.header_text
+				},
+			);
+		}
+	}
+
+	/// Fits the thumbnail into `area` at its aspect ratio, left-aligned. A
+	/// square placeholder stands in until the host has loaded it.
+	fn draw_cell_image<Renderer>(&self, renderer: &mut Renderer, cell_image: Option<&CellImage>, area: Rectangle)
+	where
+		Renderer: ImageRenderer<Handle = image::Handle>,
+	{
+		let side = area.width.min(area.height);
+		let Some(CellImage::Loaded(handle)) = cell_image else {
+			renderer.fill_quad(
+				renderer::Quad {
+					bounds: Rectangle {
+						width: side,
+						height: side,
+						..area
+					},
+					border: border::Border {
+						color: self.theme.border,
+						width: 1.0,
+						radius: 2.0.into(),
+					},
+					..renderer::Quad::default()
+				},
+				self.theme.header_background,
+			);
+			return;
+		};
+		let size = renderer
+			.measure_image(handle)
+			.map_or(Size::new(side, side), |size| Size::new(size.width as f32, size.height as f32));
+		let scale = (area.width / size.width).min(area.height / size.height);
+		let bounds = Rectangle {
+			width: size.width * scale,
+			height: size.height * scale,
+			..area
+		};
+		renderer.draw_image(image::Image::new(handle.clone()), bounds, area);
 	}
 
-	fn total_content_height(&self) -> f32 {
-		self.header_height() + self.total_row_count() as f32 * ROW_HEIGHT
+	/// Samples one value per bar rather than parsing the whole column, so the
+	/// cost per cell is bounded by its width.
+	fn draw_sparkline<Renderer>(&self, renderer: &mut Renderer, col_idx: usize, row_idx: usize, area: Rectangle)
+	where
+		Renderer: renderer::Renderer,
+	{
+		let rows = self.data_frame.height();
+		let bars = ((area.width / SPARKLINE_BAR_WIDTH).floor() as usize).min(rows);
+		let Some(column) = self.data_frame.columns().get(col_idx) else {
+			return;
+		};
+		if bars == 0 || area.height <= 0.0 {
+			return;
+		}
+		let values: Vec<Option<f64>> = (0..bars)
+			.map(|bar| {
+				cell_to_string(column, bar * rows / bars)
+					.trim()
+					.parse::<f64>()
+					.ok()
+					.filter(|v| v.is_finite())
+			})
+			.collect();
+		let (min, max) = values
+			.iter()
+			.flatten()
+			.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+		if min > max {
+			return;
+		}
+		let range = (max - min).max(f64::EPSILON);
+		let current = self.frame_row(row_idx).map(|row| row * bars / rows);
+		for (bar, value) in values.into_iter().enumerate() {
+			let Some(value) = value else {
+				continue;
+			};
+			let height = 1.0 + ((value - min) / range) as f32 * (area.height - 1.0);
+			renderer.fill_quad(
+				renderer::Quad {
+					bounds: Rectangle {
+						x: area.x + bar as f32 * SPARKLINE_BAR_WIDTH,
+						y: area.y + area.height - height,
+						width: SPARKLINE_BAR_WIDTH - 1.0,
+						height,
+					},
+					..renderer::Quad::default()
+				},
+				if Some(bar) == current {
+					self.theme.sparkline_current
+				} else {
+					self.theme.sparkline
+				},
+			);
+		}
 	}
 
+	/// Rows the body can address: every row when virtual, the frame otherwise.
 	fn loaded_row_count(&self) -> usize {
-		self.data_frame.height()
+		if self.on_fetch_rows.is_some() {
+			self.total_row_count()
+		} else {
+			self.data_frame.height()
+		}
+	}
+
+	/// Absolute index of body row 0; virtual bodies are indexed absolutely.
+	fn abs_offset(&self) -> usize {
+		if self.on_fetch_rows.is_some() { 0 } else { self.row_offset }
+	}
+
+	/// Maps a body row to its row in the frame, `None` outside a virtual window.
+	fn frame_row(&self, row_idx: usize) -> Option<usize> {
+		match self.on_fetch_rows {
+			Some(_) => row_idx
+				.checked_sub(self.row_offset)
+				.filter(|&row| row < self.data_frame.height()),
+			None => Some(row_idx),
+		}
 	}
 
 	fn cell_str(&self, col_idx: usize, row_idx: usize) -> String {
-		let series: &Column = match self.data_frame.columns().get(col_idx) {
-			Some(s) => s,
-			None => return String::new(),
-		};
-		if row_idx >= series.len() {
-			return String::new();
+		match (self.data_frame.columns().get(col_idx), self.frame_row(row_idx)) {
+			(Some(series), Some(row)) => cell_to_string(series, row),
+			_ => String::new(),
+		}
+	}
+
+	fn divider_color(&self) -> Color {
+		self.border_color.unwrap_or(self.theme.border)
+	}
+
+	fn row_divider(&self) -> Option<(Color, f32)> {
+		self.grid_lines_style.horizontal(self.divider_color())
+	}
+
+	fn col_divider(&self) -> Option<(Color, f32)> {
+		self.grid_lines_style.vertical(self.divider_color())
+	}
+
+	/// Width reserved on the right for the vertical scrollbar.
+	fn v_scrollbar_width(&self) -> f32 {
+		if self.scrollbar_style == ScrollbarStyle::Always { V_SCROLLBAR_WIDTH } else { 0.0 }
+	}
+
+	/// Height reserved at the bottom for the horizontal scrollbar.
+	fn h_scrollbar_height(&self) -> f32 {
+		if self.scrollbar_style == ScrollbarStyle::Always { H_SCROLLBAR_HEIGHT } else { 0.0 }
+	}
+
+	/// Thumb colour for the current style, or `None` when nothing should be drawn or grabbed.
+	fn scrollbar_color(&self, state: &TableState) -> Option<Color> {
+		match self.scrollbar_style {
+			ScrollbarStyle::Always => Some(self.theme.scrollbar_thumb),
+			ScrollbarStyle::Never => None,
+			ScrollbarStyle::Overlay if state.opacity_timer > 0.0 => {
+				let fade = (state.opacity_timer / OVERLAY_SCROLLBAR_FADE_TAIL).min(1.0);
+				Some(self.theme.scrollbar_thumb.scale_alpha(OVERLAY_SCROLLBAR_OPACITY * fade))
+			}
+			ScrollbarStyle::Overlay => None,
+		}
+	}
+
+	fn row_background(&self, abs_idx: usize) -> Color {
+		let (even, odd) = self.stripe_colors.unwrap_or((self.theme.row_even, self.theme.row_odd));
+		if !self.zebra_striping {
+			self.theme.background
+		} else if abs_idx.is_multiple_of(2) {
+			even
+		} else {
+			odd
+		}
+	}
+
+	fn compare_tint(&self, col_idx: usize, row_idx: usize) -> Option<Color> {
+		let baseline = self.compare_baseline.as_deref()?;
+		let abs_idx = self.abs_offset() + row_idx;
+		match baseline.columns().get(col_idx) {
+			Some(column) if abs_idx < baseline.height() => (cell_to_string(column, abs_idx)
+				!= self.cell_str(col_idx, row_idx))
+			.then_some(self.theme.diff_changed),
+			_ => Some(self.theme.diff_added),
+		}
+	}
+
+	fn cell_display_str(&self, col_idx: usize, row_idx: usize) -> String {
+		if let Some(format) = self.numeric_formats.get(&col_idx) {
+			let raw = self.cell_str(col_idx, row_idx);
+			return format_numeric(&raw, format).unwrap_or(raw);
+		}
+		match (self.data_frame.columns().get(col_idx), self.frame_row(row_idx)) {
+			(Some(series), Some(row)) => cell_display_string(series, row),
+			_ => String::new(),
+		}
+	}
+
+	fn cell_shown_str(&self, col_idx: usize, row_idx: usize) -> String {
+		let text = self.cell_display_str(col_idx, row_idx);
+		match self.max_cell_length {
+			Some(max_chars) => truncate_cell(&text, max_chars).unwrap_or(text),
+			None => text,
+		}
+	}
+
+	fn image_source(&self, col_idx: usize, row_idx: usize) -> Option<String> {
+		if !self.image_cols.contains(&col_idx) || self.frame_row(row_idx).is_none() {
+			return None;
+		}
+		let source = self.cell_str(col_idx, row_idx).trim().to_string();
+		(!source.is_empty()).then_some(source)
+	}
+
+	fn url_cell(&self, col_idx: usize, row_idx: usize) -> Option<String> {
+		if !self.url_cells || self.frame_row(row_idx).is_none() {
+			return None;
+		}
+		let raw = self.cell_str(col_idx, row_idx);
+		let url = raw.trim();
+		(url.starts_with("http://") || url.starts_with("https://")).then(|| url.to_string())
+	}
+
+	/// Parses the raw cell text when JSON preview is on and it looks like an
+	/// object or array. Only called for cells being drawn or expanded, and
+	/// longer texts stay plain so scrolling doesn't re-parse large documents.
+	fn json_cell(&self, col_idx: usize, row_idx: usize) -> Option<serde_json::Value> {
+		if !self.json_preview || self.frame_row(row_idx).is_none() {
+			return None;
+		}
+		let raw = self.cell_str(col_idx, row_idx);
+		let trimmed = raw.trim_start();
+		if trimmed.len() > JSON_PREVIEW_MAX_LEN || (!trimmed.starts_with('{') && !trimmed.starts_with('[')) {
+			return None;
+		}
+		serde_json::from_str(trimmed).ok()
+	}
+
+	fn to_tsv(&self, rows: &[usize], cols: &[usize]) -> String {
+		let mut out = String::new();
+		if self.copy_includes_headers {
+			let names = self.data_frame.get_column_names();
+			let header: Vec<&str> = cols
+				.iter()
+				.filter_map(|&col| names.get(col).map(|name| name.as_str()))
+				.collect();
+			out.push_str(&header.join("\t"));
+			out.push('\n');
 		}
-		match series.get(row_idx) {
-			Ok(AnyValue::Null) | Err(_) => String::new(),
-			Ok(AnyValue::String(s)) => s.to_string(),
-			Ok(AnyValue::StringOwned(s)) => s.to_string(),
-			Ok(v) => format!("{v}"),
+		for &row in rows {
+			let line: Vec<String> = cols.iter().map(|&col| self.cell_str(col, row)).collect();
+			out.push_str(&line.join("\t"));
+			out.push('\n');
 		}
+		out
 	}
 
 	fn col_left_edges(&self, state: &TableState) -> Vec<f32> {
-		let mut edges = Vec::with_capacity(state.col_widths.len());
+		let widths = self.visible_col_widths(state);
+		let mut edges = Vec::with_capacity(widths.len());
 		let mut x = 0.0f32;
-		for &w in &state.col_widths {
+		for &w in &widths {
 			edges.push(x);
 			x += w;
 		}
@@ -148,15 +1771,19 @@ impl<'a> Table<'a> {
 	}
 
 	fn row_num_width(&self, bounds: Rectangle, v_scroll: f64) -> f32 {
-		let first_visible = (v_scroll / ROW_HEIGHT as f64).floor() as usize;
-		let visible_count = ((bounds.height - self.header_height()) / ROW_HEIGHT).ceil() as usize + 1;
-		let max_idx = self.row_offset + first_visible + visible_count + 1;
+		if !self.row_numbers {
+			return 0.0;
+		}
+		let row_h = self.base_row_height();
+		let first_visible = (v_scroll / row_h as f64).floor() as usize;
+		let visible_count = ((bounds.height - self.header_total_height()) / row_h).ceil() as usize + 1;
+		let max_idx = self.abs_offset() + first_visible + visible_count + 1;
 		let digits = if max_idx > 0 {
 			(max_idx as f64).log10().floor() as f32 + 1.0
 		} else {
 			1.0
 		};
-		(digits * FONT_SIZE * 0.6 + CELL_PADDING_X * 2.0).max(MIN_COL_WIDTH)
+		(digits * self.font_size * 0.6 + CELL_PADDING_X * 2.0).max(MIN_COL_WIDTH)
 	}
 
 	fn divider_at_cursor(
@@ -167,17 +1794,27 @@ impl<'a> Table<'a> {
 		cursor_y: f32,
 		row_num_w: f32,
 	) -> Option<usize> {
-		if cursor_y < bounds.y || cursor_y > bounds.y + self.header_height() {
+		if self.hide_header || cursor_y < bounds.y || cursor_y > bounds.y + self.header_total_height() {
 			return None;
 		}
 		if cursor_x < bounds.x + row_num_w {
 			return None;
 		}
-		let content_x = cursor_x - (bounds.x + row_num_w) + state.h_scroll_offset as f32;
+		let data_left = bounds.x + row_num_w;
+		let scroll_left = data_left + self.pinned_width(state);
 		let edges = self.col_left_edges(state);
-		for (i, &left) in edges.iter().enumerate() {
-			let divider_x = left + state.col_widths[i];
-			if (content_x - divider_x).abs() <= COL_RESIZE_GRAB_ZONE {
+		for i in 0..edges.len() {
+			if self.is_hidden(i) {
+				continue;
+			}
+			let divider_x = self.col_screen_left(state, &edges, data_left, i) + state.col_widths[i];
+			if i >= self.pinned_col_count() && divider_x < scroll_left {
+				continue;
+			}
+			if (cursor_x - divider_x).abs() <= COL_RESIZE_GRAB_ZONE
+				&& !(cursor_x < divider_x
+					&& self.column_menu_button_at(state, bounds, Point::new(cursor_x, cursor_y), row_num_w) == Some(i))
+			{
 				return Some(i);
 			}
 		}
@@ -191,12 +1828,14 @@ impl<'a> Table<'a> {
 		state: &TableState,
 		row_num_w: f32,
 	) -> Rectangle {
-		let total_w = self.total_content_width(state);
-		let track_w = bounds.width - V_SCROLLBAR_WIDTH - row_num_w;
+		let pinned_w = self.pinned_width(state);
+		let total_w = self.total_content_width(state) - pinned_w;
+		let track_w = bounds.width - self.v_scrollbar_width() - row_num_w - pinned_w;
 		let thumb_w = (track_w * (track_w / total_w.max(1.0))).max(20.0);
 		let max_scroll = (total_w - track_w).max(0.0);
 		let thumb_x = bounds.x
 			+ row_num_w
+			+ pinned_w
 			+ if max_scroll > 0.0 {
 				h_scroll_offset as f32 / max_scroll * (track_w - thumb_w)
 			} else {
@@ -210,44 +1849,132 @@ impl<'a> Table<'a> {
 		}
 	}
 
-	fn hit_test_cell(
+	fn hit_test_cell(
+		&self,
+		state: &TableState,
+		bounds: Rectangle,
+		pos: Point,
+		row_num_w: f32,
+	) -> Option<(usize, usize)> {
+		let header_h = self.header_total_height();
+		let data_top = bounds.y + header_h;
+		let data_bottom = bounds.y + bounds.height - self.h_scrollbar_height();
+		let data_left = bounds.x + row_num_w;
+		let data_right = bounds.x + bounds.width - self.v_scrollbar_width();
+		if pos.y < data_top || pos.y >= data_bottom || pos.x < data_left || pos.x >= data_right {
+			return None;
+		}
+		let row_idx = self.row_at_screen_y(state, bounds, pos.y)?;
+		self.col_at_screen_x(state, data_left, pos.x).map(|col_idx| (col_idx, row_idx))
+	}
+
+	fn header_col_at(
+		&self,
+		state: &TableState,
+		bounds: Rectangle,
+		pos: Point,
+		row_num_w: f32,
+	) -> Option<usize> {
+		let data_left = bounds.x + row_num_w;
+		let data_right = bounds.x + bounds.width - self.v_scrollbar_width();
+		if pos.y < self.label_top(bounds)
+			|| pos.y >= self.label_top(bounds) + self.label_height()
+			|| pos.x < data_left
+			|| pos.x >= data_right
+		{
+			return None;
+		}
+		self.col_at_screen_x(state, data_left, pos.x)
+	}
+
+	fn column_menu_button_at(
 		&self,
 		state: &TableState,
 		bounds: Rectangle,
 		pos: Point,
 		row_num_w: f32,
-	) -> Option<(usize, usize)> {
-		let header_h = self.header_height();
-		let data_top = bounds.y + header_h;
-		let data_bottom = bounds.y + bounds.height - H_SCROLLBAR_HEIGHT;
-		let data_left = bounds.x + row_num_w;
-		let data_right = bounds.x + bounds.width - V_SCROLLBAR_WIDTH;
-		if pos.y < data_top || pos.y >= data_bottom || pos.x < data_left || pos.x >= data_right {
+	) -> Option<usize> {
+		if !self.column_menu_button || self.on_column_menu.is_none() || self.hide_header {
 			return None;
 		}
-		let content_y = pos.y - data_top + state.v_scroll_offset as f32;
-		let row_idx = (content_y / ROW_HEIGHT).floor() as usize;
-		if row_idx >= self.loaded_row_count() {
+		let col_idx = self.header_col_at(state, bounds, pos, row_num_w)?;
+		if pos.y >= self.label_top(bounds) + self.name_row_height() {
 			return None;
 		}
-		let content_x = pos.x - data_left + state.h_scroll_offset as f32;
-		let mut x_acc = 0.0f32;
-		for (col_idx, &w) in state.col_widths.iter().enumerate() {
-			if content_x >= x_acc && content_x < x_acc + w {
-				return Some((col_idx, row_idx));
+		let edges = self.col_left_edges(state);
+		let right = self.col_screen_left(state, &edges, bounds.x + row_num_w, col_idx) + state.col_widths[col_idx];
+		(pos.x >= right - COLUMN_MENU_BUTTON_WIDTH).then_some(col_idx)
+	}
+
+	fn filter_col_at(
+		&self,
+		state: &TableState,
+		bounds: Rectangle,
+		pos: Point,
+		row_num_w: f32,
+	) -> Option<usize> {
+		let data_left = bounds.x + row_num_w;
+		let data_right = bounds.x + bounds.width - self.v_scrollbar_width();
+		if !self.filter_row_shown()
+			|| pos.y < self.label_top(bounds) + self.label_height()
+			|| pos.y >= bounds.y + self.header_total_height()
+			|| pos.x < data_left
+			|| pos.x >= data_right
+		{
+			return None;
+		}
+		self.col_at_screen_x(state, data_left, pos.x)
+	}
+
+	fn reorder_slot_at(&self, state: &TableState, bounds: Rectangle, x: f32, row_num_w: f32) -> usize {
+		let data_left = bounds.x + row_num_w;
+		let scroll_left = data_left + self.pinned_width(state);
+		let edges = self.col_left_edges(state);
+		for (col_idx, &w) in self.visible_col_widths(state).iter().enumerate() {
+			let left = self.col_screen_left(state, &edges, data_left, col_idx);
+			if col_idx >= self.pinned_col_count() && left + w <= scroll_left {
+				continue;
+			}
+			if w > 0.0 && x < left + w / 2.0 {
+				return col_idx;
 			}
-			x_acc += w;
 		}
-		None
+		state.col_widths.len()
+	}
+
+	fn row_drag_enabled(&self) -> bool {
+		self.on_row_reorder.is_some()
+			&& self.row_numbers
+			&& self.total_row_count() == self.loaded_row_count()
+	}
+
+	fn row_reorder_slot_at(&self, state: &TableState, bounds: Rectangle, y: f32) -> usize {
+		let content_y = (y - bounds.y - self.header_total_height()).max(0.0) as f64 + state.v_scroll_offset;
+		let row_idx = self.row_at(state, content_y);
+		let mid = self.row_top(state, row_idx) + self.row_height_of(state, row_idx) as f64 / 2.0;
+		let slot = if content_y >= mid { row_idx + 1 } else { row_idx };
+		slot.min(self.pinned_row_range().start)
+	}
+
+	fn hit_test_row(&self, state: &TableState, bounds: Rectangle, pos: Point) -> Option<usize> {
+		let data_top = bounds.y + self.header_total_height();
+		let data_bottom = bounds.y + bounds.height - self.h_scrollbar_height();
+		let data_right = bounds.x + bounds.width - self.v_scrollbar_width();
+		if pos.y < data_top || pos.y >= data_bottom || pos.x < bounds.x || pos.x >= data_right {
+			return None;
+		}
+		self.row_at_screen_y(state, bounds, pos.y)
 	}
 
-	fn v_scrollbar_thumb_rect(&self, bounds: Rectangle, v_scroll_offset: f64) -> Rectangle {
-		let total_h = self.total_content_height();
-		let track_h = bounds.height - self.header_height() - H_SCROLLBAR_HEIGHT;
-		let thumb_h = (track_h * (track_h / (total_h - self.header_height()).max(1.0))).max(20.0);
-		let max_scroll = (total_h - bounds.height + H_SCROLLBAR_HEIGHT).max(0.0);
+	fn v_scrollbar_thumb_rect(&self, bounds: Rectangle, v_scroll_offset: f64, state: &TableState) -> Rectangle {
+		let total_h = self.total_content_height(state);
+		let pinned_h = self.pinned_rows_height(state);
+		let track_h = bounds.height - self.header_total_height() - self.h_scrollbar_height();
+		let scroll_h = total_h - self.header_total_height() - pinned_h;
+		let thumb_h = (track_h * ((track_h - pinned_h) / scroll_h.max(1.0))).clamp(20.0, track_h);
+		let max_scroll = (total_h - bounds.height + self.h_scrollbar_height()).max(0.0);
 		let thumb_y = bounds.y
-			+ self.header_height()
+			+ self.header_total_height()
 			+ if max_scroll > 0.0 {
 				v_scroll_offset as f32 / max_scroll * (track_h - thumb_h)
 			} else {
@@ -288,6 +2015,8 @@ pub struct TableState {
 	resizing_col: Option<usize>,
 	resize_drag_start_x: f32,
 	resize_drag_start_width: f32,
+	resize_history: Vec<Vec<f32>>,
+	redo_stack: Vec<Vec<f32>>,
 	h_drag_start_offset: f64,
 	h_drag_start_x: f32,
 	h_dragging_scrollbar: bool,
@@ -296,21 +2025,277 @@ pub struct TableState {
 	v_drag_start_y: f32,
 	v_dragging_scrollbar: bool,
 	v_scroll_offset: f64,
+	scroll_target_v: Option<f64>,
+	scroll_target_h: Option<f64>,
+	elastic_v: f32,
+	elastic_since: Option<Instant>,
+	opacity_timer: f32,
 	selection: Option<TableSelection>,
 	is_selecting: bool,
+	selected_rows: BTreeSet<usize>,
+	last_clicked_row: Option<usize>,
+	drag_select_start: Option<usize>,
+	header_press: Option<usize>,
+	header_press_x: f32,
+	header_press_reorderable: bool,
+	reorder_col: Option<usize>,
+	reorder_drag_x: f32,
+	row_press: Option<usize>,
+	row_press_y: f32,
+	reorder_row: Option<usize>,
+	reorder_drag_y: f32,
+	sort_col: Option<(usize, SortOrder)>,
+	focused_col: Option<usize>,
+	focused_cell: Option<(usize, usize)>,
+	scrolled_to_row: Option<usize>,
+	focused_to_col: Option<(usize, u64)>,
+	applied_scroll_offset: Option<(f32, f32)>,
+	data_generation: u64,
+	load_more_requested: bool,
+	fetch_requested: Option<(usize, usize)>,
+	last_loaded_rows: usize,
+	pending_layout: Option<TableLayout>,
+	last_cell_click: Option<(Instant, (usize, usize))>,
+	focused_filter: Option<usize>,
+	filter_caret: usize,
+	hovered_header_col: Option<usize>,
+	hovered_divider: Option<usize>,
+	json_expanded: Option<((usize, usize), Point)>,
+	hover_target: Option<(HoverTarget, Instant)>,
+	hover_pos: Point,
+	tooltip_shown: bool,
+	row_heights: Vec<f32>,
+	row_hashes: Vec<Option<u64>>,
+	row_tops: Vec<f64>,
+	col_summaries: Vec<Option<f64>>,
+	summary_key: Option<(u64, usize, usize, SummaryMode)>,
+	layout_dirty: bool,
+	is_focused: bool,
 	modifiers: keyboard::Modifiers,
 }
 
-impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Table<'_>
+impl Focusable for TableState {
+	fn is_focused(&self) -> bool {
+		self.is_focused
+	}
+
+	fn focus(&mut self) {
+		self.is_focused = true;
+	}
+
+	fn unfocus(&mut self) {
+		self.is_focused = false;
+	}
+}
+
+impl TableState {
+	fn scroll_v_to(&mut self, target: f64, max: f64) {
+		self.scroll_target_v = Some(target.clamp(0.0, max));
+	}
+
+	fn scroll_v_by(&mut self, delta: f64, max: f64) {
+		let from = self.scroll_target_v.unwrap_or(self.v_scroll_offset);
+		self.scroll_v_to(from + delta, max);
+	}
+
+	fn scroll_h_to(&mut self, target: f64, max: f64) {
+		self.scroll_target_h = Some(target.clamp(0.0, max));
+	}
+
+	fn scroll_h_by(&mut self, delta: f64, max: f64) {
+		let from = self.scroll_target_h.unwrap_or(self.h_scroll_offset);
+		self.scroll_h_to(from + delta, max);
+	}
+
+	fn cancel_scroll_animation(&mut self) {
+		self.scroll_target_v = None;
+		self.scroll_target_h = None;
+	}
+
+	/// Scrolls vertically by a wheel delta, stretching past either end with
+	/// resistance instead of stopping dead.
+	fn scroll_v_elastic(&mut self, delta: f64, max: f64, viewport_h: f32) {
+		let limit = viewport_h * ELASTIC_MAX_FRACTION;
+		let raw = self.v_scroll_offset + self.elastic_v as f64 / ELASTIC_RESISTANCE + delta;
+		self.v_scroll_offset = raw.clamp(0.0, max);
+		self.elastic_v = (((raw - self.v_scroll_offset) * ELASTIC_RESISTANCE) as f32).clamp(-limit, limit);
+		self.elastic_since = (self.elastic_v != 0.0).then(Instant::now);
+	}
+
+	/// Eases the over-scroll back to zero once the wheel has gone quiet;
+	/// returns whether another frame is needed.
+	fn step_elastic(&mut self, now: Instant) -> bool {
+		if self.elastic_v == 0.0 {
+			return false;
+		}
+		if self.elastic_since.is_some_and(|since| now < since + ELASTIC_SETTLE_DELAY) {
+			return true;
+		}
+		self.elastic_since = None;
+		self.elastic_v -= self.elastic_v * SCROLL_EASING as f32;
+		if self.elastic_v.abs() <= 0.5 {
+			self.elastic_v = 0.0;
+		}
+		true
+	}
+
+	/// Eases both offsets toward their targets; returns whether another frame is needed.
+	fn step_scroll_animation(&mut self) -> bool {
+		fn step(offset: &mut f64, target: &mut Option<f64>) -> bool {
+			let Some(goal) = *target else {
+				return false;
+			};
+			let distance = goal - *offset;
+			if distance.abs() <= 0.5 {
+				*offset = goal;
+				*target = None;
+				return false;
+			}
+			*offset += distance * SCROLL_EASING;
+			true
+		}
+		let v_moving = step(&mut self.v_scroll_offset, &mut self.scroll_target_v);
+		let h_moving = step(&mut self.h_scroll_offset, &mut self.scroll_target_h);
+		v_moving || h_moving
+	}
+
+	fn finish_resize(&mut self, col_idx: usize) {
+		if self.col_widths[col_idx] == self.resize_drag_start_width {
+			return;
+		}
+		let mut previous = self.col_widths.clone();
+		previous[col_idx] = self.resize_drag_start_width;
+		if self.resize_history.len() == RESIZE_HISTORY_LIMIT {
+			self.resize_history.remove(0);
+		}
+		self.resize_history.push(previous);
+		self.redo_stack.clear();
+	}
+
+	fn undo_resize(&mut self) -> bool {
+		match self.resize_history.pop() {
+			Some(widths) if widths.len() == self.col_widths.len() => {
+				self.redo_stack.push(std::mem::replace(&mut self.col_widths, widths));
+				self.layout_dirty = true;
+				true
+			}
+			_ => false,
+		}
+	}
+
+	fn redo_resize(&mut self) -> bool {
+		match self.redo_stack.pop() {
+			Some(widths) if widths.len() == self.col_widths.len() => {
+				self.resize_history.push(std::mem::replace(&mut self.col_widths, widths));
+				self.layout_dirty = true;
+				true
+			}
+			_ => false,
+		}
+	}
+
+	/// Tracks the hovered cell or header for tooltips; returns whether a
+	/// visible tooltip was hidden.
+	fn hover(&mut self, target: Option<HoverTarget>) -> bool {
+		if target == self.hover_target.map(|(target, _)| target) {
+			return false;
+		}
+		self.hover_target = target.map(|target| (target, Instant::now()));
+		std::mem::take(&mut self.tooltip_shown)
+	}
+
+	fn rebuild_row_tops(&mut self) {
+		let mut top = 0.0;
+		self.row_tops = std::iter::once(0.0)
+			.chain(self.row_heights.iter().map(|&h| {
+				top += h as f64;
+				top
+			}))
+			.collect();
+	}
+
+	fn layout(&self) -> TableLayout {
+		TableLayout {
+			col_widths: self.col_widths.clone(),
+			h_scroll_offset: self.h_scroll_offset,
+			v_scroll_offset: self.v_scroll_offset,
+		}
+	}
+
+	fn click_row(&mut self, abs_idx: usize) {
+		if self.modifiers.shift()
+			&& let Some(anchor) = self.last_clicked_row
+		{
+			if !self.modifiers.control() {
+				self.selected_rows.clear();
+			}
+			self.selected_rows.extend(anchor.min(abs_idx)..=anchor.max(abs_idx));
+		} else if self.modifiers.control() {
+			if !self.selected_rows.remove(&abs_idx) {
+				self.selected_rows.insert(abs_idx);
+			}
+			self.last_clicked_row = Some(abs_idx);
+		} else {
+			self.selected_rows.clear();
+			self.selected_rows.insert(abs_idx);
+			self.last_clicked_row = Some(abs_idx);
+		}
+	}
+
+	fn cycle_sort(&mut self, col_idx: usize) -> Option<SortOrder> {
+		self.sort_col = match self.sort_col {
+			Some((col, SortOrder::Ascending)) if col == col_idx => {
+				Some((col_idx, SortOrder::Descending))
+			}
+			Some((col, SortOrder::Descending)) if col == col_idx => None,
+			_ => Some((col_idx, SortOrder::Ascending)),
+		};
+		self.sort_col.map(|(_, order)| order)
+	}
+
+	fn move_col(&mut self, from: usize, to: usize) {
+		let width = self.col_widths.remove(from);
+		self.col_widths.insert(to, width);
+		self.resize_history.clear();
+		self.redo_stack.clear();
+		if let Some((col, order)) = self.sort_col {
+			self.sort_col = Some((moved_col_index(col, from, to), order));
+		}
+		self.focused_col = self.focused_col.map(|col| moved_col_index(col, from, to));
+		self.focused_cell = self.focused_cell.map(|(col, row)| (moved_col_index(col, from, to), row));
+		self.json_expanded = None;
+		if from < self.col_summaries.len() && to < self.col_summaries.len() {
+			let summary = self.col_summaries.remove(from);
+			self.col_summaries.insert(to, summary);
+		}
+	}
+}
+
+pub fn moved_col_index(col: usize, from: usize, to: usize) -> usize {
+	if col == from {
+		to
+	} else if from < col && col <= to {
+		col - 1
+	} else if to <= col && col < from {
+		col + 1
+	} else {
+		col
+	}
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Table<'_, Message>
 where
-	Renderer: renderer::Renderer + TextRenderer<Font = iced::Font>,
+	Renderer: renderer::Renderer + TextRenderer<Font = iced::Font> + ImageRenderer<Handle = image::Handle>,
 {
 	fn tag(&self) -> tree::Tag {
 		tree::Tag::of::<TableState>()
 	}
 
 	fn state(&self) -> tree::State {
-		tree::State::new(TableState::default())
+		tree::State::new(TableState {
+			pending_layout: self.initial_layout.cloned(),
+			..TableState::default()
+		})
 	}
 
 	fn size(&self) -> Size<Length> {
@@ -324,6 +2309,109 @@ where
 		Node::new(limits.max())
 	}
 
+	fn overlay<'b>(
+		&'b mut self,
+		tree: &'b mut Tree,
+		_layout: Layout<'b>,
+		_renderer: &Renderer,
+		_viewport: &Rectangle,
+		translation: Vector,
+	) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+		let state = tree.state.downcast_ref::<TableState>();
+		if let Some(((col_idx, row_idx), anchor)) = state.json_expanded
+			&& let Some(value) = self.json_cell(col_idx, row_idx)
+		{
+			return Some(overlay::Element::new(Box::new(Tooltip {
+				content: serde_json::to_string_pretty(&value).unwrap_or_default(),
+				anchor: anchor + translation,
+				font: self.text_font(false, self.font_size),
+				theme: self.theme,
+			})));
+		}
+		if !state.tooltip_shown {
+			return None;
+		}
+		let content = match state.hover_target?.0 {
+			HoverTarget::Cell(col_idx, row_idx) => {
+				let custom = self
+					.tooltip_cell
+					.as_ref()
+					.and_then(|tooltip_cell| tooltip_cell(self.abs_offset() + row_idx, col_idx, &self.cell_str(col_idx, row_idx)));
+				match custom {
+					Some(text) => text,
+					None => {
+						let full = self.cell_display_str(col_idx, row_idx);
+						let truncated = self.max_cell_length.is_some_and(|max_chars| full.chars().count() > max_chars);
+						truncated.then_some(full)?
+					}
+				}
+			}
+			HoverTarget::Header(col_idx) => match self.header_tooltips.get(col_idx).cloned().flatten() {
+				Some(text) => text,
+				None => self.data_frame.get_column_names().get(col_idx)?.to_string(),
+			},
+		};
+		Some(overlay::Element::new(Box::new(Tooltip {
+			content,
+			anchor: state.hover_pos + translation,
+			font: self.text_font(false, self.font_size),
+			theme: self.theme,
+		})))
+	}
+
+	/// Reports the visible header labels and cells as text nodes in row-major
+	/// order; off-screen rows are left out to keep the output bounded.
+	fn operate(
+		&mut self,
+		tree: &mut Tree,
+		layout: Layout<'_>,
+		_renderer: &Renderer,
+		operation: &mut dyn Operation,
+	) {
+		let bounds = layout.bounds();
+		operation.focusable(None, bounds, tree.state.downcast_mut::<TableState>());
+		let state = tree.state.downcast_ref::<TableState>();
+		if state.col_widths.is_empty() {
+			return;
+		}
+		let table = &*self;
+		operation.container(None, bounds);
+		operation.traverse(&mut |operation| {
+			let data_left = bounds.x + table.row_num_width(bounds, state.v_scroll_offset);
+			let data_right = bounds.x + bounds.width - table.v_scrollbar_width();
+			let edges = table.col_left_edges(state);
+			let widths = table.visible_col_widths(state);
+			let cols: Vec<(usize, f32, f32)> = (0..table.col_count())
+				.filter(|&col_idx| !table.is_hidden(col_idx))
+				.map(|col_idx| (col_idx, table.col_screen_left(state, &edges, data_left, col_idx), widths[col_idx]))
+				.filter(|&(_, x, width)| x + width > data_left && x < data_right)
+				.collect();
+			let names = table.data_frame.get_column_names();
+			for &(col_idx, x, width) in &cols {
+				let header = Rectangle {
+					x,
+					y: table.label_top(bounds),
+					width,
+					height: table.name_row_height(),
+				};
+				operation.text(None, header, names[col_idx].as_str());
+			}
+			let data_top = bounds.y + table.header_total_height();
+			let data_bottom = bounds.y + bounds.height - table.h_scrollbar_height();
+			for row_idx in table.row_at(state, state.v_scroll_offset)..table.loaded_row_count() {
+				let y = data_top + (table.row_top(state, row_idx) - state.v_scroll_offset) as f32;
+				if y >= data_bottom {
+					break;
+				}
+				let height = table.row_height_of(state, row_idx);
+				for &(col_idx, x, width) in &cols {
+					let cell = Rectangle { x, y, width, height };
+					operation.text(None, cell, &table.cell_display_str(col_idx, row_idx));
+				}
+			}
+		});
+	}
+
 	fn mouse_interaction(
 		&self,
 		tree: &Tree,
@@ -338,11 +2426,27 @@ where
 		if state.resizing_col.is_some() {
 			return Interaction::ResizingHorizontally;
 		}
+		if state.reorder_col.is_some() {
+			return Interaction::Grabbing;
+		}
 		if let Some(pos) = cursor.position() && self
 				.divider_at_cursor(state, bounds, pos.x, pos.y, row_num_w)
 				.is_some() {
 			return Interaction::ResizingHorizontally;
 		}
+		if let Some(pos) = cursor.position()
+			&& self.column_menu_button_at(state, bounds, pos, row_num_w).is_some()
+		{
+			return Interaction::Pointer;
+		}
+		if self.on_open_url.is_some()
+			&& state.modifiers.control()
+			&& let Some(pos) = cursor.position()
+			&& let Some((col_idx, row_idx)) = self.hit_test_cell(state, bounds, pos, row_num_w)
+			&& self.url_cell(col_idx, row_idx).is_some()
+		{
+			return Interaction::Pointer;
+		}
 		Interaction::default()
 	}
 
@@ -359,19 +2463,133 @@ where
 	) {
 		let bounds = layout.bounds();
 		let state = tree.state.downcast_mut::<TableState>();
+		if state.data_generation != self.data_generation {
+			state.data_generation = self.data_generation;
+			state.selected_rows.clear();
+			state.last_clicked_row = None;
+			state.drag_select_start = None;
+			state.selection = None;
+			state.json_expanded = None;
+		}
+		if let Some(sort) = self.sorted_by {
+			state.sort_col = sort;
+		}
 		let row_num_w = self.row_num_width(bounds, state.v_scroll_offset);
-		let viewport_w = bounds.width - V_SCROLLBAR_WIDTH - row_num_w;
+		let viewport_w = bounds.width - self.v_scrollbar_width() - row_num_w;
 		self.col_widths(state, viewport_w);
-		let total_h = self.total_content_height();
+		let viewport_h = bounds.height - self.h_scrollbar_height();
+		self.update_row_heights::<Renderer>(state, viewport_h);
+		self.update_summaries(state);
+		let total_h = self.total_content_height(state);
 		let total_w = self.total_content_width(state);
-		let viewport_h = bounds.height - H_SCROLLBAR_HEIGHT;
 		let max_v_scroll = (total_h - viewport_h).max(0.0) as f64;
 		let max_h_scroll = (total_w - viewport_w).max(0.0) as f64;
-		let v_thumb = self.v_scrollbar_thumb_rect(bounds, state.v_scroll_offset);
+		state.v_scroll_offset = state.v_scroll_offset.min(max_v_scroll);
+		state.h_scroll_offset = state.h_scroll_offset.min(max_h_scroll);
+		state.scroll_target_v = state.scroll_target_v.map(|target| target.min(max_v_scroll));
+		state.scroll_target_h = state.scroll_target_h.map(|target| target.min(max_h_scroll));
+		if let Some((h, v)) = self.scroll_offset
+			&& state.applied_scroll_offset != self.scroll_offset
+		{
+			state.applied_scroll_offset = self.scroll_offset;
+			state.cancel_scroll_animation();
+			state.h_scroll_offset = (h as f64).clamp(0.0, max_h_scroll);
+			state.v_scroll_offset = (v as f64).clamp(0.0, max_v_scroll);
+			shell.request_redraw();
+		}
+		let layout_before = (state.h_scroll_offset, state.v_scroll_offset, state.resizing_col);
+		let v_thumb = self.v_scrollbar_thumb_rect(bounds, state.v_scroll_offset, state);
+		let row_h = self.base_row_height() as f64;
 		let h_thumb = self.h_scrollbar_thumb_rect(bounds, state.h_scroll_offset, state, row_num_w);
+		if self.scroll_to_row != state.scrolled_to_row {
+			state.scrolled_to_row = self.scroll_to_row;
+			if let Some(row_idx) = self.scroll_to_row.and_then(|r| r.checked_sub(self.abs_offset())) {
+				let row_y = self.row_top(state, row_idx);
+				let target_h = self.row_height_of(state, row_idx) as f64;
+				let data_h = (viewport_h - self.header_total_height() - self.pinned_rows_height(state)) as f64;
+				let visible = row_y >= state.v_scroll_offset
+					&& row_y + target_h <= state.v_scroll_offset + data_h;
+				if !visible {
+					state.v_scroll_offset = (row_y - (data_h - target_h) / 2.0)
+						.clamp(0.0, max_v_scroll);
+					shell.request_redraw();
+				}
+			}
+		}
+		if self.focus_col != state.focused_to_col {
+			state.focused_to_col = self.focus_col;
+			if let Some((col_idx, _)) = self.focus_col
+				&& col_idx >= self.pinned_col_count()
+				&& col_idx < state.col_widths.len()
+				&& !self.is_hidden(col_idx)
+			{
+				let pinned_w = self.pinned_width(state);
+				let left = (self.col_left_edges(state)[col_idx] - pinned_w) as f64;
+				let width = state.col_widths[col_idx] as f64;
+				let view_w = (viewport_w - pinned_w) as f64;
+				let visible = left >= state.h_scroll_offset
+					&& left + width <= state.h_scroll_offset + view_w;
+				if !visible {
+					state.h_scroll_offset = (left - (view_w - width) / 2.0).clamp(0.0, max_h_scroll);
+					shell.request_redraw();
+				}
+			}
+		}
+		if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event
+			&& state.is_focused
+			&& !cursor.is_over(bounds)
+		{
+			state.is_focused = false;
+			shell.request_redraw();
+		}
 		match event {
 			Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
 				if let Some(pos) = cursor.position() {
+					let filter_col = self.filter_col_at(state, bounds, pos, row_num_w);
+					let caret = if state.focused_filter == filter_col { state.filter_caret } else { 0 };
+					if state.focused_filter != filter_col {
+						state.focused_filter = filter_col;
+						shell.request_redraw();
+					}
+					if let Some(col_idx) = filter_col {
+						let edges = self.col_left_edges(state);
+						let cell_x = self.col_screen_left(state, &edges, bounds.x + row_num_w, col_idx);
+						let input = filter_input_rect(
+							cell_x,
+							state.col_widths[col_idx],
+							self.label_top(bounds) + self.label_height(),
+						);
+						let value = self.col_filter(col_idx);
+						if !value.is_empty()
+							&& pos.x >= input.x + input.width - FILTER_CLEAR_WIDTH
+							&& let Some(on_filter_change) = &self.on_filter_change
+						{
+							state.focused_filter = None;
+							shell.publish(on_filter_change(col_idx, String::new()));
+						} else {
+							let font = self.text_font(false, FILTER_FONT_SIZE);
+							let scroll = filter_text_scroll::<Renderer>(value, caret, input, font);
+							let x = pos.x - (input.x + FILTER_TEXT_INSET) + scroll;
+							state.filter_caret = value
+								.char_indices()
+								.map(|(i, _)| i)
+								.chain(std::iter::once(value.len()))
+								.min_by(|&a, &b| {
+									let dist = |i: usize| (measure_text_width::<Renderer>(&value[..i], font) - x).abs();
+									dist(a).total_cmp(&dist(b))
+								})
+								.unwrap_or(0);
+						}
+						shell.capture_event();
+						return;
+					}
+					if let Some(col_idx) = self.column_menu_button_at(state, bounds, pos, row_num_w)
+						&& let Some(on_column_menu) = &self.on_column_menu
+					{
+						shell.publish(on_column_menu(col_idx, pos));
+						shell.capture_event();
+						return;
+					}
 					if let Some(col_idx) =
 						self.divider_at_cursor(state, bounds, pos.x, pos.y, row_num_w)
 					{
@@ -381,48 +2599,161 @@ where
 						shell.request_redraw();
 						return;
 					}
-					if cursor.is_over(v_thumb) {
+					let thumbs_shown = self.scrollbar_color(state).is_some();
+					if thumbs_shown && cursor.is_over(v_thumb) {
+						state.cancel_scroll_animation();
 						state.v_dragging_scrollbar = true;
 						state.v_drag_start_y = pos.y;
 						state.v_drag_start_offset = state.v_scroll_offset;
 						shell.request_redraw();
-					} else if cursor.is_over(h_thumb) {
+					} else if thumbs_shown && cursor.is_over(h_thumb) {
+						state.cancel_scroll_animation();
 						state.h_dragging_scrollbar = true;
 						state.h_drag_start_x = pos.x;
 						state.h_drag_start_offset = state.h_scroll_offset;
 						shell.request_redraw();
-					} else if let Some(cell) =
-						self.hit_test_cell(state, bounds, pos, row_num_w)
-					{
-						let extend = state.modifiers.shift();
-						if extend && let Some(sel) = &mut state.selection {
-							sel.active = cell;
-						} else {
-							state.selection = Some(TableSelection {
-								anchor: cell,
-								active: cell,
-							});
+					} else if let Some(col_idx) = self.header_col_at(state, bounds, pos, row_num_w) {
+						let content_x = pos.x - (bounds.x + row_num_w) + state.h_scroll_offset as f32;
+						let left = self.col_left_edges(state)[col_idx];
+						let right = left + state.col_widths[col_idx];
+						let margin = COL_RESIZE_GRAB_ZONE + 4.0;
+						state.header_press = Some(col_idx);
+						state.header_press_x = pos.x;
+						state.header_press_reorderable =
+							content_x - left >= margin && right - content_x >= margin;
+					} else {
+						if state.modifiers.control()
+							&& let Some(on_open_url) = &self.on_open_url
+							&& let Some((col_idx, row_idx)) = self.hit_test_cell(state, bounds, pos, row_num_w)
+							&& let Some(url) = self.url_cell(col_idx, row_idx)
+						{
+							shell.publish(on_open_url(url));
+							shell.capture_event();
+							return;
 						}
-						state.is_selecting = true;
-						shell.request_redraw();
-					} else if state.selection.is_some() {
+						if self.row_drag_enabled()
+							&& pos.x < bounds.x + row_num_w
+							&& let Some(row_idx) = self.hit_test_row(state, bounds, pos)
+							&& row_idx < self.pinned_row_range().start
+							&& (!self.row_click_select_range
+								|| state.selected_rows.contains(&(self.abs_offset() + row_idx)))
+						{
+							state.row_press = Some(row_idx);
+							state.row_press_y = pos.y;
+						}
+						if (self.on_row_click.is_some() || self.on_selection_change.is_some())
+							&& let Some(row_idx) = self.hit_test_row(state, bounds, pos)
+						{
+							let abs_idx = self.abs_offset() + row_idx;
+							state.click_row(abs_idx);
+							if let Some(on_row_click) = &self.on_row_click {
+								shell.publish(on_row_click(abs_idx));
+							}
+							if let Some(on_selection_change) = &self.on_selection_change {
+								shell.publish(on_selection_change(state.selected_rows.len()));
+							}
+							shell.request_redraw();
+						}
+						if self.row_click_select_range
+							&& pos.x < bounds.x + row_num_w
+							&& state.row_press.is_none()
+							&& !state.modifiers.shift()
+							&& let Some(row_idx) = self.hit_test_row(state, bounds, pos)
+						{
+							state.drag_select_start = Some(self.abs_offset() + row_idx);
+						}
+						// Ctrl+click toggles whole rows, so it doesn't leave a cell
+						// selection behind for Ctrl+C to copy instead of the rows.
+						let toggles_row = state.modifiers.control()
+							&& (self.on_row_click.is_some() || self.on_selection_change.is_some());
+						if !toggles_row && let Some(cell) = self.hit_test_cell(state, bounds, pos, row_num_w) {
+							let now = Instant::now();
+							let is_double = state.last_cell_click.is_some_and(|(at, last)| {
+								last == cell && now.duration_since(at) < DOUBLE_CLICK_INTERVAL
+							});
+							state.last_cell_click = Some((now, cell));
+							state.focused_cell = Some(cell);
+							state.json_expanded = self.json_cell(cell.0, cell.1).is_some().then_some((cell, pos));
+							if is_double && let Some(on_cell_double_click) = &self.on_cell_double_click {
+								shell.publish(on_cell_double_click(self.abs_offset() + cell.1, cell.0));
+							}
+							let extend = state.modifiers.shift();
+							if extend && let Some(sel) = &mut state.selection {
+								sel.active = cell;
+							} else {
+								state.selection = Some(TableSelection {
+									anchor: cell,
+									active: cell,
+								});
+							}
+							state.is_selecting = true;
+							shell.request_redraw();
+						} else if state.selection.is_some() || state.json_expanded.is_some() {
 							state.selection = None;
+							state.json_expanded = None;
 							shell.request_redraw();
+						}
 					}
 				}
 			}
+			Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+				if let Some(on_column_menu) = &self.on_column_menu
+					&& let Some(pos) = cursor.position_over(bounds)
+					&& pos.y < bounds.y + self.header_total_height()
+					&& let Some(col_idx) = self.header_col_at(state, bounds, pos, row_num_w)
+				{
+					shell.publish(on_column_menu(col_idx, pos));
+					shell.capture_event();
+				}
+				if let Some(on_header_right_click) = &self.on_header_right_click
+					&& let Some(pos) = cursor.position_over(bounds)
+					&& self.divider_at_cursor(state, bounds, pos.x, pos.y, row_num_w).is_none()
+					&& let Some(col_idx) = self.header_col_at(state, bounds, pos, row_num_w)
+				{
+					shell.publish(on_header_right_click(col_idx));
+					shell.capture_event();
+				}
+				if let Some(on_cell_menu) = &self.on_cell_menu
+					&& let Some(pos) = cursor.position_over(bounds)
+					&& let Some((col_idx, row_idx)) = self.hit_test_cell(state, bounds, pos, row_num_w)
+				{
+					shell.publish(on_cell_menu(self.abs_offset() + row_idx, col_idx, pos));
+					shell.capture_event();
+				}
+			}
 			Event::Mouse(mouse::Event::CursorMoved { position }) => {
 				if let Some(col_idx) = state.resizing_col {
 					let delta = position.x - state.resize_drag_start_x;
+					let (min_w, max_w) = self.col_width_bounds(col_idx);
 					state.col_widths[col_idx] =
-						(state.resize_drag_start_width + delta).max(MIN_COL_WIDTH);
+						(state.resize_drag_start_width + delta).clamp(min_w, max_w);
 					let new_total_w = self.total_content_width(state);
 					let new_max_h = (new_total_w - viewport_w).max(0.0) as f64;
 					state.h_scroll_offset = state.h_scroll_offset.min(new_max_h);
 					shell.request_redraw();
+				} else if state.reorder_col.is_some() {
+					state.reorder_drag_x = position.x;
+					shell.request_redraw();
+				} else if let Some(col_idx) = state.header_press
+					&& self.on_reorder.is_some()
+					&& state.header_press_reorderable
+					&& (position.x - state.header_press_x).abs() > COL_REORDER_THRESHOLD
+				{
+					state.reorder_col = Some(col_idx);
+					state.reorder_drag_x = position.x;
+					shell.request_redraw();
+				} else if state.reorder_row.is_some() {
+					state.reorder_drag_y = position.y;
+					shell.request_redraw();
+				} else if let Some(row_idx) = state.row_press
+					&& (position.y - state.row_press_y).abs() > ROW_REORDER_THRESHOLD
+				{
+					state.reorder_row = Some(row_idx);
+					state.reorder_drag_y = position.y;
+					shell.request_redraw();
 				} else if state.v_dragging_scrollbar {
 					let drag_delta = position.y - state.v_drag_start_y;
-					let track_h = bounds.height - self.header_height() - H_SCROLLBAR_HEIGHT;
+					let track_h = bounds.height - self.header_total_height() - self.h_scrollbar_height();
 					let thumb_h = v_thumb.height;
 					let scroll_ratio = drag_delta as f64 / (track_h - thumb_h).max(1.0) as f64;
 					state.v_scroll_offset = (state.v_drag_start_offset
@@ -431,13 +2762,25 @@ where
 					shell.request_redraw();
 				} else if state.h_dragging_scrollbar {
 					let drag_delta = position.x - state.h_drag_start_x;
-					let track_w = viewport_w;
+					let track_w = viewport_w - self.pinned_width(state);
 					let thumb_w = h_thumb.width;
 					let scroll_ratio = drag_delta as f64 / (track_w - thumb_w).max(1.0) as f64;
 					state.h_scroll_offset = (state.h_drag_start_offset
 						+ scroll_ratio * max_h_scroll)
 						.clamp(0.0, max_h_scroll);
 					shell.request_redraw();
+				} else if let Some(start) = state.drag_select_start {
+					if let Some(row_idx) = self.row_at_screen_y(state, bounds, position.y) {
+						let abs_idx = self.abs_offset() + row_idx;
+						let range: BTreeSet<usize> = (start.min(abs_idx)..=start.max(abs_idx)).collect();
+						if state.selected_rows != range {
+							state.selected_rows = range;
+							if let Some(on_selection_change) = &self.on_selection_change {
+								shell.publish(on_selection_change(state.selected_rows.len()));
+							}
+							shell.request_redraw();
+						}
+					}
 				} else if state.is_selecting
 					&& let Some(cell) = self.hit_test_cell(state, bounds, *position, row_num_w)
 					&& let Some(sel) = &mut state.selection
@@ -445,10 +2788,89 @@ where
 							sel.active = cell;
 							shell.request_redraw();
 				}
+				state.hover_pos = *position;
+				let header = (state.header_press.is_none() && state.resizing_col.is_none())
+					.then(|| self.header_col_at(state, bounds, *position, row_num_w))
+					.flatten();
+				if self.column_menu_button && state.hovered_header_col != header {
+					state.hovered_header_col = header;
+					shell.request_redraw();
+				}
+				if self.resize_indicator.is_some() {
+					let divider = self.divider_at_cursor(state, bounds, position.x, position.y, row_num_w);
+					if state.hovered_divider != divider {
+						state.hovered_divider = divider;
+						shell.request_redraw();
+					}
+				}
+				let target = match header {
+					Some(col_idx) => Some(HoverTarget::Header(col_idx)),
+					None if self.tooltip_cell.is_some() || self.max_cell_length.is_some() => self
+						.hit_test_cell(state, bounds, *position, row_num_w)
+						.map(|(col_idx, row_idx)| HoverTarget::Cell(col_idx, row_idx)),
+					None => None,
+				};
+				if state.hover(target) {
+					shell.invalidate_layout();
+				}
+				if let Some(target) = target {
+					shell.request_redraw_at(Instant::now() + target.delay());
+				}
+			}
+			Event::Mouse(mouse::Event::CursorLeft) => {
+				if state.hovered_header_col.take().is_some() | state.hovered_divider.take().is_some() {
+					shell.request_redraw();
+				}
+				if state.hover(None) {
+					shell.invalidate_layout();
+				}
 			}
 			Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-				if state.resizing_col.is_some() {
-					state.resizing_col = None;
+				state.row_press = None;
+				state.drag_select_start = None;
+				if let Some(from) = state.reorder_row.take() {
+					let slot = self.row_reorder_slot_at(state, bounds, state.reorder_drag_y);
+					let to = if slot > from { slot - 1 } else { slot };
+					if to != from
+						&& let Some(on_row_reorder) = &self.on_row_reorder
+					{
+						state.selected_rows.clear();
+						state.selected_rows.insert(self.abs_offset() + to);
+						state.last_clicked_row = Some(self.abs_offset() + to);
+						shell.publish(on_row_reorder(self.abs_offset() + from, self.abs_offset() + to));
+						if let Some(on_selection_change) = &self.on_selection_change {
+							shell.publish(on_selection_change(1));
+						}
+					}
+					shell.request_redraw();
+				} else if let Some(from) = state.reorder_col.take() {
+					state.header_press = None;
+					let slot = self.reorder_slot_at(state, bounds, state.reorder_drag_x, row_num_w);
+					let to = if slot > from { slot - 1 } else { slot };
+					if to != from
+						&& let Some(on_reorder) = &self.on_reorder
+					{
+						state.move_col(from, to);
+						shell.publish(on_reorder(from, to));
+					}
+					shell.request_redraw();
+				} else if let Some(pressed_col) = state.header_press.take()
+					&& let Some(on_sort) = &self.on_sort
+					&& let Some(pos) = cursor.position()
+					&& self.header_col_at(state, bounds, pos, row_num_w) == Some(pressed_col)
+				{
+					let order = state.cycle_sort(pressed_col);
+					shell.publish(on_sort(pressed_col, order));
+					shell.request_redraw();
+				}
+				state.elastic_since = None;
+				if let Some(col_idx) = state.resizing_col.take() {
+					if let Some(on_column_resize_end) = &self.on_column_resize_end
+						&& state.col_widths[col_idx] != state.resize_drag_start_width
+					{
+						shell.publish(on_column_resize_end(col_idx, state.col_widths[col_idx]));
+					}
+					state.finish_resize(col_idx);
 					shell.request_redraw();
 				} else if state.v_dragging_scrollbar || state.h_dragging_scrollbar {
 					state.v_dragging_scrollbar = false;
@@ -459,6 +2881,10 @@ where
 				}
 			}
 			Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+				state.cancel_scroll_animation();
+				if state.hover(None) {
+					shell.invalidate_layout();
+				}
 				match delta {
 					ScrollDelta::Lines { x, y } => {
 						if x.abs() > y.abs() {
@@ -466,80 +2892,194 @@ where
 								- (*x as f64) * MIN_COL_WIDTH as f64)
 								.clamp(0.0, max_h_scroll);
 						} else {
-							state.v_scroll_offset = (state.v_scroll_offset
-								- (*y as f64) * ROW_HEIGHT as f64)
-								.clamp(0.0, max_v_scroll);
+							state.scroll_v_elastic(-(*y as f64) * row_h, max_v_scroll, viewport_h);
 						}
 					}
 					ScrollDelta::Pixels { x, y } => {
-						if x.abs() > y.abs() {
-							state.h_scroll_offset =
-								(state.h_scroll_offset - *x as f64).clamp(0.0, max_h_scroll);
-						} else {
-							state.v_scroll_offset =
-								(state.v_scroll_offset - *y as f64).clamp(0.0, max_v_scroll);
+						state.h_scroll_offset =
+							(state.h_scroll_offset - *x as f64).clamp(0.0, max_h_scroll);
+						if *y != 0.0 {
+							state.scroll_v_elastic(-*y as f64, max_v_scroll, viewport_h);
+						}
+					}
+				}
+				shell.request_redraw();
+			}
+			Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, text, .. })
+				if state.focused_filter.is_some()
+					&& (!modifiers.control()
+						|| matches!(key, keyboard::Key::Character(c) if c.eq_ignore_ascii_case("v"))) =>
+			{
+				if let Some(col_idx) = state.focused_filter {
+					let current = self.col_filter(col_idx);
+					let mut caret = state.filter_caret.min(current.len());
+					while !current.is_char_boundary(caret) {
+						caret -= 1;
+					}
+					let prev = current[..caret].char_indices().next_back().map_or(0, |(i, _)| i);
+					let next = current[caret..].chars().next().map_or(caret, |c| caret + c.len_utf8());
+					let insert = |state: &mut TableState, inserted: &str| {
+						state.filter_caret = caret + inserted.len();
+						Some(format!("{}{inserted}{}", &current[..caret], &current[caret..]))
+					};
+					let updated = match key {
+						keyboard::Key::Named(keyboard::key::Named::Escape | keyboard::key::Named::Enter) => {
+							state.focused_filter = None;
+							None
+						}
+						keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+							state.filter_caret = prev;
+							Some(format!("{}{}", &current[..prev], &current[caret..]))
+						}
+						keyboard::Key::Named(keyboard::key::Named::Delete) => {
+							state.filter_caret = caret;
+							Some(format!("{}{}", &current[..caret], &current[next..]))
+						}
+						keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
+							state.filter_caret = prev;
+							None
+						}
+						keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
+							state.filter_caret = next;
+							None
+						}
+						keyboard::Key::Named(keyboard::key::Named::Home) => {
+							state.filter_caret = 0;
+							None
+						}
+						keyboard::Key::Named(keyboard::key::Named::End) => {
+							state.filter_caret = current.len();
+							None
 						}
+						_ if modifiers.control() => {
+							use iced::advanced::clipboard::Kind;
+							let pasted: Option<String> = _clipboard
+								.read(Kind::Standard)
+								.map(|contents| contents.chars().filter(|c| !c.is_control()).collect());
+							pasted.and_then(|pasted| insert(state, &pasted))
+						}
+						_ => text
+							.as_ref()
+							.filter(|t| !t.chars().any(char::is_control))
+							.and_then(|t| insert(state, t)),
+					};
+					if let Some(updated) = updated
+						&& updated != current
+						&& let Some(on_filter_change) = &self.on_filter_change
+					{
+						shell.publish(on_filter_change(col_idx, updated));
 					}
 				}
+				shell.capture_event();
 				shell.request_redraw();
 			}
 			Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
-				if cursor.is_over(bounds) =>
+				if state.is_focused || self.focused.unwrap_or_else(|| cursor.is_over(bounds)) =>
 			{
-				let page_size = (viewport_h - self.header_height()) as f64;
+				let page_size = (viewport_h - self.header_total_height()) as f64;
 				match key {
 					keyboard::Key::Named(keyboard::key::Named::PageDown) => {
-						state.v_scroll_offset =
-							(state.v_scroll_offset + page_size).clamp(0.0, max_v_scroll);
+						state.scroll_v_by(page_size, max_v_scroll);
 					}
 					keyboard::Key::Named(keyboard::key::Named::PageUp) => {
-						state.v_scroll_offset =
-							(state.v_scroll_offset - page_size).clamp(0.0, max_v_scroll);
+						state.scroll_v_by(-page_size, max_v_scroll);
 					}
 					keyboard::Key::Named(keyboard::key::Named::Home) => {
-						state.v_scroll_offset = 0.0;
+						state.scroll_v_to(0.0, max_v_scroll);
 					}
 					keyboard::Key::Named(keyboard::key::Named::End) => {
-						state.v_scroll_offset = max_v_scroll;
+						state.scroll_v_to(max_v_scroll, max_v_scroll);
+					}
+					keyboard::Key::Named(
+						named @ (keyboard::key::Named::ArrowDown
+						| keyboard::key::Named::ArrowUp
+						| keyboard::key::Named::ArrowRight
+						| keyboard::key::Named::ArrowLeft),
+					) if self.selected_cell_border.is_some() && state.focused_cell.is_some() => {
+						let body_h = (viewport_h - self.header_total_height() - self.pinned_rows_height(state)) as f64;
+						self.step_focused_cell(state, *named, body_h, max_v_scroll, max_h_scroll);
+						shell.capture_event();
 					}
 					keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
-						state.v_scroll_offset =
-							(state.v_scroll_offset + ROW_HEIGHT as f64).clamp(0.0, max_v_scroll);
+						state.scroll_v_by(row_h, max_v_scroll);
 					}
 					keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
-						state.v_scroll_offset =
-							(state.v_scroll_offset - ROW_HEIGHT as f64).clamp(0.0, max_v_scroll);
+						state.scroll_v_by(-row_h, max_v_scroll);
 					}
 					keyboard::Key::Named(keyboard::key::Named::ArrowRight) => {
-						state.h_scroll_offset =
-							(state.h_scroll_offset + MIN_COL_WIDTH as f64).clamp(0.0, max_h_scroll);
+						state.scroll_h_by(MIN_COL_WIDTH as f64, max_h_scroll);
 					}
 					keyboard::Key::Named(keyboard::key::Named::ArrowLeft) => {
-						state.h_scroll_offset =
-							(state.h_scroll_offset - MIN_COL_WIDTH as f64).clamp(0.0, max_h_scroll);
+						state.scroll_h_by(-(MIN_COL_WIDTH as f64), max_h_scroll);
+					}
+					keyboard::Key::Character(c)
+						if c.as_str() == "c" && modifiers.control() && !modifiers.shift() =>
+					{
+						let (rows, cols): (Vec<usize>, Vec<usize>) = if let Some(sel) = &state.selection {
+							let (min_col, max_col, min_row, max_row) = sel.range();
+							((min_row..=max_row).collect(), (min_col..=max_col).collect())
+						} else if !state.selected_rows.is_empty() {
+							let rows = state
+								.selected_rows
+								.iter()
+								.filter_map(|abs_idx| abs_idx.checked_sub(self.abs_offset()))
+								.filter(|&row| row < self.loaded_row_count())
+								.collect();
+							(rows, (0..self.col_count()).collect())
+						} else {
+							let first_visible = self.row_at(state, state.v_scroll_offset);
+							let data_bottom =
+								state.v_scroll_offset + (viewport_h - self.header_total_height()) as f64;
+							let last_visible =
+								(self.row_at(state, data_bottom - 1.0) + 1).min(self.loaded_row_count());
+							((first_visible..last_visible).collect(), (0..self.col_count()).collect())
+						};
+						let cols: Vec<usize> = cols.into_iter().filter(|&c| !self.is_hidden(c)).collect();
+						use iced::advanced::clipboard::Kind;
+						_clipboard.write(Kind::Standard, self.to_tsv(&rows, &cols));
+					}
+					keyboard::Key::Character(c)
+						if c.eq_ignore_ascii_case("z")
+							&& modifiers.control()
+							&& !modifiers.shift()
+							&& state.undo_resize() =>
+					{
+						shell.request_redraw();
 					}
 					keyboard::Key::Character(c)
-						if c.as_str() == "c" && modifiers.control() =>
+						if (c.eq_ignore_ascii_case("y") || (c.eq_ignore_ascii_case("z") && modifiers.shift()))
+							&& modifiers.control()
+							&& state.redo_resize() =>
 					{
-						if let Some(sel) = &state.selection {
-							let (min_col, max_col, min_row, max_row) = sel.range();
-							let mut out = String::new();
-							for row in min_row..=max_row {
-								for col in min_col..=max_col {
-									if col > min_col {
-										out.push('\t');
-									}
-									out.push_str(&self.cell_str(col, row));
+						shell.request_redraw();
+					}
+					keyboard::Key::Character(c)
+						if c.as_str() == "f" && modifiers.control() =>
+					{
+						if let Some(on_find) = &self.on_find {
+							shell.publish(on_find());
+						}
+					}
+					keyboard::Key::Named(keyboard::key::Named::Tab) => {
+						state.focused_col = self.step_focused_col(state.focused_col, modifiers.shift());
+						let first_visible = self.row_at(state, state.v_scroll_offset);
+						state.focused_cell = state
+							.focused_col
+							.map(|col_idx| (col_idx, state.focused_cell.map_or(first_visible, |(_, row_idx)| row_idx)));
+						match state.focused_col {
+							Some(col_idx) => self.scroll_col_into_view(state, col_idx, max_h_scroll),
+							None => {
+								if let Some(on_tab_out) = &self.on_tab_out {
+									shell.publish(on_tab_out());
 								}
-								out.push('\n');
 							}
-							use iced::advanced::clipboard::Kind;
-							_clipboard.write(Kind::Standard, out);
 						}
+						shell.capture_event();
 					}
 					keyboard::Key::Named(keyboard::key::Named::Escape) => {
-						if state.selection.is_some() {
+						if state.selection.is_some() || state.json_expanded.is_some() {
 							state.selection = None;
+							state.json_expanded = None;
 							shell.request_redraw();
 						}
 					}
@@ -550,8 +3090,91 @@ where
 			Event::Keyboard(keyboard::Event::ModifiersChanged(mods)) => {
 				state.modifiers = *mods;
 			}
+			Event::Window(window::Event::RedrawRequested(now)) => {
+				if state.step_scroll_animation() | state.step_elastic(*now) {
+					shell.request_redraw();
+				}
+				if state.opacity_timer > 0.0 && !state.v_dragging_scrollbar && !state.h_dragging_scrollbar {
+					state.opacity_timer = (state.opacity_timer - OVERLAY_SCROLLBAR_FADE_STEP).max(0.0);
+					shell.request_redraw();
+				}
+				if !state.tooltip_shown
+					&& state.hover_target.is_some_and(|(target, since)| *now >= since + target.delay())
+				{
+					state.tooltip_shown = true;
+					shell.invalidate_layout();
+				}
+			}
 			_ => {}
 		}
+		if layout_before != (state.h_scroll_offset, state.v_scroll_offset, state.resizing_col) {
+			state.layout_dirty = true;
+		}
+		let scrolled = (layout_before.0, layout_before.1) != (state.h_scroll_offset, state.v_scroll_offset);
+		if scrolled && self.scrollbar_style == ScrollbarStyle::Overlay {
+			state.opacity_timer = 1.0;
+			shell.request_redraw();
+		}
+		if scrolled && let Some(on_scroll) = &self.on_scroll {
+			let offsets = (state.h_scroll_offset as f32, state.v_scroll_offset as f32);
+			state.applied_scroll_offset = Some(offsets);
+			shell.publish(on_scroll(offsets.0, offsets.1));
+		}
+		if let Some(on_layout_change) = &self.on_layout_change
+			&& state.layout_dirty
+			&& state.resizing_col.is_none()
+			&& state.scroll_target_v.is_none()
+			&& state.scroll_target_h.is_none()
+			&& !state.v_dragging_scrollbar
+			&& !state.h_dragging_scrollbar
+		{
+			state.layout_dirty = false;
+			shell.publish(on_layout_change(state.layout()));
+		}
+		let loaded = self.loaded_row_count();
+		if loaded > state.last_loaded_rows {
+			state.load_more_requested = false;
+		}
+		state.last_loaded_rows = loaded;
+		if let Some(on_load_more) = &self.on_load_more
+			&& !state.load_more_requested
+			&& loaded < self.total_row_count()
+			&& state.v_scroll_offset > max_v_scroll * 0.8
+		{
+			state.load_more_requested = true;
+			shell.publish(on_load_more());
+		}
+		if let Some(on_image_request) = &self.on_image_request
+			&& !self.image_cols.is_empty()
+		{
+			let first_visible = self.row_at(state, state.v_scroll_offset);
+			let visible_count = ((viewport_h - self.header_total_height()) / self.base_row_height()).ceil().max(0.0) as usize + 1;
+			let mut missing: Vec<String> = Vec::new();
+			for row_idx in first_visible..(first_visible + visible_count).min(self.total_row_count()) {
+				for &col_idx in &self.image_cols {
+					if let Some(source) = self.image_source(col_idx, row_idx)
+						&& !self.image_cache.is_some_and(|cache| cache.contains_key(&source))
+						&& !missing.contains(&source)
+					{
+						missing.push(source);
+					}
+				}
+			}
+			if !missing.is_empty() {
+				shell.publish(on_image_request(missing));
+			}
+		}
+		if let Some(fetch) = &self.on_fetch_rows {
+			let first_visible = self.row_at(state, state.v_scroll_offset);
+			let visible_count = ((viewport_h - self.header_total_height()) / self.base_row_height()).ceil().max(0.0) as usize + 1;
+			let needed = first_visible..(first_visible + visible_count).min(self.total_row_count());
+			let window = self.row_offset..self.row_offset + self.data_frame.height();
+			let request = (first_visible, visible_count + FETCH_MARGIN_ROWS);
+			if (needed.start < window.start || needed.end > window.end) && state.fetch_requested != Some(request) {
+				state.fetch_requested = Some(request);
+				shell.publish(fetch(request.0, request.1));
+			}
+		}
 	}
 
 	fn draw(
@@ -561,26 +3184,28 @@ where
 		_theme: &Theme,
 		_style: &Style,
 		layout: Layout<'_>,
-		_cursor: Cursor,
+		cursor: Cursor,
 		_viewport: &Rectangle,
 	) {
 		let state = tree.state.downcast_ref::<TableState>();
 		if state.col_widths.is_empty() {
+			self.draw_empty_message(renderer, layout.bounds());
+			self.draw_loading(renderer, layout.bounds(), layout.bounds());
 			return;
 		}
 		let bounds = layout.bounds();
-		let v_scroll = state.v_scroll_offset;
-		let row_num_w = self.row_num_width(bounds, v_scroll);
-		let viewport_w = bounds.width - V_SCROLLBAR_WIDTH - row_num_w;
+		let v_scroll = state.v_scroll_offset + state.elastic_v as f64;
+		let row_num_w = self.row_num_width(bounds, state.v_scroll_offset);
+		let viewport_w = bounds.width - self.v_scrollbar_width() - row_num_w;
 		let h_scroll = state.h_scroll_offset as f32;
-		let header_h = self.header_height();
+		let header_h = self.header_total_height();
 		renderer.fill_quad(
 			renderer::Quad {
 				bounds,
 				border: border::rounded(4),
 				..renderer::Quad::default()
 			},
-			colors::BG_PRIMARY,
+			self.theme.background,
 		);
 		renderer.with_layer(bounds, |renderer| {
 			renderer.fill_quad(
@@ -593,33 +3218,37 @@ where
 					},
 					..renderer::Quad::default()
 				},
-				colors::BG_SECONDARY,
-			);
-			draw_text(
-				renderer,
-				"#  ",
-				Rectangle {
-					x: bounds.x + CELL_PADDING_X,
-					y: bounds.y,
-					width: row_num_w - CELL_PADDING_X,
-					height: header_h,
-				},
-				colors::TABLE_TEXT_HEADER,
-				true,
-				Horizontal::Center,
+				self.theme.header_background,
 			);
-			renderer.fill_quad(
-				renderer::Quad {
-					bounds: Rectangle {
-						x: bounds.x + row_num_w - 1.0,
-						y: bounds.y,
-						width: 1.0,
-						height: bounds.height - H_SCROLLBAR_HEIGHT,
+			if self.row_numbers {
+				if !self.hide_header {
+					draw_text(
+						renderer,
+						"#  ",
+						Rectangle {
+							x: bounds.x + CELL_PADDING_X,
+							y: bounds.y,
+							width: row_num_w - CELL_PADDING_X,
+							height: header_h,
+						},
+						self.theme.header_text,
+						self.text_font(true, self.font_size),
+						Horizontal::Center,
+					);
+				}
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: Rectangle {
+							x: bounds.x + row_num_w - 1.0,
+							y: bounds.y,
+							width: 1.0,
+							height: bounds.height - self.h_scrollbar_height(),
+						},
+						..renderer::Quad::default()
 					},
-					..renderer::Quad::default()
-				},
-				colors::TABLE_BORDER,
-			);
+					self.divider_color(),
+				);
+			}
 			let header_clip = Rectangle {
 				x: bounds.x + row_num_w,
 				y: bounds.y,
@@ -631,14 +3260,64 @@ where
 					bounds: header_clip,
 					..renderer::Quad::default()
 				},
-				colors::BG_SECONDARY,
+				self.theme.header_background,
 			);
-			renderer.with_layer(header_clip, |renderer| {
-				let col_widths = self.col_widths_ref(state);
-				let mut cell_x = bounds.x + row_num_w - h_scroll;
-				for (col_idx, field) in self.data_frame.get_column_names().iter().enumerate() {
+			let pinned = self.pinned_col_count();
+			let pinned_w = self.pinned_width(state);
+			let edges = self.col_left_edges(state);
+			let scroll_start = edges.get(pinned).copied().unwrap_or_else(|| self.total_content_width(state));
+			let group_h = self.group_row_height();
+			let label_top = self.label_top(bounds);
+			let draw_header = |renderer: &mut Renderer, cols: Range<usize>, scroll: f32| {
+				let col_widths = self.visible_col_widths(state);
+				let names = self.data_frame.get_column_names();
+				let mut group_start = 0;
+				for group in self.column_groups {
+					let group_end = (group_start + group.span).min(self.col_count());
+					if group_start < group_end && group_start < cols.end && group_end > cols.start {
+						let edge_x = |col: usize| {
+							bounds.x + row_num_w
+								+ edges.get(col).copied().unwrap_or_else(|| self.total_content_width(state))
+								- scroll
+						};
+						let cell = Rectangle {
+							x: edge_x(group_start),
+							y: bounds.y,
+							width: edge_x(group_end) - edge_x(group_start),
+							height: group_h,
+						};
+						renderer.fill_quad(
+							renderer::Quad {
+								bounds: cell,
+								border: border::Border {
+									color: self.divider_color(),
+									width: 1.0,
+									radius: 0.0.into(),
+								},
+								..renderer::Quad::default()
+							},
+							self.theme.group_header_background,
+						);
+						draw_text(
+							renderer,
+							&group.label,
+							Rectangle {
+								x: cell.x + CELL_PADDING_X,
+								width: (cell.width - CELL_PADDING_X * 2.0).max(0.0),
+								..cell
+							},
+							self.theme.header_text,
+							self.text_font(true, self.font_size),
+							Horizontal::Center,
+						);
+					}
+					group_start = group_end;
+				}
+				let mut cell_x = bounds.x + row_num_w + edges.get(cols.start).copied().unwrap_or(scroll_start) - scroll;
+				for (col_idx, field) in names.iter().enumerate().take(cols.end).skip(cols.start) {
 					let col_w = col_widths[col_idx];
-					if cell_x + col_w >= bounds.x + row_num_w
+					if !self.is_hidden(col_idx)
+						&& cell_x + col_w >= bounds.x + row_num_w
 						&& cell_x <= bounds.x + row_num_w + viewport_w
 					{
 						if col_idx > 0 {
@@ -646,45 +3325,189 @@ where
 								renderer::Quad {
 									bounds: Rectangle {
 										x: cell_x,
-										y: bounds.y,
+										y: label_top,
 										width: 1.0,
-										height: header_h,
+										height: header_h - group_h,
 									},
 									..renderer::Quad::default()
 								},
-								colors::TABLE_BORDER,
+								self.divider_color(),
 							);
 						}
+						let menu_button = self.column_menu_button
+							&& self.on_column_menu.is_some()
+							&& state.hovered_header_col == Some(col_idx);
+						let arrow_right = if menu_button { COLUMN_MENU_BUTTON_WIDTH } else { CELL_PADDING_X };
+						let sort_order = state.sort_col.filter(|&(sorted_col, _)| sorted_col == col_idx).map(|(_, order)| order);
+						// The arrow gets its own slot so the centred label never runs under it.
+						let arrow_w = if sort_order.is_some() { SORT_ARROW_WIDTH } else { 0.0 };
+						let (label_left, label_w) = match self.sort_indicator_position {
+							SortPosition::Left => (cell_x + CELL_PADDING_X + arrow_w, col_w - CELL_PADDING_X - arrow_w),
+							SortPosition::Right if sort_order.is_some() => {
+								(cell_x + CELL_PADDING_X, col_w - CELL_PADDING_X - arrow_w - arrow_right)
+							}
+							SortPosition::Right => (cell_x + CELL_PADDING_X, col_w - CELL_PADDING_X),
+						};
 						draw_text(
 							renderer,
 							field.as_str(),
 							Rectangle {
-								x: cell_x + CELL_PADDING_X,
-								y: bounds.y,
-								width: col_w - CELL_PADDING_X,
-								height: HEADER_HEIGHT,
+								x: label_left,
+								y: label_top,
+								width: label_w.max(0.0),
+								height: self.name_row_height(),
 							},
-							colors::TABLE_TEXT_HEADER,
-							true,
+							self.theme.header_text,
+							self.text_font(true, self.font_size),
 							Horizontal::Center,
 						);
+						if let Some(order) = sort_order {
+							draw_text(
+								renderer,
+								order.arrow(),
+								Rectangle {
+									x: match self.sort_indicator_position {
+										SortPosition::Left => cell_x + CELL_PADDING_X,
+										SortPosition::Right => cell_x + col_w - arrow_right - arrow_w,
+									},
+									y: label_top,
+									width: arrow_w,
+									height: self.name_row_height(),
+								},
+								self.theme.header_text,
+								self.text_font(false, SORT_ARROW_FONT_SIZE),
+								Horizontal::Center,
+							);
+						}
+						if menu_button {
+							draw_text(
+								renderer,
+								"⋮",
+								Rectangle {
+									x: cell_x + col_w - COLUMN_MENU_BUTTON_WIDTH,
+									y: label_top,
+									width: COLUMN_MENU_BUTTON_WIDTH,
+									height: self.name_row_height(),
+								},
+								self.theme.header_text,
+								self.text_font(true, self.font_size),
+								Horizontal::Center,
+							);
+						}
+						if state.focused_col == Some(col_idx) {
+							renderer.fill_quad(
+								renderer::Quad {
+									bounds: Rectangle {
+										x: cell_x + 1.0,
+										y: label_top + 1.0,
+										width: col_w - 2.0,
+										height: self.label_height() - 2.0,
+									},
+									border: border::Border {
+										color: self.theme.focused_header,
+										width: 2.0,
+										radius: 2.0.into(),
+									},
+									..renderer::Quad::default()
+								},
+								Color::TRANSPARENT,
+							);
+						}
 						if self.show_column_types
 							&& let Some(col) = self.data_frame.columns().get(col_idx) {
 								let dtype_label = format!("{}", col.dtype());
-								draw_text_sized(
+								draw_text(
 									renderer,
 									&dtype_label,
 									Rectangle {
 										x: cell_x + CELL_PADDING_X,
-										y: bounds.y + HEADER_HEIGHT,
+										y: label_top + self.name_row_height(),
 										width: col_w - CELL_PADDING_X,
-										height: header_h - HEADER_HEIGHT,
+										height: self.label_height() - self.name_row_height(),
+									},
+									self.theme.type_label,
+									self.text_font(false, self.scaled(TYPE_LABEL_FONT_SIZE)),
+									Horizontal::Center,
+								);
+							}
+						if self.filter_row_shown() {
+							let input = filter_input_rect(cell_x, col_w, label_top + self.label_height());
+							let is_focused = state.focused_filter == Some(col_idx);
+							renderer.fill_quad(
+								renderer::Quad {
+									bounds: input,
+									border: border::Border {
+										color: if is_focused {
+											self.theme.filter_border_focused
+										} else {
+											self.theme.filter_border
+										},
+										width: 1.0,
+										radius: 3.0.into(),
+									},
+									..renderer::Quad::default()
+								},
+								self.theme.filter_background,
+							);
+							let value = self.col_filter(col_idx);
+							let font = self.text_font(false, FILTER_FONT_SIZE);
+							let text_rect = filter_text_rect(input);
+							if is_focused {
+								let mut caret = state.filter_caret.min(value.len());
+								while !value.is_char_boundary(caret) {
+									caret -= 1;
+								}
+								let scroll = filter_text_scroll::<Renderer>(value, caret, input, font);
+								renderer.fill_text(
+									Text {
+										content: value.to_string(),
+										bounds: Size::new(f32::INFINITY, text_rect.height),
+										size: Pixels(font.size),
+										font: font.font,
+										align_x: Horizontal::Left.into(),
+										align_y: Vertical::Center,
+										line_height: text::LineHeight::default(),
+										shaping: font.shaping,
+										wrapping: text::Wrapping::None,
 									},
-									colors::TABLE_TYPE_LABEL,
-									TYPE_LABEL_FONT_SIZE,
+									Point::new(text_rect.x - scroll, text_rect.center_y()),
+									self.theme.filter_text,
+									text_rect,
+								);
+								let caret_x = text_rect.x + measure_text_width::<Renderer>(&value[..caret], font) - scroll;
+								renderer.fill_quad(
+									renderer::Quad {
+										bounds: Rectangle {
+											x: caret_x,
+											y: input.y + 4.0,
+											width: 1.0,
+											height: (input.height - 8.0).max(0.0),
+										},
+										..renderer::Quad::default()
+									},
+									self.theme.filter_text,
+								);
+							} else if value.is_empty() {
+								draw_text(renderer, "Filter", text_rect, self.theme.filter_placeholder, font, Horizontal::Left);
+							} else {
+								draw_text(renderer, value, text_rect, self.theme.filter_text, font, Horizontal::Left);
+							}
+							if !value.is_empty() {
+								draw_text(
+									renderer,
+									"×",
+									Rectangle {
+										x: input.x + input.width - FILTER_CLEAR_WIDTH,
+										y: input.y,
+										width: FILTER_CLEAR_WIDTH,
+										height: input.height,
+									},
+									self.theme.filter_text,
+									self.text_font(false, FONT_SIZE),
 									Horizontal::Center,
 								);
 							}
+						}
 					}
 					cell_x += col_w;
 				}
@@ -692,74 +3515,105 @@ where
 					renderer::Quad {
 						bounds: Rectangle {
 							x: cell_x,
-							y: bounds.y,
+							y: label_top,
 							width: 1.0,
-							height: header_h,
+							height: header_h - group_h,
 						},
 						..renderer::Quad::default()
 					},
-					colors::TABLE_BORDER,
+					self.divider_color(),
 				);
-			});
+			};
+			let scroll_header_clip = Rectangle {
+				x: header_clip.x + pinned_w,
+				width: (header_clip.width - pinned_w).max(0.0),
+				..header_clip
+			};
+			if !self.hide_header {
+				renderer.with_layer(scroll_header_clip, |renderer| {
+					draw_header(renderer, pinned..self.col_count(), h_scroll);
+				});
+			}
+			if pinned > 0 && !self.hide_header {
+				renderer.with_layer(Rectangle { width: pinned_w, ..header_clip }, |renderer| {
+					draw_header(renderer, 0..pinned, 0.0);
+				});
+			}
 			renderer.fill_quad(
 				renderer::Quad {
 					bounds: Rectangle {
 						x: bounds.x,
 						y: bounds.y + header_h - 1.0,
-						width: bounds.width - V_SCROLLBAR_WIDTH,
+						width: bounds.width - self.v_scrollbar_width(),
 						height: 1.0,
 					},
 					..renderer::Quad::default()
 				},
-				colors::TABLE_BORDER,
+				self.divider_color(),
 			);
-			let first_visible = (v_scroll / ROW_HEIGHT as f64).floor() as usize;
-			let visible_count = ((bounds.height - header_h) / ROW_HEIGHT).ceil() as usize + 1;
-			let loaded = self.loaded_row_count();
-			let first_visible_y = bounds.y
-				+ header_h
-				+ (first_visible as f64 * ROW_HEIGHT as f64 - v_scroll) as f32;
+			let first_visible = self.row_at(state, v_scroll);
+			let data_bottom = bounds.y + bounds.height - self.h_scrollbar_height();
+			let pinned_rows = self.pinned_row_range();
+			let pinned_h = self.pinned_rows_height(state);
+			let pinned_top = data_bottom - pinned_h;
+			let body_end = pinned_rows.start.min(first_visible + self.max_rendered_rows);
+			debug_assert!(
+				body_end == pinned_rows.start
+					|| bounds.y + header_h + (self.row_top(state, body_end) - v_scroll) as f32 >= data_bottom,
+				"Table::max_rendered_rows ({}) cut off visible rows",
+				self.max_rendered_rows,
+			);
+			let body_rows = first_visible..body_end;
+			let body_origin = bounds.y + header_h + (self.row_top(state, first_visible) - v_scroll) as f32;
 			let row_num_clip = Rectangle {
 				x: bounds.x,
 				y: bounds.y + header_h,
 				width: row_num_w,
-				height: bounds.height - header_h - H_SCROLLBAR_HEIGHT,
+				height: bounds.height - header_h - self.h_scrollbar_height(),
 			};
-			renderer.with_layer(row_num_clip, |renderer| {
-				for row_offset in 0..=visible_count {
-					let row_idx = first_visible + row_offset;
-					if row_idx >= loaded {
+			let draw_row_numbers = |renderer: &mut Renderer, rows: Range<usize>, origin_y: f32| {
+				let rows_top = self.row_top(state, rows.start);
+				for row_idx in rows {
+					let row_y = origin_y + (self.row_top(state, row_idx) - rows_top) as f32;
+					if row_y > data_bottom {
 						break;
 					}
-					let row_y = first_visible_y + row_offset as f32 * ROW_HEIGHT;
-					if row_y + ROW_HEIGHT < bounds.y + header_h {
+					let row_h = self.row_height_of(state, row_idx);
+					if row_y + row_h < bounds.y + header_h {
 						continue;
 					}
-					let abs_idx = self.row_offset + row_idx;
+					let abs_idx = self.abs_offset() + row_idx;
+					let row_num_bg = if state.selected_rows.contains(&abs_idx) {
+						self.theme.row_selected
+					} else {
+						self.theme.header_background
+					};
 					renderer.fill_quad(
 						renderer::Quad {
 							bounds: Rectangle {
 								x: bounds.x,
 								y: row_y,
 								width: row_num_w,
-								height: ROW_HEIGHT,
+								height: row_h,
 							},
 							..renderer::Quad::default()
 						},
-						colors::BG_SECONDARY,
+						row_num_bg,
 					);
-					renderer.fill_quad(
-						renderer::Quad {
-							bounds: Rectangle {
-								x: bounds.x,
-								y: row_y + ROW_HEIGHT - 1.0,
-								width: row_num_w,
-								height: 1.0,
+					if let Some((color, line_w)) = self.row_divider() {
+						renderer.fill_quad(
+							renderer::Quad {
+								bounds: Rectangle {
+									x: bounds.x,
+									y: row_y + row_h - line_w,
+									width: row_num_w,
+									height: line_w,
+								},
+								..renderer::Quad::default()
 							},
-							..renderer::Quad::default()
-						},
-						colors::TABLE_BORDER,
-					);
+							color,
+						);
+					}
 					draw_text(
 						renderer,
 						&(abs_idx + 1).to_string(),
@@ -767,37 +3621,55 @@ where
 							x: bounds.x + CELL_PADDING_X,
 							y: row_y,
 							width: row_num_w - CELL_PADDING_X,
-							height: ROW_HEIGHT,
+							height: row_h,
 						},
-						colors::TABLE_TEXT_HEADER,
-						true,
+						self.theme.header_text,
+						self.text_font(true, self.font_size),
 						Horizontal::Left,
 					);
 				}
-			});
+			};
+			renderer.with_layer(
+				Rectangle {
+					height: row_num_clip.height - pinned_h,
+					..row_num_clip
+				},
+				|renderer| draw_row_numbers(renderer, body_rows.clone(), body_origin),
+			);
+			if pinned_h > 0.0 {
+				renderer.with_layer(
+					Rectangle {
+						y: pinned_top,
+						height: pinned_h,
+						..row_num_clip
+					},
+					|renderer| draw_row_numbers(renderer, pinned_rows.clone(), pinned_top),
+				);
+			}
 			let rows_clip = Rectangle {
 				x: bounds.x + row_num_w,
 				y: bounds.y + header_h,
 				width: viewport_w,
-				height: bounds.height - header_h - H_SCROLLBAR_HEIGHT,
+				height: bounds.height - header_h - self.h_scrollbar_height(),
 			};
-			renderer.with_layer(rows_clip, |renderer| {
-				let col_widths = self.col_widths_ref(state);
-				for row_offset in 0..=visible_count {
-					let row_idx = first_visible + row_offset;
-					if row_idx >= loaded {
+			let draw_rows = |renderer: &mut Renderer, rows: Range<usize>, origin_y: f32, cols: Range<usize>, scroll: f32| {
+				let col_widths = self.visible_col_widths(state);
+				let rows_top = self.row_top(state, rows.start);
+				for row_idx in rows {
+					let row_y = origin_y + (self.row_top(state, row_idx) - rows_top) as f32;
+					if row_y > data_bottom {
 						break;
 					}
-					let row_y = first_visible_y + row_offset as f32 * ROW_HEIGHT;
-					if row_y + ROW_HEIGHT < bounds.y + header_h {
+					let row_h = self.row_height_of(state, row_idx);
+					if row_y + row_h < bounds.y + header_h {
 						continue;
 					}
-					let abs_idx = self.row_offset + row_idx;
+					let abs_idx = self.abs_offset() + row_idx;
 
-					let row_bg = if abs_idx.is_multiple_of(2) {
-						colors::TABLE_ROW_EVEN
+					let row_bg = if state.selected_rows.contains(&abs_idx) {
+						self.theme.row_selected
 					} else {
-						colors::TABLE_ROW_ODD
+						self.row_background(abs_idx)
 					};
 					renderer.fill_quad(
 						renderer::Quad {
@@ -805,41 +3677,119 @@ where
 								x: bounds.x + row_num_w,
 								y: row_y,
 								width: viewport_w,
-								height: ROW_HEIGHT,
+								height: row_h,
 							},
 							..renderer::Quad::default()
 						},
 						row_bg,
 					);
-					renderer.fill_quad(
-						renderer::Quad {
-							bounds: Rectangle {
-								x: bounds.x + row_num_w,
-								y: row_y + ROW_HEIGHT - 1.0,
-								width: viewport_w,
-								height: 1.0,
+					if let Some(highlight_row) = &self.highlight_row
+						&& let Some(tint) = highlight_row(abs_idx)
+					{
+						renderer.fill_quad(
+							renderer::Quad {
+								bounds: Rectangle {
+									x: bounds.x + row_num_w,
+									y: row_y,
+									width: viewport_w,
+									height: row_h,
+								},
+								..renderer::Quad::default()
 							},
-							..renderer::Quad::default()
-						},
-						colors::TABLE_BORDER,
-					);
-					let mut cell_x = bounds.x + row_num_w - h_scroll;
-					for (col_idx, &col_w) in col_widths.iter().enumerate() {
-						if cell_x + col_w >= bounds.x + row_num_w
+							tint,
+						);
+					}
+					if let Some((color, line_w)) = self.row_divider() {
+						renderer.fill_quad(
+							renderer::Quad {
+								bounds: Rectangle {
+									x: bounds.x + row_num_w,
+									y: row_y + row_h - line_w,
+									width: viewport_w,
+									height: line_w,
+								},
+								..renderer::Quad::default()
+							},
+							color,
+						);
+					}
+					let mut cell_x =
+						bounds.x + row_num_w + edges.get(cols.start).copied().unwrap_or(scroll_start) - scroll;
+					for (col_idx, &col_w) in col_widths.iter().enumerate().take(cols.end).skip(cols.start) {
+						if !self.is_hidden(col_idx)
+							&& cell_x + col_w >= bounds.x + row_num_w
 							&& cell_x <= bounds.x + row_num_w + viewport_w
 						{
-							if col_idx > 0 {
+							if self.alternating_col_background && col_idx.is_multiple_of(2) {
+								renderer.fill_quad(
+									renderer::Quad {
+										bounds: Rectangle {
+											x: cell_x + 1.0,
+											y: row_y,
+											width: col_w - 1.0,
+											height: row_h - 1.0,
+										},
+										..renderer::Quad::default()
+									},
+									ALTERNATING_COL_TINT,
+								);
+							}
+							if col_idx > 0 && let Some((color, line_w)) = self.col_divider() {
 								renderer.fill_quad(
 									renderer::Quad {
 										bounds: Rectangle {
 											x: cell_x,
 											y: row_y,
-											width: 1.0,
-											height: ROW_HEIGHT,
+											width: line_w,
+											height: row_h,
+										},
+										..renderer::Quad::default()
+									},
+									color,
+								);
+							}
+							if let Some(cell_color) = &self.cell_color
+								&& let Some(color) = cell_color(abs_idx, col_idx, &self.cell_str(col_idx, row_idx))
+							{
+								renderer.fill_quad(
+									renderer::Quad {
+										bounds: Rectangle {
+											x: cell_x + 1.0,
+											y: row_y,
+											width: col_w - 1.0,
+											height: row_h - 1.0,
+										},
+										..renderer::Quad::default()
+									},
+									color,
+								);
+							}
+							if let Some(tint) = self.compare_tint(col_idx, row_idx) {
+								renderer.fill_quad(
+									renderer::Quad {
+										bounds: Rectangle {
+											x: cell_x + 1.0,
+											y: row_y,
+											width: col_w - 1.0,
+											height: row_h - 1.0,
+										},
+										..renderer::Quad::default()
+									},
+									tint,
+								);
+							}
+							if self.search_hits.binary_search(&(abs_idx, col_idx)).is_ok() {
+								renderer.fill_quad(
+									renderer::Quad {
+										bounds: Rectangle {
+											x: cell_x,
+											y: row_y,
+											width: col_w,
+											height: row_h,
 										},
 										..renderer::Quad::default()
 									},
-									colors::TABLE_BORDER,
+									self.theme.search_hit,
 								);
 							}
 							if state
@@ -853,58 +3803,311 @@ where
 											x: cell_x,
 											y: row_y,
 											width: col_w,
-											height: ROW_HEIGHT,
+											height: row_h,
+										},
+										..renderer::Quad::default()
+									},
+									self.theme.selection,
+								);
+							}
+							if let Some((color, line_w)) = self.selected_cell_border
+								&& state.focused_cell == Some((col_idx, row_idx))
+							{
+								for edge in [
+									Rectangle { x: cell_x, y: row_y, width: col_w, height: line_w },
+									Rectangle { x: cell_x, y: row_y + row_h - line_w, width: col_w, height: line_w },
+									Rectangle { x: cell_x, y: row_y, width: line_w, height: row_h },
+									Rectangle { x: cell_x + col_w - line_w, y: row_y, width: line_w, height: row_h },
+								] {
+									renderer.fill_quad(
+										renderer::Quad {
+											bounds: edge,
+											..renderer::Quad::default()
+										},
+										color,
+									);
+								}
+							}
+							if self.sparkline_cols.contains(&col_idx) {
+								let padding = self.cell_padding;
+								let area = Rectangle {
+									x: cell_x + padding.left,
+									y: row_y + 2.0 + padding.top,
+									width: col_w - padding.x(),
+									height: row_h - 4.0 - padding.y(),
+								};
+								self.draw_sparkline(renderer, col_idx, row_idx, area);
+								cell_x += col_w;
+								continue;
+							}
+							let cell_image = self
+								.image_source(col_idx, row_idx)
+								.map(|source| self.image_cache.and_then(|cache| cache.get(&source)));
+							if let Some(cell_image) = cell_image
+								&& !matches!(cell_image, Some(CellImage::Failed))
+							{
+								let padding = self.cell_padding;
+								let area = Rectangle {
+									x: cell_x + padding.left,
+									y: row_y + 2.0 + padding.top,
+									width: col_w - padding.x(),
+									height: row_h - 4.0 - padding.y(),
+								};
+								self.draw_cell_image(renderer, cell_image, area);
+								cell_x += col_w;
+								continue;
+							}
+							let text = match self.frame_row(row_idx) {
+								Some(_) => self.cell_shown_str(col_idx, row_idx),
+								None => "…".to_string(),
+							};
+							let alignment = self.column_alignments.get(col_idx).copied().unwrap_or_default();
+							let wrap = self.wraps_rows();
+							let padding = self.cell_padding;
+							let right_padding = if wrap || alignment != ColumnAlignment::Left {
+								padding.right
+							} else {
+								0.0
+							};
+							let text_bounds = Rectangle {
+								x: cell_x + padding.left,
+								y: row_y + padding.top,
+								width: col_w - padding.left - right_padding,
+								height: row_h - padding.y(),
+							};
+							let link = self.url_cell(col_idx, row_idx).is_some();
+							if link && !wrap {
+								let font = self.text_font(false, self.font_size);
+								draw_text(renderer, &text, text_bounds, self.theme.link, font, alignment.into());
+								let text_w = measure_text_width::<Renderer>(&text, font).min(text_bounds.width);
+								let underline_x = match Horizontal::from(alignment) {
+									Horizontal::Left => text_bounds.x,
+									Horizontal::Center => text_bounds.center_x() - text_w / 2.0,
+									Horizontal::Right => text_bounds.x + text_bounds.width - text_w,
+								};
+								renderer.fill_quad(
+									renderer::Quad {
+										bounds: Rectangle {
+											x: underline_x,
+											y: text_bounds.center_y() + self.font_size / 2.0 + 1.0,
+											width: text_w,
+											height: 1.0,
 										},
 										..renderer::Quad::default()
 									},
-									colors::TABLE_SELECTION,
-								);
-							}
-							let text = self.cell_str(col_idx, row_idx);
-							draw_text(
-								renderer,
-								&text,
-								Rectangle {
-									x: cell_x + CELL_PADDING_X,
-									y: row_y,
-									width: col_w - CELL_PADDING_X,
-									height: ROW_HEIGHT,
+									self.theme.link,
+								);
+							} else if !wrap && let Some(value) = self.json_cell(col_idx, row_idx) {
+								draw_json_preview(
+									renderer,
+									&json_preview_segments(&value),
+									text_bounds,
+									(self.theme.json_key, self.theme.text),
+									self.text_font(false, self.font_size),
+								);
+							} else if wrap {
+								draw_wrapped_text(
+									renderer,
+									&text,
+									text_bounds,
+									if link { self.theme.link } else { self.theme.text },
+									self.text_font(false, self.font_size),
+									alignment.into(),
+								);
+							} else {
+								draw_text(
+									renderer,
+									&text,
+									text_bounds,
+									self.theme.text,
+									self.text_font(false, self.font_size),
+									alignment.into(),
+								);
+							}
+						}
+						cell_x += col_w;
+					}
+					if let Some((color, line_w)) = self.col_divider() {
+						renderer.fill_quad(
+							renderer::Quad {
+								bounds: Rectangle {
+									x: cell_x,
+									y: row_y,
+									width: line_w,
+									height: row_h,
+								},
+								..renderer::Quad::default()
+							},
+							color,
+						);
+					}
+				}
+			};
+			let draw_body = |renderer: &mut Renderer, clip: Rectangle, rows: Range<usize>, origin_y: f32| {
+				renderer.with_layer(
+					Rectangle {
+						x: clip.x + pinned_w,
+						width: (clip.width - pinned_w).max(0.0),
+						..clip
+					},
+					|renderer| draw_rows(renderer, rows.clone(), origin_y, pinned..self.col_count(), h_scroll),
+				);
+				if pinned > 0 {
+					renderer.with_layer(Rectangle { width: pinned_w, ..clip }, |renderer| {
+						draw_rows(renderer, rows, origin_y, 0..pinned, 0.0);
+					});
+				}
+			};
+			draw_body(
+				renderer,
+				Rectangle {
+					height: rows_clip.height - pinned_h,
+					..rows_clip
+				},
+				body_rows,
+				body_origin,
+			);
+			if pinned_h > 0.0 {
+				draw_body(
+					renderer,
+					Rectangle {
+						y: pinned_top,
+						height: pinned_h,
+						..rows_clip
+					},
+					pinned_rows,
+					pinned_top,
+				);
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: Rectangle {
+							x: bounds.x,
+							y: pinned_top - 1.0,
+							width: bounds.width - self.v_scrollbar_width(),
+							height: 2.0,
+						},
+						..renderer::Quad::default()
+					},
+					self.divider_color(),
+				);
+			}
+			if let Some(mode) = self.summary_mode {
+				let footer = Rectangle {
+					x: bounds.x,
+					y: data_bottom - self.summary_height(),
+					width: row_num_w + viewport_w,
+					height: self.summary_height(),
+				};
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: footer,
+						..renderer::Quad::default()
+					},
+					self.theme.summary_background,
+				);
+				draw_text(
+					renderer,
+					mode.label(),
+					Rectangle {
+						x: footer.x + CELL_PADDING_X,
+						width: (row_num_w - CELL_PADDING_X).max(0.0),
+						..footer
+					},
+					self.theme.header_text,
+					self.text_font(true, self.font_size),
+					Horizontal::Left,
+				);
+				let col_widths = self.visible_col_widths(state);
+				let draw_summary = |renderer: &mut Renderer, cols: Range<usize>| {
+					for col_idx in cols {
+						let col_w = col_widths[col_idx];
+						if col_w <= 0.0 {
+							continue;
+						}
+						let cell_x = self.col_screen_left(state, &edges, bounds.x + row_num_w, col_idx);
+						if let Some((color, line_w)) = self.col_divider() {
+							renderer.fill_quad(
+								renderer::Quad {
+									bounds: Rectangle {
+										x: cell_x,
+										width: line_w,
+										..footer
+									},
+									..renderer::Quad::default()
 								},
-								colors::TEXT_PRIMARY,
-								false,
-								Horizontal::Left,
+								color,
 							);
 						}
-						cell_x += col_w;
-					}
-					renderer.fill_quad(
-						renderer::Quad {
-							bounds: Rectangle {
-								x: cell_x,
-								y: row_y,
-								width: 1.0,
-								height: ROW_HEIGHT,
+						let value = match state.col_summaries.get(col_idx).copied().flatten() {
+							Some(value) => self.summary_str(col_idx, value),
+							None => "—".to_string(),
+						};
+						draw_text(
+							renderer,
+							&value,
+							Rectangle {
+								x: cell_x + self.cell_padding.left,
+								width: col_w - self.cell_padding.x(),
+								..footer
 							},
-							..renderer::Quad::default()
-						},
-						colors::TABLE_BORDER,
-					);
+							self.theme.header_text,
+							self.text_font(true, self.font_size),
+							Horizontal::Right,
+						);
+					}
+				};
+				let values_clip = Rectangle {
+					x: footer.x + row_num_w,
+					width: viewport_w,
+					..footer
+				};
+				renderer.with_layer(
+					Rectangle {
+						x: values_clip.x + pinned_w,
+						width: (viewport_w - pinned_w).max(0.0),
+						..values_clip
+					},
+					|renderer| draw_summary(renderer, pinned..self.col_count()),
+				);
+				if pinned > 0 {
+					renderer.with_layer(Rectangle { width: pinned_w, ..values_clip }, |renderer| {
+						draw_summary(renderer, 0..pinned);
+					});
 				}
-			});
-			let total_h = self.total_content_height();
-			if total_h > bounds.height {
-				let thumb = self.v_scrollbar_thumb_rect(bounds, v_scroll);
+			}
+			if pinned > 0 {
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: Rectangle {
+							x: bounds.x + row_num_w + pinned_w - 1.0,
+							y: bounds.y,
+							width: 2.0,
+							height: bounds.height - self.h_scrollbar_height(),
+						},
+						..renderer::Quad::default()
+					},
+					self.divider_color(),
+				);
+			}
+			let total_h = self.total_content_height(state);
+			let thumb_color = self.scrollbar_color(state);
+			if let Some(thumb_color) = thumb_color
+				&& total_h > bounds.height
+			{
+				let thumb = self.v_scrollbar_thumb_rect(bounds, state.v_scroll_offset, state);
 				renderer.fill_quad(
 					renderer::Quad {
 						bounds: thumb,
 						border: border::rounded(2),
 						..renderer::Quad::default()
 					},
-					colors::SCROLLBAR_THUMB,
+					thumb_color,
 				);
 			}
 			let total_w = self.total_content_width(state);
-			if total_w > viewport_w {
+			if let Some(thumb_color) = thumb_color
+				&& total_w > viewport_w
+			{
 				let h_thumb =
 					self.h_scrollbar_thumb_rect(bounds, state.h_scroll_offset, state, row_num_w);
 				renderer.fill_quad(
@@ -913,31 +4116,405 @@ where
 						border: border::rounded(2),
 						..renderer::Quad::default()
 					},
-					colors::SCROLLBAR_THUMB,
+					thumb_color,
+				);
+			}
+			let data_area = Rectangle {
+				x: bounds.x + row_num_w,
+				y: bounds.y + header_h,
+				width: viewport_w,
+				height: bounds.height - header_h - self.h_scrollbar_height(),
+			};
+			self.draw_empty_message(renderer, data_area);
+			self.draw_loading(renderer, bounds, data_area);
+			if state.is_focused {
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds,
+						border: border::Border {
+							color: self.theme.focus_border,
+							width: 1.0,
+							radius: 4.0.into(),
+						},
+						..renderer::Quad::default()
+					},
+					Color::TRANSPARENT,
+				);
+			}
+			if let Some(from) = state.reorder_col {
+				let slot = self.reorder_slot_at(state, bounds, state.reorder_drag_x, row_num_w);
+				let line_x = self
+					.col_screen_left(state, &edges, bounds.x + row_num_w, slot)
+					.clamp(bounds.x + row_num_w, bounds.x + row_num_w + viewport_w - 2.0);
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: Rectangle {
+							x: line_x - 1.0,
+							y: bounds.y,
+							width: 2.0,
+							height: bounds.height - self.h_scrollbar_height(),
+						},
+						..renderer::Quad::default()
+					},
+					self.theme.drop_indicator,
+				);
+				let col_w = state.col_widths[from];
+				let ghost = Rectangle {
+					x: state.reorder_drag_x - col_w / 2.0,
+					y: bounds.y,
+					width: col_w,
+					height: header_h,
+				};
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: ghost,
+						border: border::rounded(2),
+						..renderer::Quad::default()
+					},
+					self.theme.reorder_ghost,
+				);
+				if let Some(field) = self.data_frame.get_column_names().get(from) {
+					draw_text(
+						renderer,
+						field.as_str(),
+						Rectangle {
+							x: ghost.x + CELL_PADDING_X,
+							y: ghost.y,
+							width: col_w - CELL_PADDING_X,
+							height: self.name_row_height(),
+						},
+						self.theme.header_text,
+						self.text_font(true, self.font_size),
+						Horizontal::Center,
+					);
+				}
+			}
+			if state.reorder_row.is_some() {
+				let slot = self.row_reorder_slot_at(state, bounds, state.reorder_drag_y);
+				let line_y = (bounds.y + header_h + (self.row_top(state, slot) - v_scroll) as f32)
+					.clamp(bounds.y + header_h, data_bottom - 2.0);
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: Rectangle {
+							x: bounds.x,
+							y: line_y - 1.0,
+							width: bounds.width - self.v_scrollbar_width(),
+							height: 2.0,
+						},
+						..renderer::Quad::default()
+					},
+					self.theme.drop_indicator,
 				);
 			}
+			if let Some(indicator) = self.resize_indicator
+				&& let Some(col_idx) = state.resizing_col.or(state.hovered_divider)
+			{
+				let col_left = self.col_screen_left(state, &edges, bounds.x + row_num_w, col_idx);
+				let line = Rectangle {
+					x: col_left + state.col_widths[col_idx] - indicator.width / 2.0,
+					y: bounds.y,
+					width: indicator.width,
+					height: bounds.height - self.h_scrollbar_height(),
+				};
+				renderer.fill_quad(
+					renderer::Quad {
+						bounds: line,
+						..renderer::Quad::default()
+					},
+					indicator.color,
+				);
+				if indicator.show_ghost
+					&& state.resizing_col.is_some()
+					&& let Some(pos) = cursor.position()
+				{
+					let ghost_x = col_left + state.resize_drag_start_width + pos.x - state.resize_drag_start_x;
+					let mut dash_y = line.y;
+					while dash_y < line.y + line.height {
+						renderer.fill_quad(
+							renderer::Quad {
+								bounds: Rectangle {
+									x: ghost_x - indicator.width / 2.0,
+									y: dash_y,
+									width: indicator.width,
+									height: RESIZE_GHOST_DASH.min(line.y + line.height - dash_y),
+								},
+								..renderer::Quad::default()
+							},
+							indicator.color,
+						);
+						dash_y += RESIZE_GHOST_DASH + RESIZE_GHOST_GAP;
+					}
+				}
+			}
 		});
 	}
 }
 
+pub fn cell_to_string(series: &Column, row_idx: usize) -> String {
+	if row_idx >= series.len() {
+		return String::new();
+	}
+	match series.get(row_idx) {
+		Ok(AnyValue::Null) | Err(_) => String::new(),
+		Ok(AnyValue::String(s)) => s.to_string(),
+		Ok(AnyValue::StringOwned(s)) => s.to_string(),
+		Ok(v) => format!("{v}"),
+	}
+}
+
+/// Display form of a cell: grouped digits, ISO 8601 timestamps and JSON for
+/// nested values. Copy, search and export keep using `cell_to_string`.
+pub fn cell_display_string(series: &Column, row_idx: usize) -> String {
+	if row_idx >= series.len() {
+		return String::new();
+	}
+	match series.get(row_idx) {
+		Ok(AnyValue::Null) | Err(_) => String::new(),
+		Ok(AnyValue::String(s)) => s.to_string(),
+		Ok(AnyValue::StringOwned(s)) => s.to_string(),
+		Ok(AnyValue::Datetime(ts, unit, _)) => iso_datetime(ts, unit).unwrap_or_else(|| ts.to_string()),
+		Ok(v @ (AnyValue::List(_) | AnyValue::Struct(..) | AnyValue::StructOwned(_))) => json_value(&v),
+		Ok(v) if v.dtype().is_numeric() => group_digits(&format!("{v}")),
+		Ok(v) => format!("{v}"),
+	}
+}
+
+/// Aggregates the first `rows` values of a column. Empty cells are skipped,
+/// and anything but a count is `None` when most remaining cells aren't numbers.
+fn summarize(column: &Column, rows: usize, mode: SummaryMode) -> Option<f64> {
+	let (mut count, mut failed, mut sum) = (0usize, 0usize, 0.0f64);
+	let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+	for row_idx in 0..rows {
+		let cell = cell_to_string(column, row_idx);
+		if cell.is_empty() {
+			continue;
+		}
+		match cell.trim().parse::<f64>() {
+			Ok(number) if number.is_finite() => {
+				count += 1;
+				sum += number;
+				min = min.min(number);
+				max = max.max(number);
+			}
+			_ => failed += 1,
+		}
+	}
+	if mode != SummaryMode::Count && (count == 0 || failed > count) {
+		return None;
+	}
+	Some(match mode {
+		SummaryMode::Sum => sum,
+		SummaryMode::Count => (count + failed) as f64,
+		SummaryMode::Average => sum / count as f64,
+		SummaryMode::Min => min,
+		SummaryMode::Max => max,
+	})
+}
+
+/// `None` when `text` already fits in `max_chars` characters.
+fn truncate_cell(text: &str, max_chars: usize) -> Option<String> {
+	if text.chars().count() <= max_chars {
+		return None;
+	}
+	let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+	truncated.push('…');
+	Some(truncated)
+}
+
+pub fn format_numeric(value: &str, format: &NumericFormat) -> Option<String> {
+	let number: f64 = value.trim().parse().ok().filter(|n: &f64| n.is_finite())?;
+	Some(match format {
+		NumericFormat::Integer => group_digits(&format!("{number:.0}")),
+		NumericFormat::Float(places) => group_digits(&format!("{number:.*}", *places as usize)),
+		NumericFormat::Currency(symbol) => {
+			let amount = group_digits(&format!("{:.2}", number.abs()));
+			if number < 0.0 {
+				format!("-{symbol}{amount}")
+			} else {
+				format!("{symbol}{amount}")
+			}
+		}
+		NumericFormat::Percentage(places) => {
+			format!("{}%", group_digits(&format!("{:.*}", *places as usize, number * 100.0)))
+		}
+	})
+}
+
+fn group_digits(number: &str) -> String {
+	let (sign, unsigned) = match number.strip_prefix('-') {
+		Some(rest) => ("-", rest),
+		None => ("", number),
+	};
+	let int_len = unsigned.find(|c: char| !c.is_ascii_digit()).unwrap_or(unsigned.len());
+	let (digits, rest) = unsigned.split_at(int_len);
+	if digits.is_empty() || !(rest.is_empty() || rest.starts_with('.')) || rest.contains(['e', 'E']) {
+		return number.to_string();
+	}
+	let mut out = String::with_capacity(number.len() + digits.len() / 3);
+	out.push_str(sign);
+	for (i, ch) in digits.chars().enumerate() {
+		if i > 0 && (digits.len() - i).is_multiple_of(3) {
+			out.push(',');
+		}
+		out.push(ch);
+	}
+	out.push_str(rest);
+	out
+}
+
+fn iso_datetime(ts: i64, unit: TimeUnit) -> Option<String> {
+	let datetime = match unit {
+		TimeUnit::Nanoseconds => Some(chrono::DateTime::from_timestamp_nanos(ts)),
+		TimeUnit::Microseconds => chrono::DateTime::from_timestamp_micros(ts),
+		TimeUnit::Milliseconds => chrono::DateTime::from_timestamp_millis(ts),
+	}?;
+	Some(datetime.naive_utc().format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+}
+
+fn json_value(value: &AnyValue) -> String {
+	match value {
+		AnyValue::Null => "null".to_string(),
+		AnyValue::Boolean(b) => b.to_string(),
+		AnyValue::String(s) => json_string(s),
+		AnyValue::StringOwned(s) => json_string(s),
+		AnyValue::Datetime(ts, unit, _) => json_string(&iso_datetime(*ts, *unit).unwrap_or_else(|| ts.to_string())),
+		AnyValue::List(series) => {
+			let items: Vec<String> = series.iter().map(|item| json_value(&item)).collect();
+			format!("[{}]", items.join(","))
+		}
+		AnyValue::Struct(..) => json_value(&value.clone().into_static()),
+		AnyValue::StructOwned(payload) => {
+			let (values, fields) = &**payload;
+			let entries: Vec<String> = fields
+				.iter()
+				.zip(values)
+				.map(|(field, item)| format!("{}:{}", json_string(field.name()), json_value(item)))
+				.collect();
+			format!("{{{}}}", entries.join(","))
+		}
+		v if v.dtype().is_numeric() => format!("{v}"),
+		v => json_string(&format!("{v}")),
+	}
+}
+
+fn json_string(s: &str) -> String {
+	let mut out = String::with_capacity(s.len() + 2);
+	out.push('"');
+	for ch in s.chars() {
+		match ch {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+pub fn find_cells(data_frame: &DataFrame, query: &str) -> Vec<(usize, usize)> {
+	let needle = query.to_lowercase();
+	let columns = data_frame.columns();
+	let mut hits = Vec::new();
+	for row_idx in 0..data_frame.height() {
+		for (col_idx, series) in columns.iter().enumerate() {
+			if cell_to_string(series, row_idx).to_lowercase().contains(&needle) {
+				hits.push((row_idx, col_idx));
+			}
+		}
+	}
+	hits
+}
+
+/// Rows of column `col_idx` whose cell text satisfies `matches`.
+pub fn column_matches(data_frame: &DataFrame, col_idx: usize, matches: impl Fn(&str) -> bool) -> Vec<usize> {
+	let Some(series) = data_frame.columns().get(col_idx) else {
+		return Vec::new();
+	};
+	(0..data_frame.height())
+		.filter(|&row| matches(&cell_to_string(series, row)))
+		.collect()
+}
+
+fn filter_input_rect(cell_x: f32, col_w: f32, top: f32) -> Rectangle {
+	Rectangle {
+		x: cell_x + 3.0,
+		y: top + 3.0,
+		width: (col_w - 6.0).max(0.0),
+		height: FILTER_ROW_HEIGHT - 6.0,
+	}
+}
+
+/// Part of a filter input that shows its text, left of the clear button.
+fn filter_text_rect(input: Rectangle) -> Rectangle {
+	Rectangle {
+		x: input.x + FILTER_TEXT_INSET,
+		width: (input.width - FILTER_TEXT_INSET - FILTER_CLEAR_WIDTH).max(0.0),
+		..input
+	}
+}
+
+/// How far the filter text is shifted left so the caret stays in view.
+fn filter_text_scroll<Renderer>(value: &str, caret: usize, input: Rectangle, font: TextFont) -> f32
+where
+	Renderer: TextRenderer<Font = iced::Font>,
+{
+	let caret = caret.min(value.len());
+	if !value.is_char_boundary(caret) {
+		return 0.0;
+	}
+	(measure_text_width::<Renderer>(&value[..caret], font) - filter_text_rect(input).width + 1.0).max(0.0)
+}
+
+/// Indexes of the rows where every non-empty filter matches the cell text. A
+/// filter written as `/pattern/` is a regex; anything else is a case-insensitive
+/// substring.
+pub fn filter_rows(data_frame: &DataFrame, filters: &[String]) -> Result<Vec<IdxSize>, String> {
+	let mut mask = vec![true; data_frame.height()];
+	for (col_idx, filter) in filters.iter().enumerate() {
+		if filter.is_empty() || col_idx >= data_frame.width() {
+			continue;
+		}
+		let rows = if filter.len() > 1
+			&& let Some(pattern) = filter.strip_prefix('/').and_then(|f| f.strip_suffix('/'))
+		{
+			let re = regex::Regex::new(pattern).map_err(|e| e.to_string())?;
+			column_matches(data_frame, col_idx, |cell| re.is_match(cell))
+		} else {
+			let needle = filter.to_lowercase();
+			column_matches(data_frame, col_idx, |cell| cell.to_lowercase().contains(&needle))
+		};
+		let mut matched = vec![false; mask.len()];
+		for row_idx in rows {
+			matched[row_idx] = true;
+		}
+		for (keep, matched) in mask.iter_mut().zip(matched) {
+			*keep &= matched;
+		}
+	}
+	Ok((0..data_frame.height() as IdxSize).filter(|&row| mask[row as usize]).collect())
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TextFont {
+	font: iced::Font,
+	size: f32,
+	shaping: text::Shaping,
+}
+
 fn draw_text<Renderer>(
 	renderer: &mut Renderer,
 	content: &str,
 	cell_bounds: Rectangle,
 	color: Color,
-	is_bold: bool,
+	font: TextFont,
 	align_x: Horizontal,
 ) where
 	Renderer: renderer::Renderer + TextRenderer<Font = iced::Font>,
 {
-	let font = if is_bold {
-		iced::Font {
-			weight: iced::font::Weight::Bold,
-			..iced::Font::DEFAULT
-		}
-	} else {
-		iced::Font::DEFAULT
-	};
 	let x = match align_x {
 		Horizontal::Left => cell_bounds.x,
 		Horizontal::Center => cell_bounds.x + cell_bounds.width / 2.0,
@@ -947,12 +4524,12 @@ fn draw_text<Renderer>(
 		Text {
 			content: content.to_string(),
 			bounds: cell_bounds.size(),
-			size: Pixels(FONT_SIZE),
-			font,
+			size: Pixels(font.size),
+			font: font.font,
 			align_x: align_x.into(),
 			align_y: Vertical::Center,
 			line_height: text::LineHeight::default(),
-			shaping: text::Shaping::Basic,
+			shaping: font.shaping,
 			wrapping: text::Wrapping::None,
 		},
 		Point {
@@ -964,12 +4541,12 @@ fn draw_text<Renderer>(
 	);
 }
 
-fn draw_text_sized<Renderer>(
+fn draw_wrapped_text<Renderer>(
 	renderer: &mut Renderer,
 	content: &str,
 	cell_bounds: Rectangle,
 	color: Color,
-	font_size: f32,
+	font: TextFont,
 	align_x: Horizontal,
 ) where
 	Renderer: renderer::Renderer + TextRenderer<Font = iced::Font>,
@@ -983,13 +4560,13 @@ fn draw_text_sized<Renderer>(
 		Text {
 			content: content.to_string(),
 			bounds: cell_bounds.size(),
-			size: Pixels(font_size),
-			font: iced::Font::DEFAULT,
+			size: Pixels(font.size),
+			font: font.font,
 			align_x: align_x.into(),
 			align_y: Vertical::Center,
 			line_height: text::LineHeight::default(),
-			shaping: text::Shaping::Basic,
-			wrapping: text::Wrapping::None,
+			shaping: font.shaping,
+			wrapping: text::Wrapping::Word,
 		},
 		Point {
 			x,
@@ -1000,11 +4577,209 @@ fn draw_text_sized<Renderer>(
 	);
 }
 
-impl<'a, Message, Theme, Renderer> From<Table<'a>> for Element<'a, Message, Theme, Renderer>
+/// Draws the preview left to right, one run per segment, stopping once the
+/// cell is full.
+fn draw_json_preview<Renderer>(
+	renderer: &mut Renderer,
+	segments: &[(String, bool)],
+	cell_bounds: Rectangle,
+	(key_color, value_color): (Color, Color),
+	font: TextFont,
+) where
+	Renderer: renderer::Renderer + TextRenderer<Font = iced::Font>,
+{
+	let right = cell_bounds.x + cell_bounds.width;
+	let mut x = cell_bounds.x;
+	for (content, is_key) in segments {
+		if x >= right {
+			break;
+		}
+		let bounds = Rectangle {
+			x,
+			width: right - x,
+			..cell_bounds
+		};
+		let color = if *is_key { key_color } else { value_color };
+		draw_text(renderer, content, bounds, color, font, Horizontal::Left);
+		x += measure_text_width::<Renderer>(content, font);
+	}
+}
+
+/// Flattens a JSON value into compact one-line text split into runs, with
+/// `true` marking object keys.
+fn json_preview_segments(value: &serde_json::Value) -> Vec<(String, bool)> {
+	fn push(segments: &mut Vec<(String, bool)>, text: &str, is_key: bool) {
+		match segments.last_mut() {
+			Some((last, last_is_key)) if *last_is_key == is_key => last.push_str(text),
+			_ => segments.push((text.to_string(), is_key)),
+		}
+	}
+	fn walk(segments: &mut Vec<(String, bool)>, value: &serde_json::Value) {
+		match value {
+			serde_json::Value::Object(map) => {
+				push(segments, "{", false);
+				for (i, (key, item)) in map.iter().enumerate() {
+					if i > 0 {
+						push(segments, ", ", false);
+					}
+					push(segments, key, true);
+					push(segments, ": ", false);
+					walk(segments, item);
+				}
+				push(segments, "}", false);
+			}
+			serde_json::Value::Array(items) => {
+				push(segments, "[", false);
+				for (i, item) in items.iter().enumerate() {
+					if i > 0 {
+						push(segments, ", ", false);
+					}
+					walk(segments, item);
+				}
+				push(segments, "]", false);
+			}
+			other => push(segments, &other.to_string(), false),
+		}
+	}
+	let mut segments = Vec::new();
+	walk(&mut segments, value);
+	segments
+}
+
+fn measure_text_width<Renderer>(content: &str, font: TextFont) -> f32
+where
+	Renderer: TextRenderer<Font = iced::Font>,
+{
+	Renderer::Paragraph::with_text(Text {
+		content,
+		bounds: Size::new(f32::INFINITY, f32::INFINITY),
+		size: Pixels(font.size),
+		font: font.font,
+		align_x: Horizontal::Left.into(),
+		align_y: Vertical::Top,
+		line_height: text::LineHeight::default(),
+		shaping: font.shaping,
+		wrapping: text::Wrapping::None,
+	})
+	.min_bounds()
+	.width
+}
+
+fn measure_text_height<Renderer>(content: &str, width: f32, font: TextFont) -> f32
+where
+	Renderer: TextRenderer<Font = iced::Font>,
+{
+	Renderer::Paragraph::with_text(Text {
+		content,
+		bounds: Size::new(width.max(1.0), f32::INFINITY),
+		size: Pixels(font.size),
+		font: font.font,
+		align_x: Horizontal::Left.into(),
+		align_y: Vertical::Top,
+		line_height: text::LineHeight::default(),
+		shaping: font.shaping,
+		wrapping: text::Wrapping::Word,
+	})
+	.min_bounds()
+	.height
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HoverTarget {
+	Cell(usize, usize),
+	Header(usize),
+}
+
+impl HoverTarget {
+	fn delay(self) -> Duration {
+		match self {
+			HoverTarget::Cell(..) => TOOLTIP_DELAY,
+			HoverTarget::Header(_) => HEADER_TOOLTIP_DELAY,
+		}
+	}
+}
+
+struct Tooltip {
+	content: String,
+	anchor: Point,
+	font: TextFont,
+	theme: TableTheme,
+}
+
+impl Tooltip {
+	fn text(&self, bounds: Size) -> Text<&str> {
+		Text {
+			content: &self.content,
+			bounds,
+			size: Pixels(self.font.size),
+			font: self.font.font,
+			align_x: Horizontal::Left.into(),
+			align_y: Vertical::Top,
+			line_height: text::LineHeight::default(),
+			shaping: self.font.shaping,
+			wrapping: text::Wrapping::Word,
+		}
+	}
+}
+
+impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer> for Tooltip
 where
 	Renderer: renderer::Renderer + TextRenderer<Font = iced::Font>,
 {
-	fn from(table: Table<'a>) -> Self {
+	fn layout(&mut self, _renderer: &Renderer, bounds: Size) -> Node {
+		let text_size = Renderer::Paragraph::with_text(self.text(Size::new(TOOLTIP_MAX_WIDTH, f32::INFINITY)))
+			.min_bounds();
+		let size = Size::new(
+			text_size.width + TOOLTIP_PADDING * 2.0,
+			text_size.height + TOOLTIP_PADDING * 2.0,
+		);
+		let below = self.anchor.y + 18.0;
+		let y = if below + size.height > bounds.height {
+			self.anchor.y - 4.0 - size.height
+		} else {
+			below
+		};
+		let x = (self.anchor.x + 12.0).min(bounds.width - size.width);
+		Node::new(size).move_to(Point::new(x.max(0.0), y.max(0.0)))
+	}
+
+	fn draw(
+		&self,
+		renderer: &mut Renderer,
+		_theme: &Theme,
+		_style: &Style,
+		layout: Layout<'_>,
+		_cursor: Cursor,
+	) {
+		let bounds = layout.bounds();
+		renderer.fill_quad(
+			renderer::Quad {
+				bounds,
+				border: border::Border {
+					color: self.theme.filter_border,
+					width: 1.0,
+					radius: 4.0.into(),
+				},
+				..renderer::Quad::default()
+			},
+			self.theme.header_background,
+		);
+		let text_bounds = bounds.shrink(TOOLTIP_PADDING);
+		renderer.fill_text(
+			self.text(text_bounds.size()).with_content(self.content.clone()),
+			text_bounds.position(),
+			self.theme.text,
+			bounds,
+		);
+	}
+}
+
+impl<'a, Message, Theme, Renderer> From<Table<'a, Message>> for Element<'a, Message, Theme, Renderer>
+where
+	Message: 'a,
+	Renderer: renderer::Renderer + TextRenderer<Font = iced::Font> + ImageRenderer<Handle = image::Handle> + 'a,
+{
+	fn from(table: Table<'a, Message>) -> Self {
 		Self::new(table)
 	}
 }
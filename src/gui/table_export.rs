@@ -0,0 +1,296 @@
+use super::table::cell_to_string;
+use chrono::NaiveDate;
+use polars::{
+	frame::{column::Column, DataFrame},
+	prelude::{DataType, ParquetWriter, PolarsResult},
+};
+use std::{
+	borrow::Cow,
+	collections::HashSet,
+	fs::File,
+	io::{self, BufWriter, Write},
+	path::Path,
+};
+
+pub fn write_csv(data_frame: &DataFrame, path: &Path) -> io::Result<()> {
+	let mut out = BufWriter::new(File::create(path)?);
+	let names = data_frame.get_column_names();
+	write_csv_record(&mut out, names.iter().map(|name| name.as_str()))?;
+	let columns = data_frame.columns();
+	for row_idx in 0..data_frame.height() {
+		let cells: Vec<String> = columns
+			.iter()
+			.map(|column| cell_to_string(column, row_idx))
+			.collect();
+		write_csv_record(&mut out, cells.iter().map(String::as_str))?;
+	}
+	out.flush()
+}
+
+/// Renders up to `max_rows` rows as a GitHub-flavored Markdown table with
+/// every column padded to its widest cell.
+pub fn to_markdown(data_frame: &DataFrame, max_rows: usize) -> String {
+	let columns = data_frame.columns();
+	let mut rows: Vec<Vec<String>> = vec![
+		data_frame
+			.get_column_names()
+			.iter()
+			.map(|name| markdown_cell(name.as_str()))
+			.collect(),
+	];
+	for row_idx in 0..data_frame.height().min(max_rows) {
+		rows.push(
+			columns
+				.iter()
+				.map(|column| markdown_cell(&cell_to_string(column, row_idx)))
+				.collect(),
+		);
+	}
+	let widths: Vec<usize> = (0..columns.len())
+		.map(|col| rows.iter().map(|row| row[col].chars().count()).max().unwrap_or(0).max(3))
+		.collect();
+	let line = |cells: &[String]| {
+		let padded: Vec<String> = cells
+			.iter()
+			.zip(&widths)
+			.map(|(cell, &width)| format!("{cell:<width$}"))
+			.collect();
+		format!("| {} |\n", padded.join(" | "))
+	};
+	let mut out = line(&rows[0]);
+	let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+	out.push_str(&line(&separator));
+	for row in &rows[1..] {
+		out.push_str(&line(row));
+	}
+	out
+}
+
+/// Cells of `data_frame` whose text differs from the same position in
+/// `baseline`, counting cells outside the baseline's shape as different.
+pub fn count_differences(data_frame: &DataFrame, baseline: &DataFrame) -> usize {
+	let base_columns = baseline.columns();
+	data_frame
+		.columns()
+		.iter()
+		.enumerate()
+		.map(|(col_idx, column)| match base_columns.get(col_idx) {
+			Some(base) => (0..data_frame.height())
+				.filter(|&row_idx| {
+					row_idx >= baseline.height()
+						|| cell_to_string(column, row_idx) != cell_to_string(base, row_idx)
+				})
+				.count(),
+			None => data_frame.height(),
+		})
+		.sum()
+}
+
+/// Distinct join keys found only in `left`, in both, and only in `right`,
+/// comparing cells by their displayed text. Null and empty keys never join,
+/// so they aren't counted.
+pub fn join_counts(left: &Column, right: &Column) -> (usize, usize, usize) {
+	let keys = |column: &Column| -> HashSet<String> {
+		(0..column.len())
+			.map(|row_idx| cell_to_string(column, row_idx))
+			.filter(|key| !key.is_empty())
+			.collect()
+	};
+	let (left, right) = (keys(left), keys(right));
+	let both = left.intersection(&right).count();
+	(left.len() - both, both, right.len() - both)
+}
+
+fn markdown_cell(value: &str) -> String {
+	value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Writes the frame as Parquet, first narrowing string columns to integer, float,
+/// boolean or date columns when every value parses. Returns the written schema.
+pub fn write_parquet(data_frame: &DataFrame, path: &Path) -> PolarsResult<String> {
+	let mut inferred = infer_column_types(data_frame)?;
+	ParquetWriter::new(File::create(path)?).finish(&mut inferred)?;
+	Ok(schema_summary(&inferred))
+}
+
+pub fn infer_column_types(data_frame: &DataFrame) -> PolarsResult<DataFrame> {
+	let columns = data_frame
+		.columns()
+		.iter()
+		.map(infer_column_type)
+		.collect::<PolarsResult<Vec<_>>>()?;
+	DataFrame::new(data_frame.height(), columns)
+}
+
+fn infer_column_type(column: &Column) -> PolarsResult<Column> {
+	if column.dtype() != &DataType::String {
+		return Ok(column.clone());
+	}
+	let values: Vec<Option<&str>> = column
+		.str()?
+		.into_iter()
+		.map(|v| v.filter(|v| !v.trim().is_empty()).map(str::trim))
+		.collect();
+	let name = column.name().clone();
+	let present = || values.iter().flatten();
+	if present().next().is_none() {
+		return Ok(column.clone());
+	}
+	if present().all(|v| v.parse::<i64>().is_ok()) {
+		let parsed: Vec<Option<i64>> = values.iter().map(|v| v.and_then(|v| v.parse().ok())).collect();
+		return Ok(Column::new(name, parsed));
+	}
+	if present().all(|v| v.parse::<f64>().is_ok()) {
+		let parsed: Vec<Option<f64>> = values.iter().map(|v| v.and_then(|v| v.parse().ok())).collect();
+		return Ok(Column::new(name, parsed));
+	}
+	if present().all(|v| parse_bool(v).is_some()) {
+		let parsed: Vec<Option<bool>> = values.iter().map(|v| v.and_then(parse_bool)).collect();
+		return Ok(Column::new(name, parsed));
+	}
+	if present().all(|v| parse_date(v).is_some()) {
+		let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid epoch");
+		let days: Vec<Option<i32>> = values
+			.iter()
+			.map(|v| v.and_then(parse_date).map(|d| (d - epoch).num_days() as i32))
+			.collect();
+		return Column::new(name, days).cast(&DataType::Date);
+	}
+	Ok(column.clone())
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+	match value.to_ascii_lowercase().as_str() {
+		"true" => Some(true),
+		"false" => Some(false),
+		_ => None,
+	}
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+	NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+pub fn schema_summary(data_frame: &DataFrame) -> String {
+	data_frame
+		.columns()
+		.iter()
+		.map(|column| format!("{}({})", column.name(), dtype_label(column.dtype())))
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+fn dtype_label(dtype: &DataType) -> String {
+	match dtype {
+		DataType::Int32 => "i32".into(),
+		DataType::Int64 => "i64".into(),
+		DataType::UInt32 => "u32".into(),
+		DataType::UInt64 => "u64".into(),
+		DataType::Float32 => "f32".into(),
+		DataType::Float64 => "f64".into(),
+		DataType::Boolean => "bool".into(),
+		DataType::Date => "date".into(),
+		DataType::String => "str".into(),
+		other => other.to_string(),
+	}
+}
+
+fn write_csv_record<'a>(
+	out: &mut impl Write,
+	fields: impl Iterator<Item = &'a str>,
+) -> io::Result<()> {
+	for (i, field) in fields.enumerate() {
+		if i > 0 {
+			out.write_all(b",")?;
+		}
+		out.write_all(csv_field(field).as_bytes())?;
+	}
+	out.write_all(b"\r\n")
+}
+
+fn csv_field(field: &str) -> Cow<'_, str> {
+	if field.contains([',', '"', '\n', '\r']) {
+		Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+	} else {
+		Cow::Borrowed(field)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn plain_fields_are_not_quoted() {
+		assert_eq!(csv_field("abc"), "abc");
+		assert_eq!(csv_field(""), "");
+	}
+
+	#[test]
+	fn special_fields_are_quoted_per_rfc_4180() {
+		assert_eq!(csv_field("a,b"), "\"a,b\"");
+		assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+		assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+	}
+
+	#[test]
+	fn markdown_columns_are_padded_to_the_widest_cell() {
+		let columns = vec![
+			Column::new("id".into(), ["1", "22"]),
+			Column::new("name".into(), ["a|b", "longer"]),
+		];
+		let height = 2;
+		let df = DataFrame::new(height, columns).unwrap();
+		assert_eq!(
+			to_markdown(&df, 10),
+			"| id  | name   |\n| --- | ------ |\n| 1   | a\\|b   |\n| 22  | longer |\n"
+		);
+		assert_eq!(to_markdown(&df, 1).lines().count(), 3);
+	}
+
+	#[test]
+	fn differences_include_cells_beyond_the_baseline() {
+		let height = 2;
+		let baseline = DataFrame::new(height, vec![Column::new("a".into(), ["1", "2"])]).unwrap();
+		let columns = vec![
+			Column::new("a".into(), ["1", "5", "3"]),
+			Column::new("b".into(), ["x", "y", "z"]),
+		];
+		let height = 3;
+		let df = DataFrame::new(height, columns).unwrap();
+		assert_eq!(count_differences(&df, &baseline), 5);
+		assert_eq!(count_differences(&baseline, &baseline), 0);
+	}
+
+	#[test]
+	fn join_counts_compare_distinct_keys() {
+		let left = Column::new("id".into(), ["1", "2", "2", "3"]);
+		let right = Column::new("id".into(), ["2", "3", "4", "5", "5"]);
+		assert_eq!(join_counts(&left, &right), (1, 2, 2));
+	}
+
+	#[test]
+	fn join_counts_skip_null_and_empty_keys() {
+		let left = Column::new("id".into(), [Some("1"), None, Some(""), Some("2")]);
+		let right = Column::new("id".into(), [None, Some(""), Some("2"), Some("3")]);
+		assert_eq!(join_counts(&left, &right), (1, 1, 1));
+	}
+
+	#[test]
+	fn string_columns_are_narrowed_when_every_value_parses() {
+		let columns = vec![
+			Column::new("id".into(), ["1", "2", ""]),
+			Column::new("value".into(), ["1.5", "2", "3"]),
+			Column::new("flag".into(), ["true", "FALSE", "true"]),
+			Column::new("day".into(), ["2024-01-02", "2024-02-03", "2024-03-04"]),
+			Column::new("name".into(), ["a", "1", "c"]),
+		];
+		let height = 3;
+		let df = DataFrame::new(height, columns).unwrap();
+		let inferred = infer_column_types(&df).unwrap();
+		assert_eq!(
+			schema_summary(&inferred),
+			"id(i64), value(f64), flag(bool), day(date), name(str)"
+		);
+	}
+}
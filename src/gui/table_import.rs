@@ -0,0 +1,219 @@
+use super::table_export::infer_column_types;
+use polars::{
+	frame::{column::Column, DataFrame},
+	prelude::{JsonReader, ParquetReader, SerReader},
+};
+use std::{
+	collections::HashSet,
+	fs::File,
+	io::{BufRead, BufReader},
+	path::Path,
+};
+
+const CSV_CHUNK_ROWS: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+	Comma,
+	Tab,
+	Semicolon,
+	Pipe,
+}
+
+impl Delimiter {
+	pub const ALL: [Delimiter; 4] = [
+		Delimiter::Comma,
+		Delimiter::Tab,
+		Delimiter::Semicolon,
+		Delimiter::Pipe,
+	];
+
+	pub fn as_char(self) -> char {
+		match self {
+			Delimiter::Comma => ',',
+			Delimiter::Tab => '\t',
+			Delimiter::Semicolon => ';',
+			Delimiter::Pipe => '|',
+		}
+	}
+
+	pub fn from_char(c: char) -> Option<Self> {
+		Self::ALL.into_iter().find(|delimiter| delimiter.as_char() == c)
+	}
+}
+
+impl std::fmt::Display for Delimiter {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Delimiter::Comma => write!(f, "Comma"),
+			Delimiter::Tab => write!(f, "Tab"),
+			Delimiter::Semicolon => write!(f, "Semicolon"),
+			Delimiter::Pipe => write!(f, "Pipe"),
+		}
+	}
+}
+
+/// Tab for `.tsv` files, `preferred` when the header line contains it, tab
+/// when the header has tabs but no commas, and comma otherwise.
+pub fn detect_delimiter(path: &Path, preferred: u8) -> u8 {
+	if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("tsv")) {
+		return b'\t';
+	}
+	let mut header = String::new();
+	if let Ok(file) = File::open(path) {
+		let _ = BufReader::new(file).read_line(&mut header);
+	}
+	if header.contains(char::from(preferred)) {
+		preferred
+	} else if header.contains('\t') && !header.contains(',') {
+		b'\t'
+	} else {
+		b','
+	}
+}
+
+/// Streams a delimited text file into a frame in fixed-size chunks so the raw
+/// records never sit in memory all at once, then narrows the string columns.
+pub fn read_csv(path: &Path, delimiter: u8) -> Result<DataFrame, String> {
+	let file = File::open(path).map_err(|e| e.to_string())?;
+	let mut reader = csv::ReaderBuilder::new()
+		.delimiter(delimiter)
+		.from_reader(BufReader::new(file));
+	let headers = unique_headers(reader.headers().map_err(|e| e.to_string())?);
+	let mut data_frame: Option<DataFrame> = None;
+	let mut chunk: Vec<Vec<Option<String>>> = vec![Vec::with_capacity(CSV_CHUNK_ROWS); headers.len()];
+	let mut record = csv::StringRecord::new();
+	loop {
+		let more = reader.read_record(&mut record).map_err(|e| e.to_string())?;
+		if more {
+			for (values, field) in chunk.iter_mut().zip(record.iter()) {
+				values.push((!field.is_empty()).then(|| field.to_string()));
+			}
+		}
+		let chunk_rows = chunk.first().map_or(0, Vec::len);
+		if chunk_rows == CSV_CHUNK_ROWS || (!more && (chunk_rows > 0 || data_frame.is_none())) {
+			let columns = headers
+				.iter()
+				.zip(chunk.iter_mut())
+				.map(|(name, values)| Column::new(name.as_str().into(), std::mem::take(values)))
+				.collect();
+			let next = DataFrame::new(chunk_rows, columns).map_err(|e| e.to_string())?;
+			match &mut data_frame {
+				Some(df) => {
+					df.vstack_mut(&next).map_err(|e| e.to_string())?;
+				}
+				None => data_frame = Some(next),
+			}
+		}
+		if !more {
+			break;
+		}
+	}
+	let mut data_frame = data_frame.unwrap_or_default();
+	data_frame.rechunk_mut();
+	infer_column_types(&data_frame).map_err(|e| e.to_string())
+}
+
+pub fn read_parquet(path: &Path) -> Result<DataFrame, String> {
+	let file = File::open(path).map_err(|e| e.to_string())?;
+	ParquetReader::new(file).finish().map_err(|e| e.to_string())
+}
+
+pub fn read_json(path: &Path) -> Result<DataFrame, String> {
+	let file = File::open(path).map_err(|e| e.to_string())?;
+	JsonReader::new(file).finish().map_err(|e| e.to_string())
+}
+
+fn unique_headers(record: &csv::StringRecord) -> Vec<String> {
+	let mut seen = HashSet::new();
+	record
+		.iter()
+		.enumerate()
+		.map(|(i, name)| {
+			let base = if name.is_empty() { format!("column_{}", i + 1) } else { name.to_string() };
+			let mut candidate = base.clone();
+			let mut suffix = 2;
+			while !seen.insert(candidate.clone()) {
+				candidate = format!("{base}_{suffix}");
+				suffix += 1;
+			}
+			candidate
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::path::PathBuf;
+
+	fn temp_file(name: &str, contents: &str) -> PathBuf {
+		let path = std::env::temp_dir().join(format!("polariton_{}_{name}", std::process::id()));
+		std::fs::write(&path, contents).unwrap();
+		path
+	}
+
+	#[test]
+	fn delimiter_comes_from_extension_then_header() {
+		let tsv = temp_file("extension.tsv", "a,b\n1,2\n");
+		assert_eq!(detect_delimiter(&tsv, b';'), b'\t');
+		let preferred = temp_file("preferred.csv", "a;b,c\n");
+		assert_eq!(detect_delimiter(&preferred, b';'), b';');
+		let tabs = temp_file("tabs.csv", "a\tb\n");
+		assert_eq!(detect_delimiter(&tabs, b';'), b'\t');
+		let commas = temp_file("commas.csv", "a,b\tc\n");
+		assert_eq!(detect_delimiter(&commas, b';'), b',');
+		for path in [tsv, preferred, tabs, commas] {
+			std::fs::remove_file(path).unwrap();
+		}
+	}
+
+	#[test]
+	fn blank_and_repeated_headers_are_renamed() {
+		let record = csv::StringRecord::from(vec!["a", "", "a", "a", "a_2"]);
+		assert_eq!(unique_headers(&record), ["a", "column_2", "a_2", "a_3", "a_2_2"]);
+	}
+
+	#[test]
+	fn chunks_are_stacked_in_order() {
+		let mut contents = String::from("id,name\n");
+		for row in 0..=CSV_CHUNK_ROWS {
+			contents.push_str(&format!("{row},row{row}\n"));
+		}
+		let path = temp_file("chunks.csv", &contents);
+		let df = read_csv(&path, b',').unwrap();
+		std::fs::remove_file(path).unwrap();
+		assert_eq!(df.shape(), (CSV_CHUNK_ROWS + 1, 2));
+		let ids = df.column("id").unwrap().i64().unwrap();
+		assert_eq!(ids.get(0), Some(0));
+		assert_eq!(ids.get(CSV_CHUNK_ROWS), Some(CSV_CHUNK_ROWS as i64));
+		let names = df.column("name").unwrap().str().unwrap();
+		assert_eq!(names.get(CSV_CHUNK_ROWS), Some(format!("row{CSV_CHUNK_ROWS}").as_str()));
+	}
+
+	#[test]
+	fn empty_and_header_only_files_give_empty_frames() {
+		let empty = temp_file("empty.csv", "");
+		assert_eq!(read_csv(&empty, b',').unwrap().shape(), (0, 0));
+		let header_only = temp_file("header_only.csv", "a,b\n");
+		let df = read_csv(&header_only, b',').unwrap();
+		assert_eq!(df.shape(), (0, 2));
+		assert_eq!(df.get_column_names(), ["a", "b"]);
+		for path in [empty, header_only] {
+			std::fs::remove_file(path).unwrap();
+		}
+	}
+
+	#[test]
+	fn empty_fields_are_null_and_short_records_are_rejected() {
+		let nulls = temp_file("nulls.csv", "a;b\n1;\n;x\n");
+		let df = read_csv(&nulls, b';').unwrap();
+		assert_eq!(df.column("a").unwrap().null_count(), 1);
+		assert_eq!(df.column("b").unwrap().null_count(), 1);
+		let short = temp_file("short.csv", "a,b\n1,2\n3\n");
+		assert!(read_csv(&short, b',').is_err());
+		for path in [nulls, short] {
+			std::fs::remove_file(path).unwrap();
+		}
+	}
+}
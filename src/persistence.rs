@@ -15,12 +15,81 @@ pub struct SavedStatement {
 	pub code: String,
 }
 
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableLayout {
+	pub col_widths: Vec<f32>,
+	pub h_scroll_offset: f64,
+	pub v_scroll_offset: f64,
+}
+
+/// User preferences from the settings dialog, saved as soon as they change.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Prefs {
+	pub table_font_size: f32,
+	pub zebra_striping: bool,
+	pub auto_close_brackets: bool,
+	pub tab_width: usize,
+	pub default_delimiter: char,
+}
+
+impl Default for Prefs {
+	fn default() -> Self {
+		Self {
+			table_font_size: 14.0,
+			zebra_striping: true,
+			auto_close_brackets: true,
+			tab_width: 4,
+			default_delimiter: ',',
+		}
+	}
+}
+
+impl Prefs {
+	/// `;`-separated fields with the delimiter last, as stored under `prefs`.
+	fn to_setting(self) -> String {
+		format!(
+			"{};{};{};{};{}",
+			self.table_font_size,
+			self.zebra_striping,
+			self.auto_close_brackets,
+			self.tab_width,
+			self.default_delimiter
+		)
+	}
+
+	fn from_setting(s: &str) -> Option<Self> {
+		// The delimiter is always the last character and may itself be ';'.
+		// Fields added later are optional so older rows still load.
+		let default_delimiter = s.chars().last()?;
+		let fields: Vec<&str> = s[..s.len() - default_delimiter.len_utf8()]
+			.strip_suffix(';')?
+			.split(';')
+			.collect();
+		let defaults = Prefs::default();
+		Some(Prefs {
+			table_font_size: fields.first()?.parse::<f32>().ok()?,
+			zebra_striping: fields.get(1)?.parse::<bool>().ok()?,
+			auto_close_brackets: fields
+				.get(2)
+				.and_then(|f| f.parse::<bool>().ok())
+				.unwrap_or(defaults.auto_close_brackets),
+			tab_width: fields
+				.get(3)
+				.and_then(|f| f.parse::<usize>().ok())
+				.unwrap_or(defaults.tab_width),
+			default_delimiter,
+		})
+	}
+}
+
 #[derive(Clone, Default)]
 pub struct StartupData {
 	pub window_size: Option<(f32, f32)>,
 	pub salt: Vec<u8>,
 	pub is_password_protected: bool,
 	pub show_column_types: bool,
+	pub table_layout: Option<TableLayout>,
+	pub prefs: Prefs,
 }
 
 const SALT_LEN: usize = 32;
@@ -177,7 +246,7 @@ async fn open_public() -> Connection {
 
 pub async fn load_startup_data() -> StartupData {
 	let conn = open_public().await;
-	let (window_size, salt_hex, is_password_protected, show_column_types) = conn
+	let (window_size, salt_hex, is_password_protected, show_column_types, table_layout, prefs) = conn
 		.call(|db| {
 			let get = |key: &str| -> Option<String> {
 				db.query_row("SELECT value FROM settings WHERE key = ?1", [key], |row| {
@@ -198,7 +267,33 @@ pub async fn load_startup_data() -> StartupData {
 			let show_column_types = get("show_column_types")
 				.and_then(|s| s.parse::<bool>().ok())
 				.unwrap_or(false);
-			Ok::<_, rusqlite::Error>((window_size, salt_hex, is_password_protected, show_column_types))
+			let table_layout = get("table_layout").and_then(|s| {
+				let mut parts = s.splitn(3, ';');
+				let col_widths = parts
+					.next()?
+					.split(',')
+					.filter(|w| !w.is_empty())
+					.map(|w| w.parse::<f32>().ok())
+					.collect::<Option<Vec<f32>>>()?;
+				let h_scroll_offset = parts.next()?.parse::<f64>().ok()?;
+				let v_scroll_offset = parts.next()?.parse::<f64>().ok()?;
+				Some(TableLayout {
+					col_widths,
+					h_scroll_offset,
+					v_scroll_offset,
+				})
+			});
+			let prefs = get("prefs")
+				.and_then(|s| Prefs::from_setting(&s))
+				.unwrap_or_default();
+			Ok::<_, rusqlite::Error>((
+				window_size,
+				salt_hex,
+				is_password_protected,
+				show_column_types,
+				table_layout,
+				prefs,
+			))
 		})
 		.await
 		.unwrap_or_default();
@@ -224,9 +319,25 @@ pub async fn load_startup_data() -> StartupData {
 		salt,
 		is_password_protected,
 		show_column_types,
+		table_layout,
+		prefs,
 	}
 }
 
+pub async fn save_prefs(prefs: Prefs) {
+	let conn = open_public().await;
+	let value = prefs.to_setting();
+	conn.call(move |db| {
+		db.execute(
+			"INSERT OR REPLACE INTO settings (key, value) VALUES ('prefs', ?1)",
+			[value.as_str()],
+		)?;
+		Ok::<(), rusqlite::Error>(())
+	})
+	.await
+	.ok();
+}
+
 pub async fn save_show_column_types(val: bool) {
 	let conn = open_public().await;
 	let value = val.to_string();
@@ -255,6 +366,29 @@ pub async fn save_window_size(width: f32, height: f32) {
 	.ok();
 }
 
+pub async fn save_table_layout(layout: TableLayout) {
+	let conn = open_public().await;
+	let col_widths = layout
+		.col_widths
+		.iter()
+		.map(|w| w.to_string())
+		.collect::<Vec<_>>()
+		.join(",");
+	let value = format!(
+		"{};{};{}",
+		col_widths, layout.h_scroll_offset, layout.v_scroll_offset
+	);
+	conn.call(move |db| {
+		db.execute(
+			"INSERT OR REPLACE INTO settings (key, value) VALUES ('table_layout', ?1)",
+			[value.as_str()],
+		)?;
+		Ok::<(), rusqlite::Error>(())
+	})
+	.await
+	.ok();
+}
+
 pub async fn save_is_password_protected(val: bool) {
 	let conn = open_public().await;
 	let value = val.to_string();
@@ -484,3 +618,38 @@ impl PrivateDb {
 		self.load_statements().await
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn prefs_round_trip_with_a_semicolon_delimiter() {
+		let prefs = Prefs {
+			table_font_size: 12.5,
+			zebra_striping: false,
+			auto_close_brackets: false,
+			tab_width: 2,
+			default_delimiter: ';',
+		};
+		assert_eq!(prefs.to_setting(), "12.5;false;false;2;;");
+		assert_eq!(Prefs::from_setting(&prefs.to_setting()), Some(prefs));
+		let tab = Prefs { default_delimiter: '\t', ..prefs };
+		assert_eq!(Prefs::from_setting(&tab.to_setting()), Some(tab));
+	}
+
+	#[test]
+	fn older_prefs_rows_fill_in_later_fields() {
+		let prefs = Prefs::from_setting("16;false;|").unwrap();
+		assert_eq!(
+			prefs,
+			Prefs {
+				table_font_size: 16.0,
+				zebra_striping: false,
+				default_delimiter: '|',
+				..Prefs::default()
+			}
+		);
+		assert_eq!(Prefs::from_setting("16;;"), None);
+	}
+}
@@ -8,6 +8,15 @@ use std::io::Cursor;
 use std::path::Path as StdPath;
 use std::sync::Arc;
 
+/// Encodes 8-bit RGBA pixels as a PNG file.
+pub fn save_rgba_png(path: &StdPath, width: u32, height: u32, rgba: &[u8]) -> Result<(), png::EncodingError> {
+	let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+	let mut encoder = Encoder::new(file, width, height);
+	encoder.set_color(ColorType::Rgba);
+	encoder.set_depth(BitDepth::Eight);
+	encoder.write_header()?.write_image_data(rgba)
+}
+
 pub struct SvgBackend {
 	pub svg: String,
 	current_transform: Affine,